@@ -0,0 +1,1657 @@
+use crate::error::AssembleError;
+use crate::error::Span;
+use crate::expr::evaluate_expr;
+use crate::expr::is_address;
+use crate::expr::substitute_constants;
+use crate::expr::Symbols;
+use crate::inst::instruction_size;
+use crate::isa::Isa;
+use crate::object::*;
+use crate::parser::ast::*;
+use crate::parser::Parser;
+use crate::pseudo;
+use crate::scanner::Scanner;
+use crate::Context;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+// Directives emitting each of their arguments as a little-endian value of the given size
+const DATA_DIRECTIVES: &[(&str, u32)] = &[(".byte", 1), (".half", 2), (".word", 4), (".dword", 8)];
+
+// `addi zero, zero, 0` and `c.addi zero, 0`, padding code
+const NOP: u32 = 0x00000013;
+const C_NOP: u16 = 0x0001;
+
+// Sections every program has, in the order they are laid out. Sections named by `.section` follow.
+const DEFAULT_SECTIONS: &[(&str, SectionKind)] = &[
+    (".text", SectionKind::Text),
+    (".data", SectionKind::Data),
+    (".rodata", SectionKind::ReadOnlyData),
+    (".bss", SectionKind::Bss),
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub isa: Isa,
+    // Leave undefined symbols to the linker instead of reporting them
+    pub relocatable: bool,
+}
+
+/// Assemble `source` into a flat binary
+pub fn assemble(source: &str) -> Result<Vec<u8>, AssembleError> {
+    Ok(assemble_with_options(source, &Options::default())?.to_binary())
+}
+
+/// Assemble `source` into a relocatable object, leaving undefined symbols to the linker
+pub fn assemble_object(source: &str) -> Result<Object, AssembleError> {
+    let options = Options {
+        relocatable: true,
+        ..Default::default()
+    };
+    assemble_with_options(source, &options)
+}
+
+pub fn assemble_with_options(source: &str, options: &Options) -> Result<Object, AssembleError> {
+    let mut assembler = Assembler {
+        options: options.clone(),
+        symbols: Symbols::default(),
+        constants: HashMap::new(),
+        globals: vec![],
+        uncompressed: HashSet::new(),
+        sections: vec![],
+        addresses: vec![],
+    };
+    let tokens = Scanner::new(source.to_string()).scan_tokens()?;
+    let mut program = Parser::new(tokens).parse()?;
+    rename_local_labels(&mut program)?;
+    assembler.collect_labels(&program)?;
+    if assembler.options.isa.has("c") {
+        assembler.compress_program(&program)?;
+    }
+    assembler.encode_program(&program)
+}
+
+struct Assembler {
+    options: Options,
+    symbols: Symbols,
+    // First assignment of each constant, which it stands for until an assignment is walked past
+    constants: HashMap<String, Operand>,
+    // Symbols declared with `.globl`
+    globals: Vec<String>,
+    // Statements kept at full size so that compressing the program settles
+    uncompressed: HashSet<usize>,
+    sections: Vec<(String, SectionKind)>,
+    // Address of each section in the flat binary, which labels are given relative to
+    addresses: Vec<u32>,
+}
+
+// Section statements are being placed in while walking the program
+struct Location {
+    section: usize,
+    // Sections to go back to with `.popsection`
+    stack: Vec<usize>,
+    // Number of bytes placed in each section so far
+    offsets: Vec<u32>,
+    // Largest alignment asked for in each section
+    alignments: Vec<u32>,
+}
+
+impl Location {
+    fn new(sections: usize) -> Location {
+        Location {
+            section: 0,
+            stack: vec![],
+            offsets: vec![0; sections],
+            alignments: vec![1; sections],
+        }
+    }
+
+    fn offset(&self) -> u32 {
+        self.offsets[self.section]
+    }
+
+    fn advance(&mut self, size: u32) {
+        self.offsets[self.section] += size;
+    }
+}
+
+impl Assembler {
+    // First pass: assign an address to every label
+    fn collect_labels(&mut self, program: &Program) -> Result<(), AssembleError> {
+        self.collect_constants(program)?;
+        self.collect_sections(program)?;
+        let mut location = Location::new(self.sections.len());
+        let mut labels = HashMap::new();
+        let mut pcrel_hi = vec![];
+        self.symbols.constants = self.constants.clone();
+        for statement in &program.statements {
+            match &statement.kind {
+                StatementKind::Label(name) => {
+                    if self.symbols.constants.contains_key(name) || labels.contains_key(name) {
+                        return Err(AssembleError::DuplicateSymbol {
+                            symbol: name.clone(),
+                            span: statement.span,
+                        });
+                    }
+                    labels.insert(name.clone(), (location.section, location.offset()));
+                }
+                StatementKind::Instruction { mnemonic, operands } => {
+                    let (section, kind) = &self.sections[location.section];
+                    if *kind == SectionKind::Bss {
+                        return Err(AssembleError::BssContents {
+                            lexeme: mnemonic.clone(),
+                            section: section.clone(),
+                            span: statement.span,
+                        });
+                    }
+                    if let Some(target) = pcrel_hi_target(operands) {
+                        pcrel_hi.push((location.section, location.offset(), target));
+                    }
+                    location.advance(
+                        4 * pseudo::instruction_count(
+                            mnemonic,
+                            operands,
+                            &self.symbols,
+                            self.options.isa.xlen,
+                        )? as u32,
+                    );
+                }
+                StatementKind::Directive { name, arguments } => match name.as_str() {
+                    ".globl" | ".global" => {
+                        for argument in arguments {
+                            let OperandKind::Expression(Expr::Symbol(symbol)) = &argument.kind
+                            else {
+                                return Err(AssembleError::InvalidOperand {
+                                    description: "Expected a symbol".to_string(),
+                                    lexeme: argument.lexeme.clone(),
+                                    span: argument.span,
+                                });
+                            };
+                            self.globals.push(symbol.clone());
+                        }
+                    }
+                    ".equ" | ".set" => self.assign(arguments),
+                    _ if self.switch_section(name, statement.span, arguments, &mut location)? => {}
+                    _ => {
+                        let size =
+                            match self.align(name, statement.span, arguments, &mut location)? {
+                                Some(padding) => Some(padding.len() as u32),
+                                None => self.data_size(name, statement.span, arguments)?,
+                            };
+                        let Some(size) = size else {
+                            return Err(AssembleError::UnsupportedDirective {
+                                directive: name.clone(),
+                                span: statement.span,
+                            });
+                        };
+                        location.advance(size);
+                    }
+                },
+            }
+        }
+        self.place(&location, labels, pcrel_hi);
+        Ok(())
+    }
+
+    // Sections named by `.section` or `.pushsection`, in order of first appearance after the
+    // default ones
+    fn collect_sections(&mut self, program: &Program) -> Result<(), AssembleError> {
+        self.sections = DEFAULT_SECTIONS
+            .iter()
+            .map(|&(name, kind)| (name.to_string(), kind))
+            .collect();
+        for statement in &program.statements {
+            let StatementKind::Directive { name, arguments } = &statement.kind else {
+                continue;
+            };
+            if name != ".section" && name != ".pushsection" {
+                continue;
+            }
+            let section = section_name(name, statement.span, arguments)?;
+            if !self.sections.iter().any(|(name, _)| *name == section) {
+                let kind = section_kind(&section, arguments);
+                self.sections.push((section, kind));
+            }
+        }
+        Ok(())
+    }
+
+    // Change the section statements are placed in if `name` is a section directive, returning
+    // whether it was one
+    fn switch_section(
+        &self,
+        name: &str,
+        span: Span,
+        arguments: &[Operand],
+        location: &mut Location,
+    ) -> Result<bool, AssembleError> {
+        match name {
+            ".text" | ".data" | ".rodata" | ".bss" => location.section = self.section_index(name),
+            ".section" => {
+                location.section = self.section_index(&section_name(name, span, arguments)?);
+            }
+            ".pushsection" => {
+                location.stack.push(location.section);
+                location.section = self.section_index(&section_name(name, span, arguments)?);
+            }
+            ".popsection" => {
+                location.section = location.stack.pop().ok_or(AssembleError::InvalidOperand {
+                    description: "No section was pushed".to_string(),
+                    lexeme: name.to_string(),
+                    span,
+                })?;
+            }
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    // Sections are collected beforehand, so every section directive names a known section
+    fn section_index(&self, name: &str) -> usize {
+        self.sections
+            .iter()
+            .position(|(section, _)| section == name)
+            .expect("section was collected")
+    }
+
+    // Alignment of a section, which code needs at least for its instructions
+    fn section_alignment(&self, kind: SectionKind, location: &Location, index: usize) -> u32 {
+        let minimum = match kind {
+            SectionKind::Text if self.options.isa.has("c") => 2,
+            SectionKind::Text => 4,
+            _ => 1,
+        };
+        location.alignments[index].max(minimum)
+    }
+
+    // Padding emitted by `.align`, `.p2align` or `.balign`, `None` for any other directive. The
+    // section is aligned as strictly so that the padding still holds once it is placed.
+    fn align(
+        &self,
+        name: &str,
+        span: Span,
+        arguments: &[Operand],
+        location: &mut Location,
+    ) -> Result<Option<Vec<u8>>, AssembleError> {
+        if name != ".align" && name != ".p2align" && name != ".balign" {
+            return Ok(None);
+        }
+        if arguments.is_empty() || arguments.len() > 3 {
+            return Err(AssembleError::WrongOperandCount {
+                mnemonic: name.to_string(),
+                expected: arguments.len().clamp(1, 3),
+                found: arguments.len(),
+                span,
+            });
+        }
+        // `.align` takes a power of two on RISC-V, like `.p2align`
+        let value = self.value_argument(&arguments[0])?;
+        let alignment = if name == ".balign" {
+            value
+        } else if (0..32).contains(&value) {
+            1 << value
+        } else {
+            0
+        };
+        if alignment <= 0 || alignment > 1 << 31 || alignment & (alignment - 1) != 0 {
+            return Err(AssembleError::InvalidOperand {
+                description: "Expected a power of two alignment".to_string(),
+                lexeme: arguments[0].lexeme.clone(),
+                span: arguments[0].span,
+            });
+        }
+        let offset = location.offset();
+        let size = offset.next_multiple_of(alignment as u32) - offset;
+        // Alignment is skipped altogether when it takes more than the given maximum
+        if let Some(max) = arguments.get(2) {
+            if size as i64 > self.value_argument(max)? {
+                return Ok(Some(vec![]));
+            }
+        }
+        let alignments = &mut location.alignments[location.section];
+        *alignments = (*alignments).max(alignment as u32);
+        let padding = match arguments.get(1) {
+            Some(fill) => vec![self.value_argument(fill)? as u8; size as usize],
+            None if self.sections[location.section].1 == SectionKind::Text => self.nops(size),
+            None => vec![0; size as usize],
+        };
+        Ok(Some(padding))
+    }
+
+    // Padding of code, made up of nops once any bytes left over by data are zeroed
+    fn nops(&self, size: u32) -> Vec<u8> {
+        let compressed = self.options.isa.has("c");
+        let mut padding = vec![0; (size % if compressed { 2 } else { 4 }) as usize];
+        if compressed && (size as usize - padding.len()) % 4 == 2 {
+            padding.extend_from_slice(&C_NOP.to_le_bytes());
+        }
+        while padding.len() < size as usize {
+            padding.extend_from_slice(&NOP.to_le_bytes());
+        }
+        padding
+    }
+
+    // Lay the sections out once their sizes are known and turn the offsets of labels within
+    // their section into addresses. Returns whether any label or section moved.
+    fn place(
+        &mut self,
+        location: &Location,
+        labels: HashMap<String, (usize, u32)>,
+        pcrel_hi: Vec<(usize, u32, Operand)>,
+    ) -> bool {
+        let layout: Vec<_> = self
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(index, &(_, kind))| {
+                let alignment = self.section_alignment(kind, location, index);
+                (kind, location.offsets[index] as u64, alignment as u64)
+            })
+            .collect();
+        let addresses: Vec<u32> = section_addresses(&layout)
+            .into_iter()
+            .map(|address| address as u32)
+            .collect();
+        let mut moved = addresses != self.addresses;
+        self.symbols.pcrel_hi = pcrel_hi
+            .into_iter()
+            .map(|(section, offset, target)| (addresses[section] + offset, target))
+            .collect();
+        self.symbols.sections = labels
+            .iter()
+            .map(|(name, &(section, _))| (name.clone(), section))
+            .collect();
+        let labels: HashMap<String, u32> = labels
+            .into_iter()
+            .map(|(name, (section, offset))| (name, addresses[section] + offset))
+            .collect();
+        moved |= labels != self.symbols.labels;
+        self.symbols.labels = labels;
+        self.addresses = addresses;
+        moved
+    }
+
+    // Symbols assigned with `.equ`, `.set` or `=`. They are known before any label so that
+    // every pass agrees on which symbols are constants. `.set` and `=` may assign a symbol again,
+    // `.equ` may not.
+    fn collect_constants(&mut self, program: &Program) -> Result<(), AssembleError> {
+        let mut directives = HashMap::new();
+        for statement in &program.statements {
+            let StatementKind::Directive { name, arguments } = &statement.kind else {
+                continue;
+            };
+            if name != ".equ" && name != ".set" {
+                continue;
+            }
+            if arguments.len() != 2 {
+                return Err(AssembleError::WrongOperandCount {
+                    mnemonic: name.clone(),
+                    expected: 2,
+                    found: arguments.len(),
+                    span: statement.span,
+                });
+            }
+            let OperandKind::Expression(Expr::Symbol(symbol)) = &arguments[0].kind else {
+                return Err(AssembleError::InvalidOperand {
+                    description: "Expected a symbol".to_string(),
+                    lexeme: arguments[0].lexeme.clone(),
+                    span: arguments[0].span,
+                });
+            };
+            if !matches!(arguments[1].kind, OperandKind::Expression(_)) {
+                return Err(AssembleError::InvalidOperand {
+                    description: "Expected a value".to_string(),
+                    lexeme: arguments[1].lexeme.clone(),
+                    span: arguments[1].span,
+                });
+            }
+            match directives.insert(symbol, name) {
+                Some(previous) if previous == ".equ" || name == ".equ" => {
+                    return Err(AssembleError::DuplicateSymbol {
+                        symbol: symbol.clone(),
+                        span: arguments[0].span,
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    self.constants.insert(symbol.clone(), arguments[1].clone());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Reassign the constant of a `.equ` or `.set` being walked past, in terms of what the symbols
+    // it refers to are assigned at this point. Its operands were checked by `collect_constants`.
+    fn assign(&mut self, arguments: &[Operand]) {
+        let (OperandKind::Expression(Expr::Symbol(symbol)), OperandKind::Expression(expr)) =
+            (&arguments[0].kind, &arguments[1].kind)
+        else {
+            return;
+        };
+        let value = Operand {
+            kind: OperandKind::Expression(substitute_constants(expr, &self.symbols)),
+            ..arguments[1].clone()
+        };
+        self.symbols.constants.insert(symbol.clone(), value);
+    }
+
+    // Labels move as instructions are compressed, which may in turn let more instructions be
+    // compressed. Lay the program out again until the labels settle, keeping any statement that
+    // grows back at its full size so that this terminates.
+    fn compress_program(&mut self, program: &Program) -> Result<(), AssembleError> {
+        let mut sizes = vec![None; program.statements.len()];
+        loop {
+            let mut location = Location::new(self.sections.len());
+            let mut labels = HashMap::new();
+            let mut pcrel_hi = vec![];
+            self.symbols.constants = self.constants.clone();
+            for (index, statement) in program.statements.iter().enumerate() {
+                match &statement.kind {
+                    StatementKind::Label(name) => {
+                        labels.insert(name.clone(), (location.section, location.offset()));
+                    }
+                    StatementKind::Instruction { operands, .. } => {
+                        if let Some(target) = pcrel_hi_target(operands) {
+                            pcrel_hi.push((location.section, location.offset(), target));
+                        }
+                        let mut relocations = vec![];
+                        let relocations = self.options.relocatable.then_some(&mut relocations);
+                        let size = self
+                            .encode_statement(index, statement, &location, relocations)?
+                            .iter()
+                            .map(|&word| instruction_size(word))
+                            .sum::<u32>();
+                        if sizes[index].is_some_and(|previous| size > previous) {
+                            self.uncompressed.insert(index);
+                        }
+                        sizes[index] = Some(size);
+                        location.advance(size);
+                    }
+                    StatementKind::Directive { name, arguments } => {
+                        if name == ".equ" || name == ".set" {
+                            self.assign(arguments);
+                            continue;
+                        }
+                        if self.switch_section(name, statement.span, arguments, &mut location)? {
+                            continue;
+                        }
+                        let size =
+                            match self.align(name, statement.span, arguments, &mut location)? {
+                                Some(padding) => padding.len() as u32,
+                                None => self
+                                    .data_size(name, statement.span, arguments)?
+                                    .unwrap_or(0),
+                            };
+                        location.advance(size);
+                    }
+                }
+            }
+            if !self.place(&location, labels, pcrel_hi) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn encode_statement(
+        &self,
+        index: usize,
+        statement: &Statement,
+        location: &Location,
+        relocations: Option<&mut Vec<Relocation>>,
+    ) -> Result<Vec<u32>, AssembleError> {
+        let StatementKind::Instruction { mnemonic, operands } = &statement.kind else {
+            return Ok(vec![]);
+        };
+        let mut context = Context {
+            pc: self.addresses[location.section] + location.offset(),
+            section: location.section,
+            symbols: &self.symbols,
+            relocations,
+            isa: &self.options.isa,
+            compress: self.options.isa.has("c") && !self.uncompressed.contains(&index),
+        };
+        pseudo::encode_statement(mnemonic, statement.span, operands, &mut context)
+    }
+
+    // Number of bytes emitted by a data directive, `None` for any other directive. Unlike
+    // `encode_data` this does not need the labels the values refer to.
+    fn data_size(
+        &self,
+        name: &str,
+        span: Span,
+        arguments: &[Operand],
+    ) -> Result<Option<u32>, AssembleError> {
+        if let Some(&(_, size)) = DATA_DIRECTIVES.iter().find(|(n, _)| *n == name) {
+            return Ok(Some(size * arguments.len() as u32));
+        }
+        match name {
+            ".string" | ".asciz" => {
+                let mut size = 0;
+                for argument in arguments {
+                    size += string_argument(argument)?.len() as u32 + 1;
+                }
+                Ok(Some(size))
+            }
+            ".zero" | ".space" | ".skip" => Ok(Some(self.fill_arguments(name, span, arguments)?.0)),
+            ".fill" => {
+                let (repeat, size, _) = self.repeat_arguments(span, arguments)?;
+                Ok(Some(repeat * size))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    // Bytes emitted by a data directive, `None` for any other directive
+    fn encode_data(
+        &self,
+        name: &str,
+        span: Span,
+        arguments: &[Operand],
+        location: &Location,
+        relocations: Option<&mut Vec<Relocation>>,
+        warnings: &mut Vec<AssembleError>,
+    ) -> Result<Option<Vec<u8>>, AssembleError> {
+        let mut bytes = vec![];
+        if let Some(&(_, size)) = DATA_DIRECTIVES.iter().find(|(n, _)| *n == name) {
+            let (min, max) = value_range(size);
+            let kind = match size {
+                4 => Some(RelocationKind::Data32),
+                8 => Some(RelocationKind::Data64),
+                _ => None,
+            };
+            let mut context = Context {
+                pc: self.addresses[location.section] + location.offset(),
+                section: location.section,
+                symbols: &self.symbols,
+                relocations,
+                isa: &self.options.isa,
+                compress: false,
+            };
+            for argument in arguments {
+                // Addresses are filled in by the linker, which has no relocation narrower than a
+                // word
+                if let OperandKind::Expression(expr) = &argument.kind {
+                    match kind {
+                        Some(kind) if crate::relocate(expr, argument, kind, &mut context) => {
+                            bytes.resize(bytes.len() + size as usize, 0);
+                            context.pc += size;
+                            continue;
+                        }
+                        None if self.options.relocatable && is_address(expr, &self.symbols) => {
+                            return Err(AssembleError::InvalidOperand {
+                                description: format!("Cannot relocate an address in '{name}'"),
+                                lexeme: argument.lexeme.clone(),
+                                span: argument.span,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                let value = self.value_argument(argument)?;
+                if value < min || value > max {
+                    return Err(AssembleError::ImmediateOutOfRange {
+                        lexeme: argument.lexeme.clone(),
+                        value,
+                        min,
+                        max,
+                        span: argument.span,
+                    });
+                }
+                bytes.extend_from_slice(&value.to_le_bytes()[..size as usize]);
+                context.pc += size;
+            }
+            return Ok(Some(bytes));
+        }
+        match name {
+            ".string" | ".asciz" => {
+                for argument in arguments {
+                    bytes.extend_from_slice(string_argument(argument)?);
+                    bytes.push(0);
+                }
+            }
+            ".zero" | ".space" | ".skip" => {
+                let (size, fill) = self.fill_arguments(name, span, arguments)?;
+                bytes.resize(size as usize, fill);
+            }
+            ".fill" => {
+                let (repeat, size, value) = self.repeat_arguments(span, arguments)?;
+                // The GNU assembler keeps the low bytes of a value that does not fit
+                let (min, max) = value_range(size);
+                if size > 0 && (value < min || value > max) {
+                    warnings.push(AssembleError::ImmediateTruncated {
+                        lexeme: arguments[2].lexeme.clone(),
+                        value,
+                        truncated: value & max,
+                        span: arguments[2].span,
+                    });
+                }
+                for _ in 0..repeat {
+                    bytes.extend_from_slice(&value.to_le_bytes()[..size as usize]);
+                }
+            }
+            _ => return Ok(None),
+        }
+        Ok(Some(bytes))
+    }
+
+    // Size and fill byte of `.zero size`, and of `.space` or `.skip size[, fill]`
+    fn fill_arguments(
+        &self,
+        name: &str,
+        span: Span,
+        arguments: &[Operand],
+    ) -> Result<(u32, u8), AssembleError> {
+        let max_arguments = if name == ".zero" { 1 } else { 2 };
+        if arguments.is_empty() || arguments.len() > max_arguments {
+            return Err(AssembleError::WrongOperandCount {
+                mnemonic: name.to_string(),
+                expected: arguments.len().clamp(1, max_arguments),
+                found: arguments.len(),
+                span,
+            });
+        }
+        let size = self.value_argument(&arguments[0])?;
+        if !(0..=u32::MAX as i64).contains(&size) {
+            return Err(AssembleError::InvalidOperand {
+                description: "Expected a non-negative size".to_string(),
+                lexeme: arguments[0].lexeme.clone(),
+                span: arguments[0].span,
+            });
+        }
+        let fill = match arguments.get(1) {
+            Some(argument) => self.value_argument(argument)? as u8,
+            None => 0,
+        };
+        Ok((size as u32, fill))
+    }
+
+    // Count, size and value of `.fill repeat[, size[, value]]`, whose value is written as a
+    // little-endian number of up to 8 bytes
+    fn repeat_arguments(
+        &self,
+        span: Span,
+        arguments: &[Operand],
+    ) -> Result<(u32, u32, i64), AssembleError> {
+        if arguments.is_empty() || arguments.len() > 3 {
+            return Err(AssembleError::WrongOperandCount {
+                mnemonic: ".fill".to_string(),
+                expected: arguments.len().clamp(1, 3),
+                found: arguments.len(),
+                span,
+            });
+        }
+        let repeat = self.value_argument(&arguments[0])?;
+        if !(0..=u32::MAX as i64).contains(&repeat) {
+            return Err(AssembleError::InvalidOperand {
+                description: "Expected a non-negative count".to_string(),
+                lexeme: arguments[0].lexeme.clone(),
+                span: arguments[0].span,
+            });
+        }
+        let size = match arguments.get(1) {
+            Some(argument) => {
+                let size = self.value_argument(argument)?;
+                if !(0..=8).contains(&size) {
+                    return Err(AssembleError::InvalidOperand {
+                        description: "Expected a size of at most 8 bytes".to_string(),
+                        lexeme: argument.lexeme.clone(),
+                        span: argument.span,
+                    });
+                }
+                size
+            }
+            None => 1,
+        };
+        let value = match arguments.get(2) {
+            Some(argument) => self.value_argument(argument)?,
+            None => 0,
+        };
+        Ok((repeat as u32, size as u32, value))
+    }
+
+    fn value_argument(&self, argument: &Operand) -> Result<i64, AssembleError> {
+        let OperandKind::Expression(expr) = &argument.kind else {
+            return Err(AssembleError::InvalidOperand {
+                description: "Expected a value".to_string(),
+                lexeme: argument.lexeme.clone(),
+                span: argument.span,
+            });
+        };
+        evaluate_expr(expr, argument, &self.symbols)
+    }
+
+    // Second pass: encode every instruction using the collected labels
+    fn encode_program(&mut self, program: &Program) -> Result<Object, AssembleError> {
+        let mut sections: Vec<Section> = self
+            .sections
+            .iter()
+            .map(|(name, kind)| Section::new(name, *kind))
+            .collect();
+        let mut symbols = vec![];
+        let mut listing = vec![];
+        let mut warnings = vec![];
+        let mut location = Location::new(sections.len());
+        self.symbols.constants = self.constants.clone();
+        for (index, statement) in program.statements.iter().enumerate() {
+            let start = location.offset();
+            let mut relocations = vec![];
+            let bytes = match &statement.kind {
+                StatementKind::Label(name) => {
+                    symbols.push(Symbol {
+                        name: name.clone(),
+                        section: Some(location.section),
+                        value: start as u64,
+                        global: self.globals.contains(name),
+                    });
+                    continue;
+                }
+                StatementKind::Instruction { .. } => {
+                    let words = self.encode_statement(
+                        index,
+                        statement,
+                        &location,
+                        self.options.relocatable.then_some(&mut relocations),
+                    )?;
+                    words
+                        .into_iter()
+                        .flat_map(|word| {
+                            word.to_le_bytes()
+                                .into_iter()
+                                .take(instruction_size(word) as usize)
+                        })
+                        .collect()
+                }
+                StatementKind::Directive { name, arguments } => {
+                    if name == ".equ" || name == ".set" {
+                        self.assign(arguments);
+                        continue;
+                    }
+                    if self.switch_section(name, statement.span, arguments, &mut location)? {
+                        continue;
+                    }
+                    let bytes = match self.align(name, statement.span, arguments, &mut location)? {
+                        Some(padding) => padding,
+                        None => match self.encode_data(
+                            name,
+                            statement.span,
+                            arguments,
+                            &location,
+                            self.options.relocatable.then_some(&mut relocations),
+                            &mut warnings,
+                        )? {
+                            Some(bytes) => bytes,
+                            None => continue,
+                        },
+                    };
+                    let section = &sections[location.section];
+                    let contents = bytes.iter().any(|&byte| byte != 0) || !relocations.is_empty();
+                    if section.kind == SectionKind::Bss && contents {
+                        return Err(AssembleError::BssContents {
+                            lexeme: name.clone(),
+                            section: section.name.clone(),
+                            span: statement.span,
+                        });
+                    }
+                    bytes
+                }
+            };
+            // Relocations are applied at offsets within their section
+            let address = self.addresses[location.section] as u64;
+            for relocation in relocations {
+                sections[location.section].relocations.push(Relocation {
+                    offset: relocation.offset - address,
+                    ..relocation
+                });
+            }
+            listing.push(ListingLine {
+                line: statement.span.line,
+                section: location.section,
+                offset: start as u64,
+                size: bytes.len() as u64,
+            });
+            location.advance(bytes.len() as u32);
+            sections[location.section].data.extend_from_slice(&bytes);
+        }
+
+        for (index, section) in sections.iter_mut().enumerate() {
+            section.alignment = self.section_alignment(section.kind, &location, index) as u64;
+        }
+
+        // Temporary `.L` labels, such as the renamed numeric ones, are only kept for the relocations
+        // referring to them
+        symbols.retain(|symbol| {
+            !symbol.name.starts_with(".L")
+                || sections
+                    .iter()
+                    .flat_map(|s| &s.relocations)
+                    .any(|r| r.symbol == symbol.name)
+        });
+
+        // Labels made up for the auipc of `la`, named after its address
+        for (index, section) in sections.iter().enumerate() {
+            for relocation in &section.relocations {
+                let address = self.addresses[index] as u64 + relocation.offset;
+                let name = pseudo::pcrel_hi_label(address);
+                if relocation.kind == RelocationKind::PcrelHi20
+                    && section.relocations.iter().any(|r| r.symbol == name)
+                {
+                    symbols.push(Symbol {
+                        name,
+                        section: Some(index),
+                        value: relocation.offset,
+                        global: false,
+                    });
+                }
+            }
+        }
+
+        // Symbols defined elsewhere
+        let undefined = sections
+            .iter()
+            .flat_map(|s| s.relocations.iter().map(|r| &r.symbol))
+            .chain(self.globals.iter());
+        for name in undefined {
+            if !symbols.iter().any(|s| &s.name == name) {
+                symbols.push(Symbol {
+                    name: name.clone(),
+                    section: None,
+                    value: 0,
+                    global: true,
+                });
+            }
+        }
+
+        Ok(Object {
+            sections,
+            symbols,
+            listing,
+            warnings,
+        })
+    }
+}
+
+// Numeric labels such as `1:` may be defined any number of times. Give each definition a name of
+// its own, like the GNU assembler does, and point `1b` and `1f` at the previous and next one.
+fn rename_local_labels(program: &mut Program) -> Result<(), AssembleError> {
+    let mut totals: HashMap<String, usize> = HashMap::new();
+    for statement in &program.statements {
+        if let StatementKind::Label(name) = &statement.kind {
+            if is_local_label(name) {
+                *totals.entry(name.clone()).or_default() += 1;
+            }
+        }
+    }
+    let mut defined: HashMap<String, usize> = HashMap::new();
+    for statement in &mut program.statements {
+        let operands = match &mut statement.kind {
+            StatementKind::Label(name) => {
+                if is_local_label(name) {
+                    let count = defined.entry(name.clone()).or_default();
+                    *count += 1;
+                    *name = local_label_name(name, *count);
+                }
+                continue;
+            }
+            StatementKind::Instruction { operands, .. } => operands,
+            StatementKind::Directive { arguments, .. } => arguments,
+        };
+        for operand in operands {
+            let span = operand.span;
+            let expr = match &mut operand.kind {
+                OperandKind::Expression(expr) => expr,
+                OperandKind::Memory {
+                    offset: Some(expr), ..
+                } => expr,
+                _ => continue,
+            };
+            rename_references(expr, &totals, &defined, span)?;
+        }
+    }
+    Ok(())
+}
+
+fn rename_references(
+    expr: &mut Expr,
+    totals: &HashMap<String, usize>,
+    defined: &HashMap<String, usize>,
+    span: Span,
+) -> Result<(), AssembleError> {
+    match expr {
+        Expr::Symbol(symbol) => {
+            let Some((number, forward)) = symbol
+                .strip_suffix('b')
+                .map(|n| (n, false))
+                .or_else(|| symbol.strip_suffix('f').map(|n| (n, true)))
+                .filter(|(n, _)| is_local_label(n))
+            else {
+                return Ok(());
+            };
+            let count = defined.get(number).copied().unwrap_or(0) + forward as usize;
+            if count == 0 || count > totals.get(number).copied().unwrap_or(0) {
+                return Err(AssembleError::UndefinedSymbol {
+                    symbol: symbol.clone(),
+                    span,
+                });
+            }
+            *symbol = local_label_name(number, count);
+        }
+        // `%pcrel_lo(1)` refers back to the label of its auipc
+        Expr::Relocation { function, argument } if function == "pcrel_lo" => {
+            if let Expr::Number(number) = **argument {
+                **argument = Expr::Symbol(format!("{number}b"));
+            }
+            rename_references(argument, totals, defined, span)?;
+        }
+        Expr::Relocation { argument: expr, .. } | Expr::Negate(expr) | Expr::Not(expr) => {
+            rename_references(expr, totals, defined, span)?;
+        }
+        Expr::Binary { left, right, .. } => {
+            rename_references(left, totals, defined, span)?;
+            rename_references(right, totals, defined, span)?;
+        }
+        Expr::Number(_) => {}
+    }
+    Ok(())
+}
+
+fn is_local_label(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_digit())
+}
+
+// Name of the `count`th definition of numeric label `number`, as the GNU assembler spells it
+fn local_label_name(number: &str, count: usize) -> String {
+    format!(".L{number}\u{2}{count}")
+}
+
+// Target of the `%pcrel_hi` among the operands of an auipc
+fn pcrel_hi_target(operands: &[Operand]) -> Option<Operand> {
+    operands.iter().find_map(|operand| match &operand.kind {
+        OperandKind::Expression(Expr::Relocation { function, argument })
+            if function == "pcrel_hi" =>
+        {
+            Some(Operand {
+                kind: OperandKind::Expression((**argument).clone()),
+                ..operand.clone()
+            })
+        }
+        _ => None,
+    })
+}
+
+// Values that fit in `size` bytes, taken as either signed or unsigned
+fn value_range(size: u32) -> (i64, i64) {
+    let bits = 8 * size;
+    let min = i64::MIN >> (64 - bits);
+    let max = if bits == 64 {
+        i64::MAX
+    } else {
+        (1 << bits) - 1
+    };
+    (min, max)
+}
+
+// Name given to `.section` or `.pushsection`, either as is or quoted
+fn section_name(
+    directive: &str,
+    span: Span,
+    arguments: &[Operand],
+) -> Result<String, AssembleError> {
+    let Some(argument) = arguments.first() else {
+        return Err(AssembleError::WrongOperandCount {
+            mnemonic: directive.to_string(),
+            expected: 1,
+            found: 0,
+            span,
+        });
+    };
+    match &argument.kind {
+        OperandKind::Expression(Expr::Symbol(name)) => Ok(name.clone()),
+        OperandKind::String(bytes) => Ok(String::from_utf8_lossy(bytes).into_owned()),
+        _ => Err(AssembleError::InvalidOperand {
+            description: "Expected a section name".to_string(),
+            lexeme: argument.lexeme.clone(),
+            span: argument.span,
+        }),
+    }
+}
+
+// Kind of a section given by `.section name[, "flags"[, @type]]`. Like the GNU assembler, flags
+// take precedence over the name, which otherwise picks the kind of its standard section.
+fn section_kind(name: &str, arguments: &[Operand]) -> SectionKind {
+    let nobits = matches!(
+        arguments.get(2).map(|a| &a.kind),
+        Some(OperandKind::Expression(Expr::Symbol(section_type))) if section_type == "@nobits"
+    );
+    match arguments.get(1).map(|a| &a.kind) {
+        Some(OperandKind::String(flags)) if flags.contains(&b'x') => SectionKind::Text,
+        Some(OperandKind::String(flags)) if flags.contains(&b'w') && nobits => SectionKind::Bss,
+        Some(OperandKind::String(flags)) if flags.contains(&b'w') => SectionKind::Data,
+        Some(OperandKind::String(_)) => SectionKind::ReadOnlyData,
+        _ => {
+            let prefixes = [
+                (".text", SectionKind::Text),
+                (".data", SectionKind::Data),
+                (".sdata", SectionKind::Data),
+                (".rodata", SectionKind::ReadOnlyData),
+                (".srodata", SectionKind::ReadOnlyData),
+                (".bss", SectionKind::Bss),
+                (".sbss", SectionKind::Bss),
+            ];
+            prefixes
+                .iter()
+                .find(|(prefix, _)| {
+                    name.strip_prefix(prefix)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+                })
+                .map_or(SectionKind::Data, |&(_, kind)| kind)
+        }
+    }
+}
+
+fn string_argument(argument: &Operand) -> Result<&[u8], AssembleError> {
+    let OperandKind::String(bytes) = &argument.kind else {
+        return Err(AssembleError::InvalidOperand {
+            description: "Expected a string".to_string(),
+            lexeme: argument.lexeme.clone(),
+            span: argument.span,
+        });
+    };
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::assemble;
+    use super::assemble_object;
+    use super::assemble_with_options;
+    use super::Options;
+    use crate::error::AssembleError;
+    use crate::error::Span;
+    use crate::isa::Isa;
+    use crate::object::*;
+
+    fn words(bytes: &[u8]) -> Vec<u32> {
+        bytes
+            .chunks(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn backward_branch() {
+        let source = "
+loop:   addi t0 t0 -1
+        bne t0 zero loop
+";
+        let words = words(&assemble(source).unwrap());
+        assert_eq!(words[0], 0b111111111111_00101_000_00101_0010011);
+        assert_eq!(words[1], 0b1_111111_00000_00101_001_1110_1_1100011);
+    }
+
+    #[test]
+    fn forward_jump() {
+        let source = "
+        jal ra puts
+        add t0 t1 t2
+puts:   jalr zero ra 0
+";
+        let words = words(&assemble(source).unwrap());
+        assert_eq!(words[0], 0b0_0000000100_0_00000000_00001_1101111);
+    }
+
+    #[test]
+    fn label_errors() {
+        assert_eq!(
+            assemble("beq t0 t1 nowhere\n").unwrap_err(),
+            AssembleError::UndefinedSymbol {
+                symbol: "nowhere".to_string(),
+                span: Span {
+                    line: 1,
+                    column: 11,
+                    length: 7
+                }
+            }
+        );
+        assert!(matches!(
+            assemble("a: add t0 t1 t2\na: add t0 t1 t2\n").unwrap_err(),
+            AssembleError::DuplicateSymbol { .. }
+        ));
+    }
+
+    #[test]
+    fn object_symbols_and_relocations() {
+        let source = "
+        .globl main
+main:   jal ra puts
+        beq a0 zero done
+        add a0 a0 a0
+done:   jalr zero ra 0
+";
+        let object = assemble_object(source).unwrap();
+        let text = &object.sections[0];
+        assert_eq!(text.name, ".text");
+        assert_eq!(text.data.len(), 16);
+        assert_eq!(
+            text.relocations,
+            vec![Relocation {
+                offset: 0,
+                kind: RelocationKind::Jal,
+                symbol: "puts".to_string(),
+                addend: 0,
+            }]
+        );
+        let main = object.symbols.iter().find(|s| s.name == "main").unwrap();
+        assert!(main.global && main.section == Some(0));
+        let done = object.symbols.iter().find(|s| s.name == "done").unwrap();
+        assert!(!done.global && done.value == 12);
+        let puts = object.symbols.iter().find(|s| s.name == "puts").unwrap();
+        assert!(puts.global && puts.section.is_none());
+        assert!(assemble(source).is_err());
+    }
+
+    #[test]
+    fn extension_gating() {
+        let options = Options {
+            isa: Isa::parse("rv32i").unwrap(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            assemble_with_options("mul a0, a0, a1\n", &options).unwrap_err(),
+            AssembleError::MissingExtension { .. }
+        ));
+        for (source, extension) in [("csrr a0, mstatus\n", "zicsr"), ("fence.i\n", "zifencei")] {
+            match assemble_with_options(source, &options).unwrap_err() {
+                AssembleError::MissingExtension { extension: e, .. } => assert_eq!(e, extension),
+                error => panic!("Unexpected error {error}"),
+            }
+        }
+        let options = Options {
+            isa: Isa::parse("rv32i_zicsr_zifencei").unwrap(),
+            ..Default::default()
+        };
+        assert!(assemble_with_options("csrr a0, mstatus\nfence.i\n", &options).is_ok());
+        let options = Options {
+            isa: Isa::parse("rv32im").unwrap(),
+            ..Default::default()
+        };
+        assert!(assemble_with_options("mul a0, a0, a1\n", &options).is_ok());
+        assert!(matches!(
+            assemble_with_options("mulw a0, a0, a1\n", &options).unwrap_err(),
+            AssembleError::Rv64Only { .. }
+        ));
+        assert!(matches!(
+            assemble_with_options("ld a0, 0(a1)\n", &options).unwrap_err(),
+            AssembleError::Rv64Only { .. }
+        ));
+        assert!(assemble_with_options("slli a0, a0, 32\n", &options).is_err());
+        let options = Options {
+            isa: Isa::parse("rv64i").unwrap(),
+            ..Default::default()
+        };
+        assert!(assemble_with_options("ld a0, 0(a1)\nslli a0, a0, 63\n", &options).is_ok());
+        assert!(assemble_with_options("slliw a0, a0, 32\n", &options).is_err());
+        assert!(matches!(
+            assemble_with_options("mulw a0, a0, a1\n", &options).unwrap_err(),
+            AssembleError::MissingExtension { .. }
+        ));
+        let options = Options {
+            isa: Isa::parse("rv64ia").unwrap(),
+            ..Default::default()
+        };
+        assert!(
+            assemble_with_options("lr.d.aq t0, (a0)\namoadd.d a0, a1, (a2)\n", &options).is_ok()
+        );
+        assert!(matches!(
+            assemble_with_options("amoadd.d a0, a1, (a2)\n", &Options::default()).unwrap_err(),
+            AssembleError::Rv64Only { .. }
+        ));
+        // li lays out its 64-bit expansion before labels are resolved
+        let object = assemble_with_options("li a0, 0x123456789abcdef0\nend:\n", &options).unwrap();
+        assert_eq!(object.symbols[0].value, 32);
+    }
+
+    #[test]
+    fn data_directives() {
+        let source = "
+        la a0, msg
+        .byte 1, -1, 0xff
+        .half 0x1234
+        .word msg
+msg:    .string \"OK\\n\", \"\\x41\\101\"
+        .space 2, 0x5a
+        .dword -2
+";
+        let bytes = assemble(source).unwrap();
+        assert_eq!(words(&bytes[..8]), vec![0x00000517, 0x01150513]);
+        assert_eq!(&bytes[8..13], &[0x01, 0xff, 0xff, 0x34, 0x12]);
+        assert_eq!(&bytes[13..17], &17u32.to_le_bytes());
+        assert_eq!(
+            &bytes[17..],
+            b"OK\n\0AA\0ZZ\xfe\xff\xff\xff\xff\xff\xff\xff"
+        );
+        assert!(matches!(
+            assemble(".byte 256\n").unwrap_err(),
+            AssembleError::ImmediateOutOfRange { max: 255, .. }
+        ));
+        assert!(matches!(
+            assemble(".zero\n").unwrap_err(),
+            AssembleError::WrongOperandCount { .. }
+        ));
+        assert!(assemble(".string 1\n").is_err());
+    }
+
+    #[test]
+    fn constants_and_expressions() {
+        let source = "
+.equ RTC_BASE, 0x40000000
+.set WORDS, (end - start) / 4
+MASK = ~(1 << 4) & 0xff
+start:  li a0, RTC_BASE
+        li a1, WORDS
+        addi a2, a2, MASK
+        addi a3, a3, -8 >> 60 | 2 * 3
+end:
+";
+        let words = words(&assemble(source).unwrap());
+        // Constants are loaded as such, while label differences keep both halves of the load
+        assert_eq!(words[0], 0x40000537);
+        assert_eq!(words[1..3], [0x000005b7, 0x00558593]);
+        assert_eq!(words[3], 0x0ef60613);
+        assert_eq!(words[4], 0x00f68693);
+        assert!(matches!(
+            assemble(".equ A, B\n.equ B, A\nli a0, A\n").unwrap_err(),
+            AssembleError::InvalidOperand { .. }
+        ));
+        assert!(matches!(
+            assemble(".equ A, 1\nA = 2\n").unwrap_err(),
+            AssembleError::DuplicateSymbol { .. }
+        ));
+        assert!(matches!(
+            assemble(".set A, 1\n.equ A, 2\n").unwrap_err(),
+            AssembleError::DuplicateSymbol { .. }
+        ));
+        assert!(matches!(
+            assemble("addi a0, a0, 1 / 0\n").unwrap_err(),
+            AssembleError::InvalidOperand { .. }
+        ));
+    }
+
+    #[test]
+    fn reassigned_constants() {
+        // `.set` and `=` assign a symbol again, for the statements that follow
+        let source = "
+.set COUNT, 1
+        addi a0, zero, COUNT
+COUNT = COUNT + 1
+        addi a1, zero, COUNT
+.set LIMIT, COUNT * 0x1000
+.set COUNT, 0
+        li a2, LIMIT
+        addi a3, zero, COUNT
+";
+        assert_eq!(
+            words(&assemble(source).unwrap()),
+            [0x00100513, 0x00200593, 0x00002637, 0x00000693]
+        );
+    }
+
+    #[test]
+    fn relocation_operators() {
+        let source = "
+        lui a0, %hi(msg)
+        addi a0, a0, %lo(msg)
+1:      auipc t0, %pcrel_hi(msg)
+        sw t1, %pcrel_lo(1)(t0)
+        .zero 0x900
+msg:
+";
+        let object = assemble_with_options(source, &Options::default()).unwrap();
+        assert!(object.warnings.is_empty());
+        let words = words(&object.to_binary()[..16]);
+        assert_eq!(words, [0x00001537, 0x91050513, 0x00001297, 0x9062a423]);
+        let options = Options {
+            isa: Isa::parse("rv32gc").unwrap(),
+            ..Default::default()
+        };
+        let bytes = assemble_with_options("lui a0, %hi(8)\naddi a0, a0, %lo(8)\n", &options)
+            .unwrap()
+            .to_binary();
+        assert_eq!(bytes.len(), 8);
+        assert!(matches!(
+            assemble("addi a0, a0, %hi(8)\n").unwrap_err(),
+            AssembleError::InvalidOperand { .. }
+        ));
+        assert!(matches!(
+            assemble("start: nop\naddi a0, a0, %pcrel_lo(start)\n").unwrap_err(),
+            AssembleError::InvalidOperand { .. }
+        ));
+
+        let source = "
+1:      auipc t0, %pcrel_hi(ext)
+        lw t0, %pcrel_lo(1)(t0)
+        li a0, ext
+        la a1, ext
+";
+        let object = assemble_object(source).unwrap();
+        let relocations: Vec<_> = object.sections[0]
+            .relocations
+            .iter()
+            .map(|r| (r.offset, r.kind, r.symbol.as_str()))
+            .collect();
+        assert_eq!(
+            relocations,
+            [
+                (0, RelocationKind::PcrelHi20, "ext"),
+                (4, RelocationKind::PcrelLo12I, ".L1\u{2}1"),
+                (8, RelocationKind::Hi20, "ext"),
+                (12, RelocationKind::Lo12I, "ext"),
+                (16, RelocationKind::PcrelHi20, "ext"),
+                (20, RelocationKind::PcrelLo12I, ".Lpcrel_hi16"),
+            ]
+        );
+        let label = object
+            .symbols
+            .iter()
+            .find(|s| s.name == ".Lpcrel_hi16")
+            .unwrap();
+        assert!(label.section == Some(0) && label.value == 16 && !label.global);
+    }
+
+    #[test]
+    fn numeric_local_labels() {
+        let source = "
+1:      addi a0, a0, 1
+        bnez a0, 1b
+        j 1f
+        li a1, 0b101
+1:      beq a0, a1, 1b
+";
+        let words = words(&assemble(source).unwrap());
+        assert_eq!(words[1], 0xfe051ee3);
+        assert_eq!(words[2], 0x0080006f);
+        assert_eq!(words[3], 0x00500593);
+        assert_eq!(words[4], 0x00b50063);
+        assert_eq!(
+            assemble("1: j 1f\n").unwrap_err(),
+            AssembleError::UndefinedSymbol {
+                symbol: "1f".to_string(),
+                span: Span {
+                    line: 1,
+                    column: 6,
+                    length: 2
+                }
+            }
+        );
+        assert!(assemble("j 2b\n2:\n").is_err());
+
+        // Only the label an external %pcrel_lo refers back to stays in the symbol table
+        let source = "
+1:      auipc t0, %pcrel_hi(ext)
+        addi t0, t0, %pcrel_lo(1b)
+2:      j 2b
+.Lend:  j .Lend
+";
+        let object = assemble_object(source).unwrap();
+        let names: Vec<_> = object.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, [".L1\u{2}1", "ext"]);
+    }
+
+    #[test]
+    fn trap_program() {
+        let options = Options {
+            isa: Isa::parse("rv64g").unwrap(),
+            relocatable: true,
+        };
+        let object = assemble_with_options(include_str!("testdata/trap.s"), &options).unwrap();
+        let symbol = |name: &str| {
+            let symbol = object.symbols.iter().find(|s| s.name == name).unwrap();
+            (symbol.section, symbol.value)
+        };
+        let text = &object.sections[0].data;
+        let word = |offset: u64| words(&text[offset as usize..offset as usize + 4])[0];
+        let (_, mtvec) = symbol("mtvec");
+        assert_eq!(word(4), 0x00028293 | (mtvec as u32) << 20);
+        let (_, loop_start) = symbol("loop");
+        assert_eq!(word(loop_start), 0x10500073);
+        assert_eq!(symbol("pass_msg"), (Some(2), 0));
+        assert_eq!(symbol("fail_msg"), (Some(2), 6));
+        assert_eq!(object.sections[2].data, b"PASS\n\0FAIL\n\0");
+        // Left to the linker
+        assert_eq!(symbol("puts").0, None);
+        assert_eq!(symbol("shutdown").0, None);
+    }
+
+    #[test]
+    fn sections() {
+        let source = "
+        la a0, msg
+        .section .rodata
+msg:    .string \"hi\"
+        .data
+        .pushsection .text.init, \"ax\", @progbits
+start:  j start
+        .popsection
+count:  .word 7
+        .bss
+buffer: .zero 4
+";
+        // Sections follow one another in the flat binary, `.text.init` aligned after `.rodata`
+        let binary = assemble(source).unwrap();
+        assert_eq!(words(&binary[..8]), [0x00000517, 0x00c50513]);
+        assert_eq!(binary[8..], [7, 0, 0, 0, b'h', b'i', 0, 0, 0x6f, 0, 0, 0]);
+
+        let object = assemble_object(source).unwrap();
+        let names: Vec<_> = object.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, [".text", ".data", ".rodata", ".bss", ".text.init"]);
+        assert_eq!(object.sections[4].kind, SectionKind::Text);
+        let symbol = |name: &str| {
+            let symbol = object.symbols.iter().find(|s| s.name == name).unwrap();
+            (symbol.section, symbol.value)
+        };
+        assert_eq!(symbol("msg"), (Some(2), 0));
+        assert_eq!(symbol("count"), (Some(1), 0));
+        assert_eq!(symbol("buffer"), (Some(3), 0));
+        assert_eq!(symbol("start"), (Some(4), 0));
+        // `msg` is only placed relative to the code by the linker
+        let relocations: Vec<_> = object.sections[0]
+            .relocations
+            .iter()
+            .map(|r| (r.offset, r.kind, r.symbol.as_str()))
+            .collect();
+        assert_eq!(
+            relocations,
+            [
+                (0, RelocationKind::PcrelHi20, "msg"),
+                (4, RelocationKind::PcrelLo12I, ".Lpcrel_hi0")
+            ]
+        );
+        assert_eq!(object.sections[4].data, [0x6f, 0, 0, 0]);
+
+        assert!(matches!(
+            assemble(".bss\nnop\n"),
+            Err(AssembleError::BssContents { .. })
+        ));
+        assert!(matches!(
+            assemble(".bss\n.byte 1\n"),
+            Err(AssembleError::BssContents { .. })
+        ));
+        assert!(assemble(".popsection\n").is_err());
+    }
+
+    #[test]
+    fn data_relocations() {
+        let source = "
+start:  nop
+        .data
+table:  .word start, end - start, 5
+        .dword handler
+        .text
+end:
+";
+        let object = assemble_object(source).unwrap();
+        // Addresses are left to the linker, while a label difference is a constant
+        assert_eq!(
+            object.sections[1].data,
+            [0, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        let relocations: Vec<_> = object.sections[1]
+            .relocations
+            .iter()
+            .map(|r| (r.offset, r.kind, r.symbol.as_str()))
+            .collect();
+        assert_eq!(
+            relocations,
+            [
+                (0, RelocationKind::Data32, "start"),
+                (12, RelocationKind::Data64, "handler")
+            ]
+        );
+        assert!(object.sections[0].relocations.is_empty());
+        // The flat binary holds the addresses themselves
+        let binary = assemble("start: nop\n.data\n.word start, end\n.text\nend:\n").unwrap();
+        assert_eq!(words(&binary[4..]), [0, 4]);
+        assert!(matches!(
+            assemble_object(".bss\n.word start\n.text\nstart:\n"),
+            Err(AssembleError::BssContents { .. })
+        ));
+        // No relocation is narrow enough for an address in a byte or halfword
+        for source in [
+            ".data\nx: .word 0\n.half x\n",
+            ".data\nx: .word 0\n.byte x+1\n",
+        ] {
+            assert!(matches!(
+                assemble_object(source),
+                Err(AssembleError::InvalidOperand { .. })
+            ));
+        }
+        let object = assemble_object(".data\nx: .word 0\ny: .half y - x\n").unwrap();
+        assert_eq!(object.sections[1].data, [0, 0, 0, 0, 4, 0]);
+    }
+
+    #[test]
+    fn relocation_addends() {
+        let source = "
+.set OFFSET, 4
+start:  la a0, msg + OFFSET
+        lui a1, %hi(msg+4)
+        sw a2, %lo(msg - 2)(a1)
+        call ext+8
+        la a3, start+8
+        .data
+        .word msg+4, 2 + ext
+        .section .rodata
+msg:    .string \"hi\"
+";
+        let object = assemble_object(source).unwrap();
+        let relocations = |section: usize| -> Vec<_> {
+            object.sections[section]
+                .relocations
+                .iter()
+                .map(|r| (r.offset, r.kind, r.symbol.as_str(), r.addend))
+                .collect()
+        };
+        assert_eq!(
+            relocations(0),
+            [
+                (0, RelocationKind::PcrelHi20, "msg", 4),
+                (4, RelocationKind::PcrelLo12I, ".Lpcrel_hi0", 0),
+                (8, RelocationKind::Hi20, "msg", 4),
+                (12, RelocationKind::Lo12S, "msg", -2),
+                (16, RelocationKind::Call, "ext", 8)
+            ]
+        );
+        assert_eq!(
+            relocations(1),
+            [
+                (0, RelocationKind::Data32, "msg", 4),
+                (4, RelocationKind::Data32, "ext", 2)
+            ]
+        );
+        // A label in the same section is still resolved by the assembler
+        let text = &object.sections[0].data;
+        assert_eq!(words(&text[24..32]), [0x00000697, 0xff068693]);
+    }
+
+    #[test]
+    fn alignment_and_fill() {
+        let source = "
+start:  addi a0, a0, 1
+        .byte 1
+        .p2align 2
+trap:   j trap
+        .data
+        .byte 1
+        .balign 4, 0xff
+        .fill 2, 2, 0x1234
+        .skip 2, 0x55
+";
+        assert_eq!(
+            assemble(source).unwrap(),
+            [
+                0x13, 0x05, 0x15, 0x00, 1, 0, 0, 0, 0x6f, 0, 0, 0, 1, 0xff, 0xff, 0xff, 0x34, 0x12,
+                0x34, 0x12, 0x55, 0x55
+            ]
+        );
+        let object = assemble_object(source).unwrap();
+        assert_eq!(object.sections[1].alignment, 4);
+
+        // Code is padded with c.nop and nop once compressed
+        let options = Options {
+            isa: Isa::parse("rv32gc").unwrap(),
+            ..Default::default()
+        };
+        let object = assemble_with_options("addi a0, a0, 1\n.align 3\nret\n", &options).unwrap();
+        assert_eq!(
+            object.to_binary(),
+            [0x05, 0x05, 0x01, 0x00, 0x13, 0x00, 0x00, 0x00, 0x82, 0x80]
+        );
+        assert_eq!(object.sections[0].alignment, 8);
+
+        // Alignment is skipped when it would take more than the maximum
+        assert_eq!(
+            assemble(".byte 1\n.balign 8, 0, 3\n.byte 2\n").unwrap(),
+            [1, 2]
+        );
+        assert!(assemble(".balign 3\n").is_err());
+        assert!(assemble(".fill 1, 9, 0\n").is_err());
+    }
+
+    #[test]
+    fn listing_and_warnings() {
+        let object = assemble_object("start:\n  lui t0 0x12345\n  .fill 2, 1, 0x1234\n").unwrap();
+        assert_eq!(
+            object.listing,
+            vec![
+                ListingLine {
+                    line: 2,
+                    section: 0,
+                    offset: 0,
+                    size: 4
+                },
+                ListingLine {
+                    line: 3,
+                    section: 0,
+                    offset: 4,
+                    size: 2
+                },
+            ]
+        );
+        assert_eq!(
+            object.warnings,
+            vec![AssembleError::ImmediateTruncated {
+                lexeme: "0x1234".to_string(),
+                value: 0x1234,
+                truncated: 0x34,
+                span: Span {
+                    line: 3,
+                    column: 15,
+                    length: 6
+                }
+            }]
+        );
+        // The upper immediate is bits 31:12 of the value loaded
+        assert_eq!(object.sections[0].data[..4], 0x123452b7u32.to_le_bytes());
+        assert!(assemble("lui t0, 0x100000\n").is_err());
+    }
+}
@@ -0,0 +1,393 @@
+use crate::error::AssembleError;
+use crate::error::Span;
+use crate::expr::evaluate_expr;
+use crate::expr::is_address;
+use crate::inst::*;
+use crate::invalid_operand;
+use crate::isa::Xlen;
+use crate::parser::ast::*;
+use crate::reg::*;
+use crate::Context;
+
+// Compressed instruction named `mnemonic` and the set it belongs to
+pub fn find_compressed(
+    mnemonic: &str,
+) -> Option<(
+    &'static CompressedSet<'static>,
+    &'static CompressedInstruction<'static>,
+)> {
+    COMPRESSED_SETS.iter().find_map(|set| {
+        set.instructions
+            .iter()
+            .find(|i| i.asm_string == mnemonic)
+            .map(|i| (set, i))
+    })
+}
+
+/// Encode an explicit `c.` instruction
+pub fn encode_compressed(
+    mnemonic: &str,
+    mnemonic_span: Span,
+    operands: &[Operand],
+    context: &mut Context,
+) -> Result<u32, AssembleError> {
+    let Some((set, inst)) = find_compressed(mnemonic) else {
+        return Err(AssembleError::UnknownMnemonic {
+            mnemonic: mnemonic.to_string(),
+            span: mnemonic_span,
+        });
+    };
+    for extension in ["c", set.extension] {
+        if !context.isa.has(extension) {
+            return Err(AssembleError::MissingExtension {
+                mnemonic: mnemonic.to_string(),
+                extension: extension.to_string(),
+                span: mnemonic_span,
+            });
+        }
+    }
+    match set.xlen {
+        Some(Xlen::Rv64) if context.isa.xlen != Xlen::Rv64 => {
+            return Err(AssembleError::Rv64Only {
+                mnemonic: mnemonic.to_string(),
+                span: mnemonic_span,
+            })
+        }
+        Some(Xlen::Rv32) if context.isa.xlen != Xlen::Rv32 => {
+            return Err(AssembleError::Rv32Only {
+                mnemonic: mnemonic.to_string(),
+                span: mnemonic_span,
+            })
+        }
+        _ => {}
+    }
+    if inst.num_of_arguments != operands.len() {
+        return Err(AssembleError::WrongOperandCount {
+            mnemonic: mnemonic.to_string(),
+            expected: inst.num_of_arguments,
+            found: operands.len(),
+            span: mnemonic_span,
+        });
+    }
+    encode(inst, mnemonic_span, operands, context)
+}
+
+/// Compressed form of a base instruction, if its operands fit one
+pub fn compress(mnemonic: &str, operands: &[Operand], context: &Context) -> Option<u32> {
+    let instructions = available_sets(context.isa)
+        .flat_map(|set| set.instructions.iter())
+        .flat_map(|inst| inst.expansions.iter().map(move |e| (inst, e)))
+        .filter(|(_, e)| e.base == mnemonic && e.arguments.len() == operands.len());
+    for (inst, expansion) in instructions {
+        // Bind the operands of the compressed instruction to those of the base instruction
+        let mut bound: Vec<Option<&Operand>> = vec![None; inst.num_of_arguments];
+        let matches = expansion
+            .arguments
+            .iter()
+            .zip(operands)
+            .all(|(arg, operand)| match arg {
+                ExpandArg::Operand(index) => match bound[*index] {
+                    Some(previous) => same_operand(previous, operand, context),
+                    None => {
+                        bound[*index] = Some(operand);
+                        true
+                    }
+                },
+                ExpandArg::Reg(name) => operand_register_number(operand) == any_register(name),
+                ExpandArg::Imm(imm) => matches!(
+                    &operand.kind,
+                    OperandKind::Expression(expr)
+                        if evaluate_expr(expr, operand, context.symbols).ok() == Some(*imm)
+                ),
+            });
+        if !matches {
+            continue;
+        }
+        let compressed_operands: Vec<Operand> = bound.into_iter().flatten().cloned().collect();
+        if let Ok(inst_bits) = encode(inst, Span::default(), &compressed_operands, context) {
+            return Some(inst_bits);
+        }
+    }
+    None
+}
+
+fn available_sets(isa: &crate::Isa) -> impl Iterator<Item = &'static CompressedSet<'static>> + '_ {
+    COMPRESSED_SETS.iter().filter(move |set| {
+        isa.has("c") && isa.has(set.extension) && set.xlen.is_none_or(|xlen| xlen == isa.xlen)
+    })
+}
+
+fn encode(
+    inst: &CompressedInstruction,
+    mnemonic_span: Span,
+    operands: &[Operand],
+    context: &Context,
+) -> Result<u32, AssembleError> {
+    let mut inst_bits = inst.opcode_func;
+    for (arg, operand) in inst.arguments.iter().zip(operands) {
+        match arg {
+            CompressedArgs::Rd | CompressedArgs::Rs1 | CompressedArgs::Rs2 => {
+                inst_bits |= register_bits(inst, arg, operand, &REG_FILE)?
+            }
+            CompressedArgs::FRd | CompressedArgs::FRs2 => {
+                inst_bits |= register_bits(inst, arg, operand, &F_REG_FILE)?
+            }
+            CompressedArgs::Sp => {
+                if operand_register_number(operand) != Some(2) {
+                    return Err(invalid_operand(operand, "Expected sp"));
+                }
+            }
+            CompressedArgs::Imm => {
+                let OperandKind::Expression(expr) = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected an immediate"));
+                };
+                inst_bits |= imm_bits(inst, expr, operand, context)?;
+            }
+            CompressedArgs::Shamt => {
+                let OperandKind::Expression(expr) = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected a shift amount"));
+                };
+                let shamt = evaluate_expr(expr, operand, context.symbols)?;
+                let max = match context.isa.xlen {
+                    Xlen::Rv32 => SHAMT_MAX,
+                    Xlen::Rv64 => SHAMT_MAX_RV64,
+                };
+                if !(1..=max).contains(&shamt) {
+                    return Err(AssembleError::ImmediateOutOfRange {
+                        lexeme: operand.lexeme.clone(),
+                        value: shamt,
+                        min: 1,
+                        max,
+                        span: operand.span,
+                    });
+                }
+                let shamt = shamt as u32;
+                inst_bits |= ((shamt & 0x20) << 7) | ((shamt & 0x1F) << 2);
+            }
+            CompressedArgs::Mem | CompressedArgs::SpMem => {
+                let OperandKind::Memory { offset, base } = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected a memory operand"));
+                };
+                let base_operand = Operand {
+                    kind: OperandKind::Register(base.clone()),
+                    lexeme: base.clone(),
+                    span: operand.span,
+                };
+                if let CompressedArgs::Mem = arg {
+                    inst_bits |=
+                        register_bits(inst, &CompressedArgs::Rs1, &base_operand, &REG_FILE)?;
+                } else if operand_register_number(&base_operand) != Some(2) {
+                    return Err(invalid_operand(operand, "Expected an offset from sp"));
+                }
+                inst_bits |= match offset {
+                    Some(expr) => imm_bits(inst, expr, operand, context)?,
+                    None => 0,
+                };
+            }
+            CompressedArgs::NoArg => break,
+        }
+    }
+
+    // Encodings of some operands belong to another instruction, e.g. c.lui with sp is c.addi16sp
+    let decoded = available_sets(context.isa)
+        .flat_map(|set| set.instructions.iter())
+        .find(|i| inst_bits & i.mask == i.opcode_func);
+    if decoded.is_none_or(|decoded| decoded.asm_string != inst.asm_string) {
+        return Err(AssembleError::InvalidOperand {
+            description: "Operands are reserved for another instruction".to_string(),
+            lexeme: inst.asm_string.to_string(),
+            span: mnemonic_span,
+        });
+    }
+    Ok(inst_bits)
+}
+
+fn register_bits(
+    inst: &CompressedInstruction,
+    arg: &CompressedArgs,
+    operand: &Operand,
+    reg_file: &[Register],
+) -> Result<u32, AssembleError> {
+    let invalid_register = || AssembleError::InvalidRegister {
+        lexeme: operand.lexeme.clone(),
+        span: operand.span,
+    };
+    let OperandKind::Register(name) = &operand.kind else {
+        return Err(invalid_register());
+    };
+    let number = register_number(name, reg_file).ok_or_else(invalid_register)?;
+    let (position, prime) = inst.format.register_field(arg);
+    if prime {
+        // Only x8-x15 and f8-f15 fit the 3-bit register fields
+        if !(8..=15).contains(&number) {
+            return Err(invalid_operand(
+                operand,
+                "Expected one of the registers 8 to 15",
+            ));
+        }
+        return Ok((number - 8) << position);
+    }
+    if inst.nonzero_reg && number == 0 {
+        return Err(invalid_operand(operand, "Register must not be zero"));
+    }
+    Ok(number << position)
+}
+
+fn imm_bits(
+    inst: &CompressedInstruction,
+    expr: &Expr,
+    operand: &Operand,
+    context: &Context,
+) -> Result<u32, AssembleError> {
+    let layout = inst.imm_layout.unwrap();
+    let mut imm = evaluate_expr(expr, operand, context.symbols)?;
+    if layout.pc_relative && is_address(expr, context.symbols) {
+        imm -= context.pc as i64;
+    }
+    let alignment = layout.imm_alignment();
+    let (min, max) = layout.imm_range();
+    if alignment >= 1 << 12 {
+        // Upper immediates are given as the 20-bit field of lui, which loads bits 31:12 of a
+        // sign-extended value. Ranges are reported in the same terms.
+        if !(0..1 << 20).contains(&imm) || !(min..=max).contains(&((imm << 44) >> 32)) {
+            return Err(AssembleError::ImmediateOutOfRange {
+                lexeme: operand.lexeme.clone(),
+                value: imm,
+                min: min >> 12,
+                max: max >> 12,
+                span: operand.span,
+            });
+        }
+        imm = (imm << 44) >> 32;
+    }
+    if imm < min || imm > max {
+        return Err(AssembleError::ImmediateOutOfRange {
+            lexeme: operand.lexeme.clone(),
+            value: imm,
+            min,
+            max,
+            span: operand.span,
+        });
+    }
+    if imm % alignment != 0 {
+        return Err(AssembleError::MisalignedImmediate {
+            lexeme: operand.lexeme.clone(),
+            value: imm,
+            alignment,
+            span: operand.span,
+        });
+    }
+    if inst.nonzero_imm && imm == 0 {
+        return Err(invalid_operand(operand, "Immediate must not be zero"));
+    }
+    Ok(layout.scatter(imm))
+}
+
+fn same_operand(a: &Operand, b: &Operand, context: &Context) -> bool {
+    match (&a.kind, &b.kind) {
+        (OperandKind::Register(_), OperandKind::Register(_)) => {
+            operand_register_number(a) == operand_register_number(b)
+        }
+        (OperandKind::Expression(x), OperandKind::Expression(y)) => {
+            let x = evaluate_expr(x, a, context.symbols).ok();
+            x.is_some() && x == evaluate_expr(y, b, context.symbols).ok()
+        }
+        _ => false,
+    }
+}
+
+fn operand_register_number(operand: &Operand) -> Option<u32> {
+    match &operand.kind {
+        OperandKind::Register(name) => any_register(name),
+        _ => None,
+    }
+}
+
+fn any_register(name: &str) -> Option<u32> {
+    register_number(name, &REG_FILE).or_else(|| register_number(name, &F_REG_FILE))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assemble_with_options;
+    use crate::error::AssembleError;
+    use crate::isa::Isa;
+    use crate::Options;
+
+    fn halfwords(source: &str, isa: &str) -> Result<Vec<u16>, AssembleError> {
+        let options = Options {
+            isa: Isa::parse(isa).unwrap(),
+            ..Default::default()
+        };
+        Ok(assemble_with_options(source, &options)?
+            .to_binary()
+            .chunks(2)
+            .map(|c| u16::from_le_bytes(c.try_into().unwrap()))
+            .collect())
+    }
+
+    #[test]
+    fn explicit() {
+        // Encodings checked against llvm-mc
+        let cases = [
+            ("c.addi4spn a0, sp, 16", 0x0808),
+            ("c.lw a1, 4(a2)", 0x424c),
+            ("c.addi a0, -32", 0x1501),
+            ("c.jal 2046", 0x2ffd),
+            ("c.addi16sp sp, -512", 0x7101),
+            ("c.lui a5, 0xfffff", 0x77fd),
+            ("c.srli s0, 31", 0x807d),
+            ("c.beqz s1, 254", 0xccfd),
+            ("c.lwsp ra, 252(sp)", 0x50fe),
+            ("c.mv a0, t6", 0x857e),
+            ("c.fsdsp f1, 8(sp)", 0xa406),
+        ];
+        for (asm_line, expected_result) in cases {
+            assert_eq!(halfwords(asm_line, "rv32gc").unwrap(), [expected_result]);
+        }
+        assert_eq!(halfwords("c.ld a0, 248(a1)", "rv64gc").unwrap(), [0x7de8]);
+    }
+
+    #[test]
+    fn invalid_operands() {
+        assert!(matches!(
+            halfwords("c.addi a0, 1", "rv32i").unwrap_err(),
+            AssembleError::MissingExtension { .. }
+        ));
+        assert!(matches!(
+            halfwords("c.jal 0", "rv64gc").unwrap_err(),
+            AssembleError::Rv32Only { .. }
+        ));
+        // Only s0, s1 and a0-a5 fit the 3-bit register fields
+        assert!(halfwords("c.lw t0, 0(a0)", "rv32gc").is_err());
+        assert!(halfwords("c.lw a0, 2(a0)", "rv32gc").is_err());
+        assert!(halfwords("c.addi a0, 0", "rv32gc").is_err());
+        assert!(halfwords("c.mv a0, zero", "rv32gc").is_err());
+        assert!(halfwords("c.slli a0, 32", "rv32gc").is_err());
+        // The encoding of c.lui with sp belongs to c.addi16sp
+        assert!(halfwords("c.lui sp, 0x1", "rv32gc").is_err());
+    }
+
+    #[test]
+    fn automatic() {
+        let source = "
+loop:   addi sp, sp, -16
+        mv a0, a1
+        add a0, a1, a0
+        addi a0, a0, 100
+        bnez a0, loop
+        ret
+";
+        assert_eq!(
+            halfwords(source, "rv32imc").unwrap(),
+            [0x1141, 0x852e, 0x952e, 0x0513, 0x0645, 0xf97d, 0x8082]
+        );
+        // Without C every instruction keeps its full size
+        assert_eq!(halfwords(source, "rv32im").unwrap().len(), 12);
+        // The auipc and jalr pair of call stays whole for the linker
+        assert_eq!(
+            halfwords("call end\nend:\n", "rv32imc").unwrap(),
+            [0x0097, 0x0000, 0x80e7, 0x0080]
+        );
+    }
+}
@@ -0,0 +1,352 @@
+use crate::error::DisassembleError;
+use crate::inst::*;
+use crate::isa::Xlen;
+use crate::reg::{float_register_name, register_name, RegisterNames};
+
+/// Disassemble an RV32 instruction word
+pub fn disassemble_word(word: u32) -> Result<String, DisassembleError> {
+    disassemble_word_for(word, Xlen::Rv32)
+}
+
+pub fn disassemble_word_for(word: u32, xlen: Xlen) -> Result<String, DisassembleError> {
+    disassemble_word_with(word, xlen, RegisterNames::Abi)
+}
+
+/// Disassemble an instruction word, printing registers in the given naming style
+pub fn disassemble_word_with(
+    word: u32,
+    xlen: Xlen,
+    names: RegisterNames,
+) -> Result<String, DisassembleError> {
+    if instruction_size(word) == 2 {
+        return disassemble_compressed(word, xlen, names);
+    }
+
+    // Find instruction
+    let Some((set, inst)) = INSTRUCTION_SETS
+        .iter()
+        .filter(|set| set.xlen.is_none_or(|set_xlen| set_xlen == xlen))
+        .flat_map(|set| set.instructions.iter().map(move |i| (set, i)))
+        .find(|(_, i)| word & i.mask == i.opcode_func)
+    else {
+        return Err(DisassembleError::UnknownInstruction(word));
+    };
+    let mut mnemonic = inst.asm_string.to_string();
+    if set.extension == "a" {
+        if let Some((suffix, _)) = ORDERINGS
+            .iter()
+            .find(|(_, bits)| word & (0b11 << 25) == *bits)
+        {
+            mnemonic += suffix;
+        }
+    }
+
+    // Format arguments
+    let mut operands = vec![];
+    for arg in inst.arguments.iter().take(inst.num_of_arguments) {
+        let operand = match arg {
+            AsmArgs::RegDest => register_name((word >> 7) & 0x1F, names).to_string(),
+            AsmArgs::RegSrc1 => register_name((word >> 15) & 0x1F, names).to_string(),
+            AsmArgs::RegSrc2 => register_name((word >> 20) & 0x1F, names).to_string(),
+            AsmArgs::Imm => format_imm(get_imm(word, &inst.inst_type), &inst.inst_type),
+            AsmArgs::Shamt => {
+                let shamt = (word >> 20) & 0x3F;
+                let max = match xlen {
+                    Xlen::Rv32 => SHAMT_MAX,
+                    Xlen::Rv64 => SHAMT_MAX_RV64,
+                };
+                if shamt as i64 > max {
+                    return Err(DisassembleError::InvalidOperand {
+                        word,
+                        description: "Shift amount out of range".to_string(),
+                    });
+                }
+                shamt.to_string()
+            }
+            AsmArgs::ShamtW => ((word >> 20) & 0x1F).to_string(),
+            AsmArgs::Mem => {
+                let imm = get_imm(word, &inst.inst_type);
+                imm.to_string() + "(" + register_name((word >> 15) & 0x1F, names) + ")"
+            }
+            AsmArgs::Pred => format_fence_set((word >> 24) & 0xF),
+            AsmArgs::Succ => format_fence_set((word >> 20) & 0xF),
+            AsmArgs::Csr => {
+                let address = (word >> 20) as u16;
+                match CSR_NAMES.iter().find(|(_, csr)| *csr == address) {
+                    Some((name, _)) => name.to_string(),
+                    None => format!("{address:#x}"),
+                }
+            }
+            AsmArgs::Uimm => ((word >> 15) & 0x1F).to_string(),
+            AsmArgs::Addr => format!("({})", register_name((word >> 15) & 0x1F, names)),
+            AsmArgs::FRegDest => float_register_name((word >> 7) & 0x1F, names).to_string(),
+            AsmArgs::FRegSrc1 => float_register_name((word >> 15) & 0x1F, names).to_string(),
+            AsmArgs::FRegSrc2 => float_register_name((word >> 20) & 0x1F, names).to_string(),
+            AsmArgs::FRegSrc3 => float_register_name((word >> 27) & 0x1F, names).to_string(),
+            AsmArgs::NoArg => break,
+        };
+        operands.push(operand);
+    }
+
+    // The rounding mode is only printed when it differs from the default
+    if takes_rounding_mode(inst) {
+        let bits = (word >> 12) & 0x7;
+        let Some((mode, _)) = ROUNDING_MODES.iter().find(|(_, mode)| *mode == bits) else {
+            return Err(DisassembleError::InvalidOperand {
+                word,
+                description: "Reserved rounding mode".to_string(),
+            });
+        };
+        if bits != default_rounding_mode(inst) {
+            operands.push(mode.to_string());
+        }
+    }
+
+    if operands.is_empty() {
+        Ok(mnemonic)
+    } else {
+        Ok(mnemonic + " " + &operands.join(", "))
+    }
+}
+
+// 16-bit instructions are given in the low half of `word`
+fn disassemble_compressed(
+    word: u32,
+    xlen: Xlen,
+    names: RegisterNames,
+) -> Result<String, DisassembleError> {
+    let Some(inst) = COMPRESSED_SETS
+        .iter()
+        .filter(|set| set.xlen.is_none_or(|set_xlen| set_xlen == xlen))
+        .flat_map(|set| set.instructions.iter())
+        .find(|i| word >> 16 == 0 && word & i.mask == i.opcode_func)
+    else {
+        return Err(DisassembleError::UnknownInstruction(word));
+    };
+    let reserved = || DisassembleError::InvalidOperand {
+        word,
+        description: format!("Reserved operand of '{}'", inst.asm_string),
+    };
+    let register = |arg: &CompressedArgs| {
+        let (position, prime) = inst.format.register_field(arg);
+        if prime {
+            ((word >> position) & 0x7) + 8
+        } else {
+            (word >> position) & 0x1F
+        }
+    };
+    let imm = || {
+        let layout = inst.imm_layout.unwrap();
+        let imm = layout.gather(word);
+        if inst.nonzero_imm && imm == 0 {
+            return Err(reserved());
+        }
+        if layout.imm_alignment() >= 1 << 12 {
+            Ok(format_imm(
+                ((imm >> 12) & 0xFFFFF) as i32,
+                &InstructionType::U,
+            ))
+        } else {
+            Ok(imm.to_string())
+        }
+    };
+
+    let mut operands = vec![];
+    for arg in inst.arguments.iter().take(inst.num_of_arguments) {
+        let operand = match arg {
+            CompressedArgs::Rd | CompressedArgs::Rs1 | CompressedArgs::Rs2 => {
+                let number = register(arg);
+                let (_, prime) = inst.format.register_field(arg);
+                if inst.nonzero_reg && !prime && number == 0 {
+                    return Err(reserved());
+                }
+                register_name(number, names).to_string()
+            }
+            CompressedArgs::FRd | CompressedArgs::FRs2 => {
+                float_register_name(register(arg), names).to_string()
+            }
+            CompressedArgs::Sp => register_name(2, names).to_string(),
+            CompressedArgs::Imm => imm()?,
+            CompressedArgs::Shamt => {
+                let shamt = ((word >> 7) & 0x20) | ((word >> 2) & 0x1F);
+                let max = match xlen {
+                    Xlen::Rv32 => SHAMT_MAX,
+                    Xlen::Rv64 => SHAMT_MAX_RV64,
+                };
+                if shamt == 0 || shamt as i64 > max {
+                    return Err(reserved());
+                }
+                shamt.to_string()
+            }
+            CompressedArgs::Mem => {
+                format!(
+                    "{}({})",
+                    imm()?,
+                    register_name(register(&CompressedArgs::Rs1), names)
+                )
+            }
+            CompressedArgs::SpMem => format!("{}({})", imm()?, register_name(2, names)),
+            CompressedArgs::NoArg => break,
+        };
+        operands.push(operand);
+    }
+    if operands.is_empty() {
+        Ok(inst.asm_string.to_string())
+    } else {
+        Ok(inst.asm_string.to_string() + " " + &operands.join(", "))
+    }
+}
+
+fn format_imm(imm: i32, inst_type: &InstructionType) -> String {
+    match inst_type {
+        // Upper immediates are easier to read in hexadecimal
+        InstructionType::U => format!("{:#x}", imm),
+        _ => imm.to_string(),
+    }
+}
+
+fn format_fence_set(bits: u32) -> String {
+    if bits == 0 {
+        return "0".to_string();
+    }
+    FENCE_ACCESSES
+        .iter()
+        .enumerate()
+        .filter(|(position, _)| bits & (1 << (FENCE_ACCESSES.len() - 1 - position)) != 0)
+        .map(|(_, access)| access)
+        .collect()
+}
+
+fn get_imm(word: u32, inst_type: &InstructionType) -> i32 {
+    let signed_word = word as i32;
+    match inst_type {
+        InstructionType::I => signed_word >> 20,
+        InstructionType::S => {
+            let imm_11_5 = signed_word >> 25;
+            let imm_4_0 = ((word >> 7) & 0x1F) as i32;
+            (imm_11_5 << 5) | imm_4_0
+        }
+        InstructionType::B => {
+            let imm_12 = signed_word >> 31;
+            let imm_11 = ((word >> 7) & 0x1) as i32;
+            let imm_10_5 = ((word >> 25) & 0x3F) as i32;
+            let imm_4_1 = ((word >> 8) & 0xF) as i32;
+            (imm_12 << 12) | (imm_11 << 11) | (imm_10_5 << 5) | (imm_4_1 << 1)
+        }
+        InstructionType::U => (word >> 12) as i32,
+        InstructionType::J => {
+            let imm_20 = signed_word >> 31;
+            let imm_19_12 = ((word >> 12) & 0xFF) as i32;
+            let imm_11 = ((word >> 20) & 0x1) as i32;
+            let imm_10_1 = ((word >> 21) & 0x3FF) as i32;
+            (imm_20 << 20) | (imm_19_12 << 12) | (imm_11 << 11) | (imm_10_1 << 1)
+        }
+        InstructionType::R | InstructionType::R4 => {
+            panic!("R-type instruction should've not entered here")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::disassemble_word;
+    use super::disassemble_word_for;
+    use super::disassemble_word_with;
+    use crate::error::DisassembleError;
+    use crate::isa::Xlen;
+    use crate::reg::RegisterNames;
+
+    #[test]
+    fn instruction_types() {
+        let cases = [
+            (0b111111111101_00110_000_00111_0010011, "addi t2, t1, -3"),
+            (0b11111111111111111111_00111_0110111, "lui t2, 0xfffff"),
+            (0b0000000_00101_00110_000_00111_0110011, "add t2, t1, t0"),
+            (0b1_1111111110_1_11111111_00111_1101111, "jal t2, -4"),
+            (0b1_111111_00110_00111_000_1110_1_1100011, "beq t2, t1, -4"),
+            (0b111111111101_00110_010_00111_0000011, "lw t2, -3(t1)"),
+            (0b1111111_00111_00110_010_11101_0100011, "sw t2, -3(t1)"),
+            (0b0100000_11111_01000_101_01000_0010011, "srai s0, s0, 31"),
+            (0x0310000f, "fence rw, w"),
+            (0x8330000f, "fence.tso"),
+            (0x00000073, "ecall"),
+            (0x02c58533, "mul a0, a1, a2"),
+            (0x100522af, "lr.w t0, (a0)"),
+            (0x0eb6252f, "amoswap.w.aqrl a0, a1, (a2)"),
+            (0x30529073, "csrrw zero, mtvec, t0"),
+            (0x7c0fd573, "csrrwi a0, 0x7c0, 31"),
+            (0x68c5f543, "fmadd.s fa0, fa1, fa2, fa3"),
+            (0x00c58553, "fadd.s fa0, fa1, fa2, rne"),
+            (0xd2050553, "fcvt.d.w fa0, a0"),
+            (0xfe913e27, "fsd fs1, -4(sp)"),
+            (0x424c, "c.lw a1, 4(a2)"),
+            (0x77fd, "c.lui a5, 0xfffff"),
+            (0xccfd, "c.beqz s1, 254"),
+            (0x0001, "c.nop"),
+        ];
+        for (word, asm_line) in cases {
+            assert_eq!(disassemble_word(word).unwrap(), asm_line);
+        }
+    }
+
+    #[test]
+    fn rv64() {
+        let cases = [
+            (0x00053583, "ld a1, 0(a0)"),
+            (0x00b53023, "sd a1, 0(a0)"),
+            (0x03f29293, "slli t0, t0, 63"),
+            (0x01f2929b, "slliw t0, t0, 31"),
+            (0x02b5053b, "mulw a0, a0, a1"),
+            (0x027352bb, "divuw t0, t1, t2"),
+            (0x02e6e63b, "remw a2, a3, a4"),
+            (0x100532af, "lr.d t0, (a0)"),
+            (0x1a75332f, "sc.d.rl t1, t2, (a0)"),
+            (0x00b6352f, "amoadd.d a0, a1, (a2)"),
+            (0xe4b6352f, "amomaxu.d.aq a0, a1, (a2)"),
+            (0x2505, "c.addiw a0, 1"),
+            (0x7de8, "c.ld a0, 248(a1)"),
+        ];
+        for (word, asm_line) in cases {
+            assert_eq!(disassemble_word_for(word, Xlen::Rv64).unwrap(), asm_line);
+        }
+        // RV64-only instructions are unknown on RV32
+        assert!(disassemble_word(0x00053583).is_err());
+        // The encoding of c.addiw is c.jal on RV32, and c.addiw may not write zero
+        assert_eq!(disassemble_word(0x2505).unwrap(), "c.jal 1568");
+        assert!(disassemble_word_for(0x2001, Xlen::Rv64).is_err());
+    }
+
+    #[test]
+    fn register_names() {
+        let cases = [
+            (0x0310000f, "fence rw, w"),
+            (0x00c5f553, "fadd.s f10, f11, f12"),
+            (0xfe732ea3, "sw x7, -3(x6)"),
+            (0x0808, "c.addi4spn x10, x2, 16"),
+        ];
+        for (word, asm_line) in cases {
+            assert_eq!(
+                disassemble_word_with(word, Xlen::Rv32, RegisterNames::Numeric).unwrap(),
+                asm_line
+            );
+        }
+        assert_eq!(
+            disassemble_word_with(0x0808, Xlen::Rv32, RegisterNames::Abi).unwrap(),
+            "c.addi4spn a0, sp, 16"
+        );
+    }
+
+    #[test]
+    fn invalid_words() {
+        assert_eq!(
+            disassemble_word(0xFFFFFFFF),
+            Err(DisassembleError::UnknownInstruction(0xFFFFFFFF))
+        );
+        // slli with shamt[5] set is not valid on RV32
+        assert!(disassemble_word(0b0000001_00000_00101_001_00101_0010011).is_err());
+        // c.addi4spn with a zero immediate is the all-zero illegal instruction
+        assert!(disassemble_word(0x0000).is_err());
+        // Rounding modes 5 and 6 are reserved
+        assert!(disassemble_word(0x00c5d553).is_err());
+    }
+}
@@ -0,0 +1,399 @@
+use crate::isa::Isa;
+use crate::isa::Xlen;
+use crate::object::*;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ElfClass {
+    Elf32,
+    Elf64,
+}
+
+const EM_RISCV: u16 = 243;
+const ET_REL: u16 = 1;
+
+const EF_RISCV_RVC: u32 = 0x1;
+const EF_RISCV_FLOAT_ABI_SINGLE: u32 = 0x2;
+const EF_RISCV_FLOAT_ABI_DOUBLE: u32 = 0x4;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const SHN_UNDEF: u16 = 0;
+
+impl Object {
+    /// Lay the object out as a relocatable ELF file for `isa`
+    pub fn to_elf(&self, isa: &Isa) -> Vec<u8> {
+        let class = match isa.xlen {
+            Xlen::Rv32 => ElfClass::Elf32,
+            Xlen::Rv64 => ElfClass::Elf64,
+        };
+        let mut writer = Writer {
+            bytes: vec![],
+            class,
+        };
+        let mut shstrtab = StringTable::new();
+        let mut strtab = StringTable::new();
+        let mut headers = vec![SectionHeader::default()];
+
+        // Assign ELF section indices, each section is followed by its relocations
+        let mut section_indices = vec![];
+        let mut next_index = 1;
+        for section in &self.sections {
+            section_indices.push(next_index);
+            next_index += if section.relocations.is_empty() { 1 } else { 2 };
+        }
+        let symtab_index = next_index;
+
+        // Symbol table, local symbols must precede global ones
+        let mut symbols: Vec<&Symbol> = self.symbols.iter().filter(|s| !s.global).collect();
+        let first_global = symbols.len() + 1;
+        symbols.extend(self.symbols.iter().filter(|s| s.global));
+        let symbol_indices: HashMap<&str, usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.name.as_str(), i + 1))
+            .collect();
+
+        // ELF header is written last, once the section header offset is known
+        writer.bytes.resize(class.ehsize() as usize, 0);
+
+        // Section contents
+        for (section, &index) in self.sections.iter().zip(section_indices.iter()) {
+            let (sh_type, flags) = match section.kind {
+                SectionKind::Text => (SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR),
+                SectionKind::Data => (SHT_PROGBITS, SHF_ALLOC | SHF_WRITE),
+                SectionKind::ReadOnlyData => (SHT_PROGBITS, SHF_ALLOC),
+                SectionKind::Bss => (SHT_NOBITS, SHF_ALLOC | SHF_WRITE),
+            };
+            writer.align(section.alignment);
+            let offset = writer.bytes.len() as u64;
+            if sh_type != SHT_NOBITS {
+                writer.bytes.extend_from_slice(&section.data);
+            }
+            headers.push(SectionHeader {
+                name: shstrtab.add(&section.name),
+                sh_type,
+                flags,
+                offset,
+                size: section.data.len() as u64,
+                addralign: section.alignment,
+                ..Default::default()
+            });
+
+            if section.relocations.is_empty() {
+                continue;
+            }
+            writer.align(class.word_size());
+            let offset = writer.bytes.len() as u64;
+            for relocation in &section.relocations {
+                let symbol = symbol_indices[relocation.symbol.as_str()] as u64;
+                let kind = relocation.kind.elf_type() as u64;
+                writer.word(relocation.offset);
+                match class {
+                    ElfClass::Elf32 => writer.u32(((symbol << 8) | kind) as u32),
+                    ElfClass::Elf64 => writer.u64((symbol << 32) | kind),
+                }
+                writer.word(relocation.addend as u64);
+            }
+            headers.push(SectionHeader {
+                name: shstrtab.add(&(".rela".to_string() + &section.name)),
+                sh_type: SHT_RELA,
+                flags: SHF_INFO_LINK,
+                offset,
+                size: writer.bytes.len() as u64 - offset,
+                link: symtab_index,
+                info: index,
+                addralign: class.word_size(),
+                entsize: class.word_size() * 3,
+            });
+        }
+
+        // .symtab
+        writer.align(class.word_size());
+        let offset = writer.bytes.len() as u64;
+        writer.symbol(0, 0, 0, SHN_UNDEF);
+        for symbol in &symbols {
+            let bind = if symbol.global { STB_GLOBAL } else { STB_LOCAL };
+            let shndx = match symbol.section {
+                Some(section) => section_indices[section] as u16,
+                None => SHN_UNDEF,
+            };
+            writer.symbol(
+                strtab.add(&symbol.name),
+                symbol.value,
+                (bind << 4) | STT_NOTYPE,
+                shndx,
+            );
+        }
+        headers.push(SectionHeader {
+            name: shstrtab.add(".symtab"),
+            sh_type: SHT_SYMTAB,
+            offset,
+            size: writer.bytes.len() as u64 - offset,
+            link: symtab_index + 1,
+            info: first_global as u32,
+            addralign: class.word_size(),
+            entsize: class.symsize(),
+            ..Default::default()
+        });
+
+        // .strtab
+        let offset = writer.bytes.len() as u64;
+        writer.bytes.extend_from_slice(&strtab.bytes);
+        headers.push(SectionHeader {
+            name: shstrtab.add(".strtab"),
+            sh_type: SHT_STRTAB,
+            offset,
+            size: strtab.bytes.len() as u64,
+            addralign: 1,
+            ..Default::default()
+        });
+
+        // .shstrtab
+        let shstrtab_name = shstrtab.add(".shstrtab");
+        let offset = writer.bytes.len() as u64;
+        writer.bytes.extend_from_slice(&shstrtab.bytes);
+        headers.push(SectionHeader {
+            name: shstrtab_name,
+            sh_type: SHT_STRTAB,
+            offset,
+            size: shstrtab.bytes.len() as u64,
+            addralign: 1,
+            ..Default::default()
+        });
+
+        // Section headers
+        writer.align(class.word_size());
+        let shoff = writer.bytes.len() as u64;
+        for header in &headers {
+            writer.u32(header.name);
+            writer.u32(header.sh_type);
+            writer.word(header.flags);
+            writer.word(0);
+            writer.word(header.offset);
+            writer.word(header.size);
+            writer.u32(header.link);
+            writer.u32(header.info);
+            writer.word(header.addralign);
+            writer.word(header.entsize);
+        }
+
+        // ELF header
+        let body = std::mem::take(&mut writer.bytes);
+        writer.bytes.extend_from_slice(&[0x7F, b'E', b'L', b'F']);
+        writer.u8(match class {
+            ElfClass::Elf32 => 1,
+            ElfClass::Elf64 => 2,
+        });
+        writer.u8(1); // Little endian
+        writer.u8(1); // ELF version
+        writer.bytes.resize(16, 0);
+        writer.u16(ET_REL);
+        writer.u16(EM_RISCV);
+        writer.u32(1);
+        writer.word(0); // Entry point
+        writer.word(0); // Program header offset
+        writer.word(shoff);
+        writer.u32(elf_flags(isa));
+        writer.u16(class.ehsize());
+        writer.u16(0); // Program header entry size
+        writer.u16(0); // Number of program headers
+        writer.u16(class.shentsize());
+        writer.u16(headers.len() as u16);
+        writer.u16(headers.len() as u16 - 1);
+        writer
+            .bytes
+            .extend_from_slice(&body[class.ehsize() as usize..]);
+        writer.bytes
+    }
+}
+
+// Whether the code may be compressed and the float ABI, taken to be the widest one the ISA
+// supports like the GNU toolchain does, so that the object links with compiled code
+fn elf_flags(isa: &Isa) -> u32 {
+    let mut flags = 0;
+    if isa.has("c") {
+        flags |= EF_RISCV_RVC;
+    }
+    if isa.has("d") {
+        flags |= EF_RISCV_FLOAT_ABI_DOUBLE;
+    } else if isa.has("f") {
+        flags |= EF_RISCV_FLOAT_ABI_SINGLE;
+    }
+    flags
+}
+
+impl ElfClass {
+    fn word_size(&self) -> u64 {
+        match self {
+            ElfClass::Elf32 => 4,
+            ElfClass::Elf64 => 8,
+        }
+    }
+
+    fn ehsize(&self) -> u16 {
+        match self {
+            ElfClass::Elf32 => 52,
+            ElfClass::Elf64 => 64,
+        }
+    }
+
+    fn shentsize(&self) -> u16 {
+        match self {
+            ElfClass::Elf32 => 40,
+            ElfClass::Elf64 => 64,
+        }
+    }
+
+    fn symsize(&self) -> u64 {
+        match self {
+            ElfClass::Elf32 => 16,
+            ElfClass::Elf64 => 24,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SectionHeader {
+    name: u32,
+    sh_type: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    addralign: u64,
+    entsize: u64,
+}
+
+struct StringTable {
+    bytes: Vec<u8>,
+}
+
+impl StringTable {
+    fn new() -> StringTable {
+        StringTable { bytes: vec![0] }
+    }
+
+    fn add(&mut self, string: &str) -> u32 {
+        let offset = self.bytes.len() as u32;
+        self.bytes.extend_from_slice(string.as_bytes());
+        self.bytes.push(0);
+        offset
+    }
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+    class: ElfClass,
+}
+
+impl Writer {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn u64(&mut self, value: u64) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    // Address sized value
+    fn word(&mut self, value: u64) {
+        match self.class {
+            ElfClass::Elf32 => self.u32(value as u32),
+            ElfClass::Elf64 => self.u64(value),
+        }
+    }
+
+    fn symbol(&mut self, name: u32, value: u64, info: u8, shndx: u16) {
+        match self.class {
+            ElfClass::Elf32 => {
+                self.u32(name);
+                self.u32(value as u32);
+                self.u32(0);
+                self.u8(info);
+                self.u8(0);
+                self.u16(shndx);
+            }
+            ElfClass::Elf64 => {
+                self.u32(name);
+                self.u8(info);
+                self.u8(0);
+                self.u16(shndx);
+                self.u64(value);
+                self.u64(0);
+            }
+        }
+    }
+
+    fn align(&mut self, alignment: u64) {
+        while !(self.bytes.len() as u64).is_multiple_of(alignment.max(1)) {
+            self.bytes.push(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::assemble_object;
+    use crate::isa::Isa;
+
+    fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+    }
+
+    fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn elf32_header_and_relocations() {
+        let object = assemble_object("main: jal ra puts\n").unwrap();
+        let elf = object.to_elf(&Isa::parse("rv32i").unwrap());
+        assert_eq!(&elf[0..6], &[0x7F, b'E', b'L', b'F', 1, 1]);
+        assert_eq!(u16_at(&elf, 16), 1); // ET_REL
+        assert_eq!(u16_at(&elf, 18), 243); // EM_RISCV
+        let shoff = u32_at(&elf, 32) as usize;
+        // null, .text, .rela.text, .data, .rodata, .bss, .symtab, .strtab, .shstrtab
+        assert_eq!(u16_at(&elf, 48), 9);
+        // .rela.text holds a single R_RISCV_JAL against symbol 2 (puts)
+        let rela = shoff + 2 * 40;
+        assert_eq!(u32_at(&elf, rela + 4), 4); // SHT_RELA
+        let rela_offset = u32_at(&elf, rela + 16) as usize;
+        assert_eq!(u32_at(&elf, rela + 20), 12);
+        assert_eq!(u32_at(&elf, rela_offset + 4), (2 << 8) | 17);
+    }
+
+    #[test]
+    fn elf64_header() {
+        let object = assemble_object("add a0 a0 a0\n").unwrap();
+        let elf = object.to_elf(&Isa::parse("rv64gc").unwrap());
+        assert_eq!(elf[4], 2);
+        assert_eq!(u32_at(&elf, 48), 0x5); // EF_RISCV_RVC | EF_RISCV_FLOAT_ABI_DOUBLE
+        assert_eq!(u16_at(&elf, 52), 64); // e_ehsize
+        assert_eq!(u16_at(&elf, 58), 64); // e_shentsize
+
+        // No relocations, so no .rela.text
+        assert_eq!(u16_at(&elf, 60), 8);
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+
+/// Location of a piece of source text. Lines and columns start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+    pub length: usize,
+}
+
+impl Span {
+    /// Smallest span covering both `self` and `other`, which must be on the same line.
+    pub fn to(self, other: Span) -> Span {
+        Span {
+            line: self.line,
+            column: self.column,
+            length: other.column + other.length - self.column,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssembleError {
+    Syntax {
+        description: String,
+        lexeme: String,
+        span: Span,
+    },
+    UnknownMnemonic {
+        mnemonic: String,
+        span: Span,
+    },
+    MissingExtension {
+        mnemonic: String,
+        extension: String,
+        span: Span,
+    },
+    Rv64Only {
+        mnemonic: String,
+        span: Span,
+    },
+    Rv32Only {
+        mnemonic: String,
+        span: Span,
+    },
+    InvalidRegister {
+        lexeme: String,
+        span: Span,
+    },
+    InvalidOperand {
+        description: String,
+        lexeme: String,
+        span: Span,
+    },
+    ImmediateOutOfRange {
+        lexeme: String,
+        value: i64,
+        min: i64,
+        max: i64,
+        span: Span,
+    },
+    MisalignedImmediate {
+        lexeme: String,
+        value: i64,
+        alignment: i64,
+        span: Span,
+    },
+    WrongOperandCount {
+        mnemonic: String,
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+    UndefinedSymbol {
+        symbol: String,
+        span: Span,
+    },
+    DuplicateSymbol {
+        symbol: String,
+        span: Span,
+    },
+    UnsupportedDirective {
+        directive: String,
+        span: Span,
+    },
+    // Instructions or initialised data in a section that only reserves zeroed space
+    BssContents {
+        lexeme: String,
+        section: String,
+        span: Span,
+    },
+    // Reported as a warning, assembly carries on with the truncated value
+    ImmediateTruncated {
+        lexeme: String,
+        value: i64,
+        truncated: i64,
+        span: Span,
+    },
+}
+
+impl AssembleError {
+    pub fn span(&self) -> Span {
+        match self {
+            AssembleError::Syntax { span, .. }
+            | AssembleError::UnknownMnemonic { span, .. }
+            | AssembleError::MissingExtension { span, .. }
+            | AssembleError::Rv64Only { span, .. }
+            | AssembleError::Rv32Only { span, .. }
+            | AssembleError::InvalidRegister { span, .. }
+            | AssembleError::InvalidOperand { span, .. }
+            | AssembleError::ImmediateOutOfRange { span, .. }
+            | AssembleError::MisalignedImmediate { span, .. }
+            | AssembleError::WrongOperandCount { span, .. }
+            | AssembleError::UndefinedSymbol { span, .. }
+            | AssembleError::DuplicateSymbol { span, .. }
+            | AssembleError::UnsupportedDirective { span, .. }
+            | AssembleError::BssContents { span, .. }
+            | AssembleError::ImmediateTruncated { span, .. } => *span,
+        }
+    }
+
+    /// Source text the error refers to
+    pub fn lexeme(&self) -> &str {
+        match self {
+            AssembleError::Syntax { lexeme, .. }
+            | AssembleError::InvalidRegister { lexeme, .. }
+            | AssembleError::InvalidOperand { lexeme, .. }
+            | AssembleError::ImmediateOutOfRange { lexeme, .. }
+            | AssembleError::MisalignedImmediate { lexeme, .. }
+            | AssembleError::BssContents { lexeme, .. }
+            | AssembleError::ImmediateTruncated { lexeme, .. } => lexeme,
+            AssembleError::UnknownMnemonic { mnemonic, .. }
+            | AssembleError::MissingExtension { mnemonic, .. }
+            | AssembleError::Rv64Only { mnemonic, .. }
+            | AssembleError::Rv32Only { mnemonic, .. }
+            | AssembleError::WrongOperandCount { mnemonic, .. } => mnemonic,
+            AssembleError::UndefinedSymbol { symbol, .. }
+            | AssembleError::DuplicateSymbol { symbol, .. } => symbol,
+            AssembleError::UnsupportedDirective { directive, .. } => directive,
+        }
+    }
+
+    /// Description of the error without its location
+    pub fn message(&self) -> String {
+        match self {
+            AssembleError::Syntax {
+                description,
+                lexeme,
+                ..
+            } => {
+                if lexeme.is_empty() {
+                    format!("Syntax error: {description}")
+                } else {
+                    format!("Syntax error: {description} '{lexeme}'")
+                }
+            }
+            AssembleError::UnknownMnemonic { mnemonic, .. } => {
+                format!("Unknown mnemonic '{mnemonic}'")
+            }
+            AssembleError::MissingExtension {
+                mnemonic,
+                extension,
+                ..
+            } => format!("'{mnemonic}' requires the '{extension}' extension"),
+            AssembleError::Rv64Only { mnemonic, .. } => {
+                format!("'{mnemonic}' is only available on RV64")
+            }
+            AssembleError::Rv32Only { mnemonic, .. } => {
+                format!("'{mnemonic}' is only available on RV32")
+            }
+            AssembleError::InvalidRegister { lexeme, .. } => {
+                format!("Invalid register '{lexeme}'")
+            }
+            AssembleError::InvalidOperand {
+                description,
+                lexeme,
+                ..
+            } => format!("Invalid operand '{lexeme}': {description}"),
+            AssembleError::ImmediateOutOfRange {
+                value, min, max, ..
+            } => format!("Immediate {value} is out of range, expected a value in [{min}, {max}]"),
+            AssembleError::MisalignedImmediate {
+                value, alignment, ..
+            } => format!("Immediate {value} is misaligned, expected a multiple of {alignment}"),
+            AssembleError::WrongOperandCount {
+                mnemonic,
+                expected,
+                found,
+                ..
+            } => format!("'{mnemonic}' expects {expected} operand(s) but {found} were given"),
+            AssembleError::UndefinedSymbol { symbol, .. } => {
+                format!("Undefined symbol '{symbol}'")
+            }
+            AssembleError::DuplicateSymbol { symbol, .. } => {
+                format!("Symbol '{symbol}' is already defined")
+            }
+            AssembleError::UnsupportedDirective { directive, .. } => {
+                format!("Unsupported directive '{directive}'")
+            }
+            AssembleError::BssContents {
+                lexeme, section, ..
+            } => format!("'{lexeme}' cannot place contents in '{section}', which only holds zeros"),
+            AssembleError::ImmediateTruncated {
+                value, truncated, ..
+            } => format!("Immediate {value:#x} is truncated to {truncated:#x}"),
+        }
+    }
+}
+
+impl fmt::Display for AssembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let span = self.span();
+        write!(f, "[Line {}:{}] {}", span.line, span.column, self.message())
+    }
+}
+
+impl std::error::Error for AssembleError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisassembleError {
+    UnknownInstruction(u32),
+    InvalidOperand { word: u32, description: String },
+}
+
+impl fmt::Display for DisassembleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DisassembleError::UnknownInstruction(word) => {
+                write!(f, "Unknown instruction {word:#010x}")
+            }
+            DisassembleError::InvalidOperand { word, description } => {
+                write!(f, "Invalid operand in {word:#010x}: {description}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DisassembleError {}
@@ -0,0 +1,251 @@
+use crate::error::AssembleError;
+use crate::parser::ast::*;
+use std::collections::HashMap;
+
+/// Values symbols stand for while assembling
+#[derive(Debug, Clone, Default)]
+pub struct Symbols {
+    // Addresses of labels
+    pub labels: HashMap<String, u32>,
+    // Index of the section each label is defined in
+    pub sections: HashMap<String, usize>,
+    // Operands assigned with `.equ`, `.set` or `=` at the statement being walked, evaluated wherever
+    // the symbol is used so that they may refer to labels defined later on
+    pub constants: HashMap<String, Operand>,
+    // Targets of `%pcrel_hi` by the address of their auipc, which `%pcrel_lo` refers back to
+    pub pcrel_hi: HashMap<u32, Operand>,
+}
+
+impl Symbols {
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.labels.contains_key(name) || self.constants.contains_key(name)
+    }
+}
+
+pub fn evaluate_expr(
+    expr: &Expr,
+    operand: &Operand,
+    symbols: &Symbols,
+) -> Result<i64, AssembleError> {
+    evaluate(expr, operand, symbols, 0)
+}
+
+// `depth` counts the constants being expanded, more than there are means one refers to itself
+fn evaluate(
+    expr: &Expr,
+    operand: &Operand,
+    symbols: &Symbols,
+    depth: usize,
+) -> Result<i64, AssembleError> {
+    match expr {
+        Expr::Number(number) => Ok(*number),
+        Expr::Symbol(name) => {
+            if let Some(&address) = symbols.labels.get(name) {
+                return Ok(address as i64);
+            }
+            let Some(constant) = symbols.constants.get(name) else {
+                return Err(AssembleError::UndefinedSymbol {
+                    symbol: name.clone(),
+                    span: operand.span,
+                });
+            };
+            if depth > symbols.constants.len() {
+                return Err(crate::invalid_operand(
+                    operand,
+                    "Symbol is defined in terms of itself",
+                ));
+            }
+            let OperandKind::Expression(expr) = &constant.kind else {
+                return Err(crate::invalid_operand(constant, "Expected a value"));
+            };
+            evaluate(expr, constant, symbols, depth + 1)
+        }
+        Expr::Negate(expr) => Ok(evaluate(expr, operand, symbols, depth)?.wrapping_neg()),
+        Expr::Not(expr) => Ok(!evaluate(expr, operand, symbols, depth)?),
+        Expr::Binary {
+            operator,
+            left,
+            right,
+        } => {
+            let left = evaluate(left, operand, symbols, depth)?;
+            let right = evaluate(right, operand, symbols, depth)?;
+            apply(*operator, left, right, operand)
+        }
+        Expr::Relocation { .. } => Err(crate::invalid_operand(
+            operand,
+            "Relocation functions must make up the whole immediate",
+        )),
+    }
+}
+
+fn apply(
+    operator: BinaryOperator,
+    left: i64,
+    right: i64,
+    operand: &Operand,
+) -> Result<i64, AssembleError> {
+    match operator {
+        BinaryOperator::Add => Ok(left.wrapping_add(right)),
+        BinaryOperator::Subtract => Ok(left.wrapping_sub(right)),
+        BinaryOperator::Multiply => Ok(left.wrapping_mul(right)),
+        BinaryOperator::Divide | BinaryOperator::Remainder if right == 0 => {
+            Err(crate::invalid_operand(operand, "Division by zero"))
+        }
+        BinaryOperator::Divide => Ok(left.wrapping_div(right)),
+        BinaryOperator::Remainder => Ok(left.wrapping_rem(right)),
+        BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight if !(0..64).contains(&right) => {
+            Err(crate::invalid_operand(operand, "Shift amount out of range"))
+        }
+        BinaryOperator::ShiftLeft => Ok(left << right),
+        // Shifts in zeros like the GNU assembler
+        BinaryOperator::ShiftRight => Ok(((left as u64) >> right) as i64),
+        BinaryOperator::And => Ok(left & right),
+        BinaryOperator::Or => Ok(left | right),
+        BinaryOperator::Xor => Ok(left ^ right),
+    }
+}
+
+/// Symbol an address refers to and the constant added to it, as in `msg + 4`, for the linker to
+/// fill in. Constants standing for such an address are followed.
+pub fn symbol_offset(expr: &Expr, operand: &Operand, symbols: &Symbols) -> Option<(String, i64)> {
+    offset_from(expr, operand, symbols, 0)
+}
+
+fn offset_from(
+    expr: &Expr,
+    operand: &Operand,
+    symbols: &Symbols,
+    depth: usize,
+) -> Option<(String, i64)> {
+    let constant = |expr: &Expr| {
+        if refers(expr, symbols, 0) {
+            return None;
+        }
+        evaluate(expr, operand, symbols, 0).ok()
+    };
+    match expr {
+        Expr::Symbol(name) => match symbols.constants.get(name) {
+            Some(Operand {
+                kind: OperandKind::Expression(expr),
+                ..
+            }) if depth <= symbols.constants.len() => {
+                offset_from(expr, operand, symbols, depth + 1)
+            }
+            Some(_) => None,
+            None => Some((name.clone(), 0)),
+        },
+        Expr::Binary {
+            operator: BinaryOperator::Add,
+            left,
+            right,
+        } => match offset_from(left, operand, symbols, depth) {
+            Some((name, addend)) => Some((name, addend.wrapping_add(constant(right)?))),
+            None => {
+                let (name, addend) = offset_from(right, operand, symbols, depth)?;
+                Some((name, addend.wrapping_add(constant(left)?)))
+            }
+        },
+        Expr::Binary {
+            operator: BinaryOperator::Subtract,
+            left,
+            right,
+        } => {
+            let (name, addend) = offset_from(left, operand, symbols, depth)?;
+            Some((name, addend.wrapping_sub(constant(right)?)))
+        }
+        _ => None,
+    }
+}
+
+/// `expr` with the constants it refers to replaced by what they are assigned at this point, so that
+/// assigning a symbol again with `.set` only affects what follows
+pub fn substitute_constants(expr: &Expr, symbols: &Symbols) -> Expr {
+    let substitute = |expr: &Expr| Box::new(substitute_constants(expr, symbols));
+    match expr {
+        Expr::Number(_) => expr.clone(),
+        Expr::Symbol(name) => match symbols.constants.get(name) {
+            Some(Operand {
+                kind: OperandKind::Expression(constant),
+                ..
+            }) => constant.clone(),
+            _ => expr.clone(),
+        },
+        Expr::Negate(expr) => Expr::Negate(substitute(expr)),
+        Expr::Not(expr) => Expr::Not(substitute(expr)),
+        Expr::Binary {
+            operator,
+            left,
+            right,
+        } => Expr::Binary {
+            operator: *operator,
+            left: substitute(left),
+            right: substitute(right),
+        },
+        Expr::Relocation { function, argument } => Expr::Relocation {
+            function: function.clone(),
+            argument: substitute(argument),
+        },
+    }
+}
+
+/// Whether the value of `expr` is an address rather than a constant. Labels subtracted from one
+/// another, as in `end - start`, leave a constant.
+pub fn is_address(expr: &Expr, symbols: &Symbols) -> bool {
+    label_count(expr, symbols, 0) != 0
+}
+
+/// Whether `expr` refers to a label, directly or through a constant, so that its value is only
+/// known once the program is laid out
+pub fn refers_to_label(expr: &Expr, symbols: &Symbols) -> bool {
+    refers(expr, symbols, 0)
+}
+
+fn refers(expr: &Expr, symbols: &Symbols, depth: usize) -> bool {
+    match expr {
+        Expr::Number(_) => false,
+        Expr::Symbol(name) => match symbols.constants.get(name) {
+            Some(Operand {
+                kind: OperandKind::Expression(expr),
+                ..
+            }) if depth <= symbols.constants.len() => refers(expr, symbols, depth + 1),
+            Some(_) => false,
+            None => true,
+        },
+        Expr::Negate(expr) | Expr::Not(expr) => refers(expr, symbols, depth),
+        Expr::Binary { left, right, .. } => {
+            refers(left, symbols, depth) || refers(right, symbols, depth)
+        }
+        Expr::Relocation { argument, .. } => refers(argument, symbols, depth),
+    }
+}
+
+// Number of labels added to the value minus the number subtracted from it. Symbols that are not
+// defined yet are taken to be labels.
+fn label_count(expr: &Expr, symbols: &Symbols, depth: usize) -> i64 {
+    match expr {
+        Expr::Number(_) => 0,
+        Expr::Symbol(name) => match symbols.constants.get(name) {
+            Some(Operand {
+                kind: OperandKind::Expression(expr),
+                ..
+            }) if depth <= symbols.constants.len() => label_count(expr, symbols, depth + 1),
+            Some(_) => 0,
+            None => 1,
+        },
+        Expr::Negate(expr) | Expr::Not(expr) => -label_count(expr, symbols, depth),
+        Expr::Binary {
+            operator,
+            left,
+            right,
+        } => {
+            let left = label_count(left, symbols, depth);
+            let right = label_count(right, symbols, depth);
+            match operator {
+                BinaryOperator::Add => left + right,
+                BinaryOperator::Subtract => left - right,
+                _ => (left != 0 || right != 0) as i64,
+            }
+        }
+        Expr::Relocation { argument, .. } => label_count(argument, symbols, depth),
+    }
+}
@@ -1,3 +1,5 @@
+use crate::isa::Xlen;
+
 pub enum InstructionType {
     R,
     I,
@@ -5,6 +7,30 @@ pub enum InstructionType {
     B,
     U,
     J,
+    // Fused multiply-add with a third source register in bits 31:27
+    R4,
+}
+
+impl InstructionType {
+    // Inclusive range of values accepted as immediate
+    pub fn imm_range(&self) -> (i64, i64) {
+        match self {
+            InstructionType::I | InstructionType::S => (-(1 << 11), (1 << 11) - 1),
+            InstructionType::B => (-(1 << 12), (1 << 12) - 2),
+            InstructionType::J => (-(1 << 20), (1 << 20) - 2),
+            // Bits 31:12 of the value, as a 20-bit unsigned field
+            InstructionType::U => (0, (1 << 20) - 1),
+            InstructionType::R | InstructionType::R4 => (0, 0),
+        }
+    }
+
+    // Immediate must be a multiple of this value
+    pub fn imm_alignment(&self) -> i64 {
+        match self {
+            InstructionType::B | InstructionType::J => 2,
+            _ => 1,
+        }
+    }
 }
 
 pub enum AsmArgs {
@@ -12,283 +38,3972 @@ pub enum AsmArgs {
     RegSrc1,
     RegSrc2,
     Imm,
+    // Shift amount of slli, srli and srai, 6 bits wide on RV64
+    Shamt,
+    // 5-bit shift amount of slliw, srliw and sraiw
+    ShamtW,
     Mem,
+    // Predecessor and successor sets of fence, e.g. `rw`
+    Pred,
+    Succ,
+    // CSR name or 12-bit address
+    Csr,
+    // 5-bit unsigned immediate of csrrwi, csrrsi and csrrci
+    Uimm,
+    // Address register of atomics, written `(rs1)`
+    Addr,
+    // Floating-point registers
+    FRegDest,
+    FRegSrc1,
+    FRegSrc2,
+    FRegSrc3,
     NoArg,
 }
 
+// Largest shift amount accepted on RV32 and by the RV64 word shifts
+pub const SHAMT_MAX: i64 = 31;
+
+// Largest shift amount accepted on RV64
+pub const SHAMT_MAX_RV64: i64 = 63;
+
+// Largest immediate of csrrwi, csrrsi and csrrci
+pub const UIMM_MAX: i64 = 31;
+
+// Access types making up a fence set, from its most significant bit down
+pub const FENCE_ACCESSES: [char; 4] = ['i', 'o', 'r', 'w'];
+
 pub struct Instruction<'a> {
     pub inst_type: InstructionType,
     pub asm_string: &'a str,
     pub opcode_func: u32,
+    pub mask: u32,
     pub num_of_arguments: usize,
-    pub arguments: [AsmArgs; 3],
+    pub arguments: [AsmArgs; 4],
 }
 
-pub const INSTRUCTIONS: [Instruction; 37] = [
+pub const INSTRUCTIONS: [Instruction; 41] = [
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "addi",
         opcode_func: MATCH_ADDI,
+        mask: MASK_ADDI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "andi",
         opcode_func: MATCH_ANDI,
+        mask: MASK_ANDI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "ori",
         opcode_func: MATCH_ORI,
+        mask: MASK_ORI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "xori",
         opcode_func: MATCH_XORI,
+        mask: MASK_XORI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "slti",
         opcode_func: MATCH_SLTI,
+        mask: MASK_SLTI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "sltiu",
         opcode_func: MATCH_SLTIU,
+        mask: MASK_SLTIU,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "slli",
         opcode_func: MATCH_SLLI,
+        mask: MASK_SLLI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Shamt,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "srli",
         opcode_func: MATCH_SRLI,
+        mask: MASK_SRLI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Shamt,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "srai",
         opcode_func: MATCH_SRAI,
+        mask: MASK_SRAI,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Shamt,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::U,
         asm_string: "lui",
         opcode_func: MATCH_LUI,
+        mask: MASK_LUI,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Imm, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::U,
         asm_string: "auipc",
         opcode_func: MATCH_AUIPC,
+        mask: MASK_AUIPC,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Imm, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "add",
         opcode_func: MATCH_ADD,
+        mask: MASK_ADD,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "and",
         opcode_func: MATCH_AND,
+        mask: MASK_AND,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "or",
         opcode_func: MATCH_OR,
+        mask: MASK_OR,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "xor",
         opcode_func: MATCH_XOR,
+        mask: MASK_XOR,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "slt",
         opcode_func: MATCH_SLT,
+        mask: MASK_SLT,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "sltu",
         opcode_func: MATCH_SLTU,
+        mask: MASK_SLTU,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "sll",
         opcode_func: MATCH_SLL,
+        mask: MASK_SLL,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "srl",
         opcode_func: MATCH_SRL,
+        mask: MASK_SRL,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "sra",
         opcode_func: MATCH_SRA,
+        mask: MASK_SRA,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::R,
         asm_string: "sub",
         opcode_func: MATCH_SUB,
+        mask: MASK_SUB,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::RegSrc2],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::J,
         asm_string: "jal",
         opcode_func: MATCH_JAL,
+        mask: MASK_JAL,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Imm, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "jalr",
         opcode_func: MATCH_JALR,
+        mask: MASK_JALR,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegDest, AsmArgs::RegSrc1, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "beq",
         opcode_func: MATCH_BEQ,
+        mask: MASK_BEQ,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "bne",
         opcode_func: MATCH_BNE,
+        mask: MASK_BNE,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "blt",
         opcode_func: MATCH_BLT,
+        mask: MASK_BLT,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "bltu",
         opcode_func: MATCH_BLTU,
+        mask: MASK_BLTU,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "bge",
         opcode_func: MATCH_BGE,
+        mask: MASK_BGE,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::B,
         asm_string: "bgeu",
         opcode_func: MATCH_BGEU,
+        mask: MASK_BGEU,
         num_of_arguments: 3,
-        arguments: [AsmArgs::RegSrc1, AsmArgs::RegSrc2, AsmArgs::Imm],
+        arguments: [
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "lw",
         opcode_func: MATCH_LW,
+        mask: MASK_LW,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "lh",
         opcode_func: MATCH_LH,
+        mask: MASK_LH,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "lhu",
         opcode_func: MATCH_LHU,
+        mask: MASK_LHU,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "lb",
         opcode_func: MATCH_LB,
+        mask: MASK_LB,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::I,
         asm_string: "lbu",
         opcode_func: MATCH_LBU,
+        mask: MASK_LBU,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegDest, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::S,
         asm_string: "sw",
         opcode_func: MATCH_SW,
+        mask: MASK_SW,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegSrc2, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::S,
         asm_string: "sh",
         opcode_func: MATCH_SH,
+        mask: MASK_SH,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegSrc2, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
     },
     Instruction {
         inst_type: InstructionType::S,
         asm_string: "sb",
         opcode_func: MATCH_SB,
+        mask: MASK_SB,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "ecall",
+        opcode_func: MATCH_ECALL,
+        mask: MASK_ECALL,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "ebreak",
+        opcode_func: MATCH_EBREAK,
+        mask: MASK_EBREAK,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    // Must come before fence, whose mask also matches it
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "fence.tso",
+        opcode_func: MATCH_FENCE_TSO,
+        mask: MASK_FENCE_TSO,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "fence",
+        opcode_func: MATCH_FENCE,
+        mask: MASK_FENCE,
+        num_of_arguments: 2,
+        arguments: [AsmArgs::Pred, AsmArgs::Succ, AsmArgs::NoArg, AsmArgs::NoArg],
+    },
+];
+
+// Control and status register instructions of the Zicsr extension
+pub const ZICSR_INSTRUCTIONS: [Instruction; 6] = [
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrw",
+        opcode_func: MATCH_CSRRW,
+        mask: MASK_CSRRW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrs",
+        opcode_func: MATCH_CSRRS,
+        mask: MASK_CSRRS,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrc",
+        opcode_func: MATCH_CSRRC,
+        mask: MASK_CSRRC,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrwi",
+        opcode_func: MATCH_CSRRWI,
+        mask: MASK_CSRRWI,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::Uimm,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrsi",
+        opcode_func: MATCH_CSRRSI,
+        mask: MASK_CSRRSI,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::Uimm,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "csrrci",
+        opcode_func: MATCH_CSRRCI,
+        mask: MASK_CSRRCI,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Csr,
+            AsmArgs::Uimm,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instruction fetch fence of the Zifencei extension
+pub const ZIFENCEI_INSTRUCTIONS: [Instruction; 1] = [Instruction {
+    inst_type: InstructionType::I,
+    asm_string: "fence.i",
+    opcode_func: MATCH_FENCE_I,
+    mask: MASK_FENCE_I,
+    num_of_arguments: 0,
+    arguments: [
+        AsmArgs::NoArg,
+        AsmArgs::NoArg,
+        AsmArgs::NoArg,
+        AsmArgs::NoArg,
+    ],
+}];
+
+// Trap return and wait for interrupt of the privileged architecture
+pub const PRIVILEGED_INSTRUCTIONS: [Instruction; 3] = [
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "mret",
+        opcode_func: MATCH_MRET,
+        mask: MASK_MRET,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "sret",
+        opcode_func: MATCH_SRET,
+        mask: MASK_SRET,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "wfi",
+        opcode_func: MATCH_WFI,
+        mask: MASK_WFI,
+        num_of_arguments: 0,
+        arguments: [
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Multiply and divide instructions of the M extension
+pub const M_INSTRUCTIONS: [Instruction; 8] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "mul",
+        opcode_func: MATCH_MUL,
+        mask: MASK_MUL,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "mulh",
+        opcode_func: MATCH_MULH,
+        mask: MASK_MULH,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "mulhsu",
+        opcode_func: MATCH_MULHSU,
+        mask: MASK_MULHSU,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "mulhu",
+        opcode_func: MATCH_MULHU,
+        mask: MASK_MULHU,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "div",
+        opcode_func: MATCH_DIV,
+        mask: MASK_DIV,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "divu",
+        opcode_func: MATCH_DIVU,
+        mask: MASK_DIVU,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "rem",
+        opcode_func: MATCH_REM,
+        mask: MASK_REM,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "remu",
+        opcode_func: MATCH_REMU,
+        mask: MASK_REMU,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Atomic instructions of the A extension, which take an ordering suffix
+pub const A_INSTRUCTIONS: [Instruction; 11] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "lr.w",
+        opcode_func: MATCH_LR_W,
+        mask: MASK_LR_W,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "sc.w",
+        opcode_func: MATCH_SC_W,
+        mask: MASK_SC_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoswap.w",
+        opcode_func: MATCH_AMOSWAP_W,
+        mask: MASK_AMOSWAP_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoadd.w",
+        opcode_func: MATCH_AMOADD_W,
+        mask: MASK_AMOADD_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoxor.w",
+        opcode_func: MATCH_AMOXOR_W,
+        mask: MASK_AMOXOR_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoand.w",
+        opcode_func: MATCH_AMOAND_W,
+        mask: MASK_AMOAND_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoor.w",
+        opcode_func: MATCH_AMOOR_W,
+        mask: MASK_AMOOR_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomin.w",
+        opcode_func: MATCH_AMOMIN_W,
+        mask: MASK_AMOMIN_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomax.w",
+        opcode_func: MATCH_AMOMAX_W,
+        mask: MASK_AMOMAX_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amominu.w",
+        opcode_func: MATCH_AMOMINU_W,
+        mask: MASK_AMOMINU_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomaxu.w",
+        opcode_func: MATCH_AMOMAXU_W,
+        mask: MASK_AMOMAXU_W,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instructions RV64 adds to the base ISA
+pub const RV64I_INSTRUCTIONS: [Instruction; 12] = [
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "ld",
+        opcode_func: MATCH_LD,
+        mask: MASK_LD,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "lwu",
+        opcode_func: MATCH_LWU,
+        mask: MASK_LWU,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::S,
+        asm_string: "sd",
+        opcode_func: MATCH_SD,
+        mask: MASK_SD,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "addiw",
+        opcode_func: MATCH_ADDIW,
+        mask: MASK_ADDIW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::Imm,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "slliw",
+        opcode_func: MATCH_SLLIW,
+        mask: MASK_SLLIW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::ShamtW,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "srliw",
+        opcode_func: MATCH_SRLIW,
+        mask: MASK_SRLIW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::ShamtW,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "sraiw",
+        opcode_func: MATCH_SRAIW,
+        mask: MASK_SRAIW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::ShamtW,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "addw",
+        opcode_func: MATCH_ADDW,
+        mask: MASK_ADDW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "subw",
+        opcode_func: MATCH_SUBW,
+        mask: MASK_SUBW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "sllw",
+        opcode_func: MATCH_SLLW,
+        mask: MASK_SLLW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "srlw",
+        opcode_func: MATCH_SRLW,
+        mask: MASK_SRLW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "sraw",
+        opcode_func: MATCH_SRAW,
+        mask: MASK_SRAW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instructions RV64 adds to the M extension
+pub const RV64M_INSTRUCTIONS: [Instruction; 5] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "mulw",
+        opcode_func: MATCH_MULW,
+        mask: MASK_MULW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "divw",
+        opcode_func: MATCH_DIVW,
+        mask: MASK_DIVW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "divuw",
+        opcode_func: MATCH_DIVUW,
+        mask: MASK_DIVUW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "remw",
+        opcode_func: MATCH_REMW,
+        mask: MASK_REMW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "remuw",
+        opcode_func: MATCH_REMUW,
+        mask: MASK_REMUW,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::RegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instructions RV64 adds to the A extension
+pub const RV64A_INSTRUCTIONS: [Instruction; 11] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "lr.d",
+        opcode_func: MATCH_LR_D,
+        mask: MASK_LR_D,
         num_of_arguments: 2,
-        arguments: [AsmArgs::RegSrc2, AsmArgs::Mem, AsmArgs::NoArg],
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "sc.d",
+        opcode_func: MATCH_SC_D,
+        mask: MASK_SC_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoswap.d",
+        opcode_func: MATCH_AMOSWAP_D,
+        mask: MASK_AMOSWAP_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoadd.d",
+        opcode_func: MATCH_AMOADD_D,
+        mask: MASK_AMOADD_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoxor.d",
+        opcode_func: MATCH_AMOXOR_D,
+        mask: MASK_AMOXOR_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoand.d",
+        opcode_func: MATCH_AMOAND_D,
+        mask: MASK_AMOAND_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amoor.d",
+        opcode_func: MATCH_AMOOR_D,
+        mask: MASK_AMOOR_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomin.d",
+        opcode_func: MATCH_AMOMIN_D,
+        mask: MASK_AMOMIN_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomax.d",
+        opcode_func: MATCH_AMOMAX_D,
+        mask: MASK_AMOMAX_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amominu.d",
+        opcode_func: MATCH_AMOMINU_D,
+        mask: MASK_AMOMINU_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
     },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "amomaxu.d",
+        opcode_func: MATCH_AMOMAXU_D,
+        mask: MASK_AMOMAXU_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::RegSrc2,
+            AsmArgs::Addr,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Single-precision instructions of the F extension
+pub const F_INSTRUCTIONS: [Instruction; 26] = [
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "flw",
+        opcode_func: MATCH_FLW,
+        mask: MASK_FLW,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::S,
+        asm_string: "fsw",
+        opcode_func: MATCH_FSW,
+        mask: MASK_FSW,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fmadd.s",
+        opcode_func: MATCH_FMADD_S,
+        mask: MASK_FMADD_S,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fmsub.s",
+        opcode_func: MATCH_FMSUB_S,
+        mask: MASK_FMSUB_S,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fnmsub.s",
+        opcode_func: MATCH_FNMSUB_S,
+        mask: MASK_FNMSUB_S,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fnmadd.s",
+        opcode_func: MATCH_FNMADD_S,
+        mask: MASK_FNMADD_S,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fadd.s",
+        opcode_func: MATCH_FADD_S,
+        mask: MASK_FADD_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsub.s",
+        opcode_func: MATCH_FSUB_S,
+        mask: MASK_FSUB_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmul.s",
+        opcode_func: MATCH_FMUL_S,
+        mask: MASK_FMUL_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fdiv.s",
+        opcode_func: MATCH_FDIV_S,
+        mask: MASK_FDIV_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsqrt.s",
+        opcode_func: MATCH_FSQRT_S,
+        mask: MASK_FSQRT_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnj.s",
+        opcode_func: MATCH_FSGNJ_S,
+        mask: MASK_FSGNJ_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnjn.s",
+        opcode_func: MATCH_FSGNJN_S,
+        mask: MASK_FSGNJN_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnjx.s",
+        opcode_func: MATCH_FSGNJX_S,
+        mask: MASK_FSGNJX_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmin.s",
+        opcode_func: MATCH_FMIN_S,
+        mask: MASK_FMIN_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmax.s",
+        opcode_func: MATCH_FMAX_S,
+        mask: MASK_FMAX_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.w.s",
+        opcode_func: MATCH_FCVT_W_S,
+        mask: MASK_FCVT_W_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.wu.s",
+        opcode_func: MATCH_FCVT_WU_S,
+        mask: MASK_FCVT_WU_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmv.x.w",
+        opcode_func: MATCH_FMV_X_W,
+        mask: MASK_FMV_X_W,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "feq.s",
+        opcode_func: MATCH_FEQ_S,
+        mask: MASK_FEQ_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "flt.s",
+        opcode_func: MATCH_FLT_S,
+        mask: MASK_FLT_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fle.s",
+        opcode_func: MATCH_FLE_S,
+        mask: MASK_FLE_S,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fclass.s",
+        opcode_func: MATCH_FCLASS_S,
+        mask: MASK_FCLASS_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.s.w",
+        opcode_func: MATCH_FCVT_S_W,
+        mask: MASK_FCVT_S_W,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.s.wu",
+        opcode_func: MATCH_FCVT_S_WU,
+        mask: MASK_FCVT_S_WU,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmv.w.x",
+        opcode_func: MATCH_FMV_W_X,
+        mask: MASK_FMV_W_X,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instructions RV64 adds to the F extension
+pub const RV64F_INSTRUCTIONS: [Instruction; 4] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.l.s",
+        opcode_func: MATCH_FCVT_L_S,
+        mask: MASK_FCVT_L_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.lu.s",
+        opcode_func: MATCH_FCVT_LU_S,
+        mask: MASK_FCVT_LU_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.s.l",
+        opcode_func: MATCH_FCVT_S_L,
+        mask: MASK_FCVT_S_L,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.s.lu",
+        opcode_func: MATCH_FCVT_S_LU,
+        mask: MASK_FCVT_S_LU,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Double-precision instructions of the D extension
+pub const D_INSTRUCTIONS: [Instruction; 26] = [
+    Instruction {
+        inst_type: InstructionType::I,
+        asm_string: "fld",
+        opcode_func: MATCH_FLD,
+        mask: MASK_FLD,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::S,
+        asm_string: "fsd",
+        opcode_func: MATCH_FSD,
+        mask: MASK_FSD,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegSrc2,
+            AsmArgs::Mem,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fmadd.d",
+        opcode_func: MATCH_FMADD_D,
+        mask: MASK_FMADD_D,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fmsub.d",
+        opcode_func: MATCH_FMSUB_D,
+        mask: MASK_FMSUB_D,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fnmsub.d",
+        opcode_func: MATCH_FNMSUB_D,
+        mask: MASK_FNMSUB_D,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R4,
+        asm_string: "fnmadd.d",
+        opcode_func: MATCH_FNMADD_D,
+        mask: MASK_FNMADD_D,
+        num_of_arguments: 4,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::FRegSrc3,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fadd.d",
+        opcode_func: MATCH_FADD_D,
+        mask: MASK_FADD_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsub.d",
+        opcode_func: MATCH_FSUB_D,
+        mask: MASK_FSUB_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmul.d",
+        opcode_func: MATCH_FMUL_D,
+        mask: MASK_FMUL_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fdiv.d",
+        opcode_func: MATCH_FDIV_D,
+        mask: MASK_FDIV_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsqrt.d",
+        opcode_func: MATCH_FSQRT_D,
+        mask: MASK_FSQRT_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnj.d",
+        opcode_func: MATCH_FSGNJ_D,
+        mask: MASK_FSGNJ_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnjn.d",
+        opcode_func: MATCH_FSGNJN_D,
+        mask: MASK_FSGNJN_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fsgnjx.d",
+        opcode_func: MATCH_FSGNJX_D,
+        mask: MASK_FSGNJX_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmin.d",
+        opcode_func: MATCH_FMIN_D,
+        mask: MASK_FMIN_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmax.d",
+        opcode_func: MATCH_FMAX_D,
+        mask: MASK_FMAX_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.s.d",
+        opcode_func: MATCH_FCVT_S_D,
+        mask: MASK_FCVT_S_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.d.s",
+        opcode_func: MATCH_FCVT_D_S,
+        mask: MASK_FCVT_D_S,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.w.d",
+        opcode_func: MATCH_FCVT_W_D,
+        mask: MASK_FCVT_W_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.wu.d",
+        opcode_func: MATCH_FCVT_WU_D,
+        mask: MASK_FCVT_WU_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "feq.d",
+        opcode_func: MATCH_FEQ_D,
+        mask: MASK_FEQ_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "flt.d",
+        opcode_func: MATCH_FLT_D,
+        mask: MASK_FLT_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fle.d",
+        opcode_func: MATCH_FLE_D,
+        mask: MASK_FLE_D,
+        num_of_arguments: 3,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::FRegSrc2,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fclass.d",
+        opcode_func: MATCH_FCLASS_D,
+        mask: MASK_FCLASS_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.d.w",
+        opcode_func: MATCH_FCVT_D_W,
+        mask: MASK_FCVT_D_W,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.d.wu",
+        opcode_func: MATCH_FCVT_D_WU,
+        mask: MASK_FCVT_D_WU,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+// Instructions RV64 adds to the D extension
+pub const RV64D_INSTRUCTIONS: [Instruction; 6] = [
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.l.d",
+        opcode_func: MATCH_FCVT_L_D,
+        mask: MASK_FCVT_L_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.lu.d",
+        opcode_func: MATCH_FCVT_LU_D,
+        mask: MASK_FCVT_LU_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmv.x.d",
+        opcode_func: MATCH_FMV_X_D,
+        mask: MASK_FMV_X_D,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::RegDest,
+            AsmArgs::FRegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.d.l",
+        opcode_func: MATCH_FCVT_D_L,
+        mask: MASK_FCVT_D_L,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fcvt.d.lu",
+        opcode_func: MATCH_FCVT_D_LU,
+        mask: MASK_FCVT_D_LU,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+    Instruction {
+        inst_type: InstructionType::R,
+        asm_string: "fmv.d.x",
+        opcode_func: MATCH_FMV_D_X,
+        mask: MASK_FMV_D_X,
+        num_of_arguments: 2,
+        arguments: [
+            AsmArgs::FRegDest,
+            AsmArgs::RegSrc1,
+            AsmArgs::NoArg,
+            AsmArgs::NoArg,
+        ],
+    },
+];
+
+pub struct InstructionSet<'a> {
+    pub extension: &'a str,
+    // Set when the instructions only exist for this XLEN
+    pub xlen: Option<Xlen>,
+    pub instructions: &'a [Instruction<'a>],
+}
+
+pub const INSTRUCTION_SETS: [InstructionSet; 13] = [
+    InstructionSet {
+        extension: "i",
+        xlen: None,
+        instructions: &INSTRUCTIONS,
+    },
+    // Privileged instructions are not named in ISA strings
+    InstructionSet {
+        extension: "i",
+        xlen: None,
+        instructions: &PRIVILEGED_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "zicsr",
+        xlen: None,
+        instructions: &ZICSR_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "zifencei",
+        xlen: None,
+        instructions: &ZIFENCEI_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "i",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64I_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "m",
+        xlen: None,
+        instructions: &M_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "m",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64M_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "a",
+        xlen: None,
+        instructions: &A_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "a",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64A_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "f",
+        xlen: None,
+        instructions: &F_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "f",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64F_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "d",
+        xlen: None,
+        instructions: &D_INSTRUCTIONS,
+    },
+    InstructionSet {
+        extension: "d",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64D_INSTRUCTIONS,
+    },
+];
+
+// Rounding modes of floating-point instructions
+pub const ROUNDING_MODES: [(&str, u32); 6] = [
+    ("rne", 0b000),
+    ("rtz", 0b001),
+    ("rdn", 0b010),
+    ("rup", 0b011),
+    ("rmm", 0b100),
+    ("dyn", 0b111),
+];
+
+// Conversions that are always exact, which default to `rne` instead
+const EXACT_CONVERSIONS: [&str; 3] = ["fcvt.d.s", "fcvt.d.w", "fcvt.d.wu"];
+
+pub fn default_rounding_mode(inst: &Instruction) -> u32 {
+    if EXACT_CONVERSIONS.contains(&inst.asm_string) {
+        0b000
+    } else {
+        0b111
+    }
+}
+
+// Floating-point computations leave funct3 out of their mask to take a rounding mode
+pub fn takes_rounding_mode(inst: &Instruction) -> bool {
+    matches!(inst.inst_type, InstructionType::R | InstructionType::R4) && inst.mask & 0x7000 == 0
+}
+
+// Ordering suffixes of atomic instructions and the aq (26) and rl (25) bits they set
+pub const ORDERINGS: [(&str, u32); 3] = [
+    (".aqrl", 0b11 << 25),
+    (".aq", 0b10 << 25),
+    (".rl", 0b01 << 25),
+];
+
+// Instruction named `mnemonic` and the set it belongs to
+pub fn find_instruction(
+    mnemonic: &str,
+) -> Option<(
+    &'static InstructionSet<'static>,
+    &'static Instruction<'static>,
+)> {
+    INSTRUCTION_SETS.iter().find_map(|set| {
+        set.instructions
+            .iter()
+            .find(|i| i.asm_string == mnemonic)
+            .map(|i| (set, i))
+    })
+}
+
+// Compressed instructions: where the encodings of two instructions overlap, the more specific one
+// comes first so that it is found first when disassembling
+
+// Formats of 16-bit instructions, which decide where their registers are placed
+#[allow(clippy::upper_case_acronyms)]
+pub enum CompressedFormat {
+    CR,
+    CI,
+    CSS,
+    CIW,
+    CL,
+    CS,
+    CA,
+    CB,
+    CJ,
+}
+
+impl CompressedFormat {
+    // Lowest bit of a register field and whether it holds one of x8-x15 (or f8-f15)
+    pub fn register_field(&self, arg: &CompressedArgs) -> (u32, bool) {
+        match (self, arg) {
+            (
+                CompressedFormat::CR | CompressedFormat::CI,
+                CompressedArgs::Rd | CompressedArgs::FRd,
+            )
+            | (CompressedFormat::CR, CompressedArgs::Rs1) => (7, false),
+            (CompressedFormat::CR | CompressedFormat::CSS, _) => (2, false),
+            (
+                CompressedFormat::CIW | CompressedFormat::CL,
+                CompressedArgs::Rd | CompressedArgs::FRd,
+            )
+            | (
+                CompressedFormat::CS | CompressedFormat::CA,
+                CompressedArgs::Rs2 | CompressedArgs::FRs2,
+            ) => (2, true),
+            _ => (7, true),
+        }
+    }
+}
+
+pub enum CompressedArgs {
+    // Destination, which doubles as first source in the CI, CR, CA and CB formats
+    Rd,
+    Rs1,
+    Rs2,
+    FRd,
+    FRs2,
+    // Stack pointer named explicitly, as in `c.addi16sp sp, 16`
+    Sp,
+    Imm,
+    Shamt,
+    // Offset from the rs1' register
+    Mem,
+    // Offset from sp, e.g. `4(sp)`
+    SpMem,
+    NoArg,
+}
+
+// Immediate scattered over a compressed instruction, each field placing imm[msb:lsb] at the
+// given instruction bit
+pub struct ImmLayout<'a> {
+    pub fields: &'a [(u32, u32, u32)],
+    pub signed: bool,
+    // Symbols are resolved relative to the instruction
+    pub pc_relative: bool,
+}
+
+impl ImmLayout<'_> {
+    // Inclusive range of values accepted as immediate
+    pub fn imm_range(&self) -> (i64, i64) {
+        let msb = self.fields.iter().map(|(msb, _, _)| *msb).max().unwrap();
+        if self.signed {
+            (-(1 << msb), (1 << msb) - 1)
+        } else {
+            (0, (1 << (msb + 1)) - 1)
+        }
+    }
+
+    // Immediate must be a multiple of this value
+    pub fn imm_alignment(&self) -> i64 {
+        1 << self.fields.iter().map(|(_, lsb, _)| *lsb).min().unwrap()
+    }
+
+    pub fn scatter(&self, imm: i64) -> u32 {
+        let imm = imm as u32;
+        self.fields.iter().fold(0, |bits, (msb, lsb, position)| {
+            let width = msb - lsb + 1;
+            bits | (((imm >> lsb) & ((1 << width) - 1)) << position)
+        })
+    }
+
+    pub fn gather(&self, word: u32) -> i64 {
+        let imm = self.fields.iter().fold(0, |imm, (msb, lsb, position)| {
+            let width = msb - lsb + 1;
+            imm | (((word >> position) & ((1 << width) - 1)) << lsb)
+        }) as i64;
+        let (min, max) = self.imm_range();
+        if imm > max {
+            imm - (max - min + 1)
+        } else {
+            imm
+        }
+    }
+}
+
+const CI_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 5, 12), (4, 0, 2)],
+    signed: true,
+    pc_relative: false,
+};
+
+// Upper immediate of c.lui, laid out as the 32-bit value it loads
+const CI_LUI_IMM: ImmLayout = ImmLayout {
+    fields: &[(17, 17, 12), (16, 12, 2)],
+    signed: true,
+    pc_relative: false,
+};
+
+const CI_ADDI16SP_IMM: ImmLayout = ImmLayout {
+    fields: &[(9, 9, 12), (4, 4, 6), (6, 6, 5), (8, 7, 3), (5, 5, 2)],
+    signed: true,
+    pc_relative: false,
+};
+
+const CIW_ADDI4SPN_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 4, 11), (9, 6, 7), (2, 2, 6), (3, 3, 5)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CL_WORD_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 3, 10), (2, 2, 6), (6, 6, 5)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CL_DOUBLE_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 3, 10), (7, 6, 5)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CI_LWSP_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 5, 12), (4, 2, 4), (7, 6, 2)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CI_LDSP_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 5, 12), (4, 3, 5), (8, 6, 2)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CSS_SWSP_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 2, 9), (7, 6, 7)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CSS_SDSP_IMM: ImmLayout = ImmLayout {
+    fields: &[(5, 3, 10), (8, 6, 7)],
+    signed: false,
+    pc_relative: false,
+};
+
+const CB_IMM: ImmLayout = ImmLayout {
+    fields: &[(8, 8, 12), (4, 3, 10), (7, 6, 5), (2, 1, 3), (5, 5, 2)],
+    signed: true,
+    pc_relative: true,
+};
+
+const CJ_IMM: ImmLayout = ImmLayout {
+    fields: &[
+        (11, 11, 12),
+        (4, 4, 11),
+        (9, 8, 9),
+        (10, 10, 8),
+        (6, 6, 7),
+        (7, 7, 6),
+        (3, 1, 3),
+        (5, 5, 2),
+    ],
+    signed: true,
+    pc_relative: true,
+};
+
+// Operand of a base instruction in terms of the compressed instruction standing for it
+pub enum ExpandArg {
+    Operand(usize),
+    Reg(&'static str),
+    Imm(i64),
+}
+
+// Base instruction a compressed instruction expands to
+pub struct Expansion<'a> {
+    pub base: &'a str,
+    pub arguments: &'a [ExpandArg],
+}
+
+pub struct CompressedInstruction<'a> {
+    pub format: CompressedFormat,
+    pub asm_string: &'a str,
+    pub opcode_func: u32,
+    pub mask: u32,
+    pub num_of_arguments: usize,
+    pub arguments: [CompressedArgs; 3],
+    pub imm_layout: Option<&'a ImmLayout<'a>>,
+    // Registers outside the x8-x15 subset may not be zero
+    pub nonzero_reg: bool,
+    pub nonzero_imm: bool,
+    pub expansions: &'a [Expansion<'a>],
+}
+
+// Compressed instructions common to RV32C and RV64C
+pub const C_INSTRUCTIONS: [CompressedInstruction; 26] = [
+    CompressedInstruction {
+        format: CompressedFormat::CIW,
+        asm_string: "c.addi4spn",
+        opcode_func: MATCH_C_ADDI4SPN,
+        mask: MASK_C_ADDI4SPN,
+        num_of_arguments: 3,
+        arguments: [CompressedArgs::Rd, CompressedArgs::Sp, CompressedArgs::Imm],
+        imm_layout: Some(&CIW_ADDI4SPN_IMM),
+        nonzero_reg: false,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "addi",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+                ExpandArg::Operand(2),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CL,
+        asm_string: "c.lw",
+        opcode_func: MATCH_C_LW,
+        mask: MASK_C_LW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_WORD_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "lw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CS,
+        asm_string: "c.sw",
+        opcode_func: MATCH_C_SW,
+        mask: MASK_C_SW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs2,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_WORD_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "sw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.nop",
+        opcode_func: MATCH_C_NOP,
+        mask: MASK_C_NOP,
+        num_of_arguments: 0,
+        arguments: [
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "addi",
+            arguments: &[
+                ExpandArg::Reg("zero"),
+                ExpandArg::Reg("zero"),
+                ExpandArg::Imm(0),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.addi",
+        opcode_func: MATCH_C_ADDI,
+        mask: MASK_C_ADDI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_IMM),
+        nonzero_reg: true,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "addi",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.li",
+        opcode_func: MATCH_C_LI,
+        mask: MASK_C_LI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_IMM),
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "addi",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Reg("zero"),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.addi16sp",
+        opcode_func: MATCH_C_ADDI16SP,
+        mask: MASK_C_ADDI16SP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Sp,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_ADDI16SP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "addi",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.lui",
+        opcode_func: MATCH_C_LUI,
+        mask: MASK_C_LUI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_LUI_IMM),
+        nonzero_reg: true,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "lui",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CB,
+        asm_string: "c.srli",
+        opcode_func: MATCH_C_SRLI,
+        mask: MASK_C_SRLI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Shamt,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "srli",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CB,
+        asm_string: "c.srai",
+        opcode_func: MATCH_C_SRAI,
+        mask: MASK_C_SRAI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Shamt,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "srai",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CB,
+        asm_string: "c.andi",
+        opcode_func: MATCH_C_ANDI,
+        mask: MASK_C_ANDI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "andi",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.sub",
+        opcode_func: MATCH_C_SUB,
+        mask: MASK_C_SUB,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "sub",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.xor",
+        opcode_func: MATCH_C_XOR,
+        mask: MASK_C_XOR,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "xor",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "xor",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Operand(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.or",
+        opcode_func: MATCH_C_OR,
+        mask: MASK_C_OR,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "or",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "or",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Operand(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.and",
+        opcode_func: MATCH_C_AND,
+        mask: MASK_C_AND,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "and",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "and",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Operand(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CJ,
+        asm_string: "c.j",
+        opcode_func: MATCH_C_J,
+        mask: MASK_C_J,
+        num_of_arguments: 1,
+        arguments: [
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CJ_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "jal",
+            arguments: &[ExpandArg::Reg("zero"), ExpandArg::Operand(0)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CB,
+        asm_string: "c.beqz",
+        opcode_func: MATCH_C_BEQZ,
+        mask: MASK_C_BEQZ,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs1,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CB_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "beq",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Reg("zero"),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CB,
+        asm_string: "c.bnez",
+        opcode_func: MATCH_C_BNEZ,
+        mask: MASK_C_BNEZ,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs1,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CB_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "bne",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Reg("zero"),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.slli",
+        opcode_func: MATCH_C_SLLI,
+        mask: MASK_C_SLLI,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Shamt,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: true,
+        nonzero_imm: true,
+        expansions: &[Expansion {
+            base: "slli",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.lwsp",
+        opcode_func: MATCH_C_LWSP,
+        mask: MASK_C_LWSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_LWSP_IMM),
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "lw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CR,
+        asm_string: "c.jr",
+        opcode_func: MATCH_C_JR,
+        mask: MASK_C_JR,
+        num_of_arguments: 1,
+        arguments: [
+            CompressedArgs::Rs1,
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "jalr",
+            arguments: &[
+                ExpandArg::Reg("zero"),
+                ExpandArg::Operand(0),
+                ExpandArg::Imm(0),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CR,
+        asm_string: "c.mv",
+        opcode_func: MATCH_C_MV,
+        mask: MASK_C_MV,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "add",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Reg("zero"),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "addi",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Imm(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CR,
+        asm_string: "c.ebreak",
+        opcode_func: MATCH_C_EBREAK,
+        mask: MASK_C_EBREAK,
+        num_of_arguments: 0,
+        arguments: [
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "ebreak",
+            arguments: &[],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CR,
+        asm_string: "c.jalr",
+        opcode_func: MATCH_C_JALR,
+        mask: MASK_C_JALR,
+        num_of_arguments: 1,
+        arguments: [
+            CompressedArgs::Rs1,
+            CompressedArgs::NoArg,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "jalr",
+            arguments: &[
+                ExpandArg::Reg("ra"),
+                ExpandArg::Operand(0),
+                ExpandArg::Imm(0),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CR,
+        asm_string: "c.add",
+        opcode_func: MATCH_C_ADD,
+        mask: MASK_C_ADD,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "add",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "add",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Operand(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CSS,
+        asm_string: "c.swsp",
+        opcode_func: MATCH_C_SWSP,
+        mask: MASK_C_SWSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs2,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CSS_SWSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "sw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+];
+
+// Compressed instructions only RV32 has
+pub const RV32C_INSTRUCTIONS: [CompressedInstruction; 1] = [CompressedInstruction {
+    format: CompressedFormat::CJ,
+    asm_string: "c.jal",
+    opcode_func: MATCH_C_JAL,
+    mask: MASK_C_JAL,
+    num_of_arguments: 1,
+    arguments: [
+        CompressedArgs::Imm,
+        CompressedArgs::NoArg,
+        CompressedArgs::NoArg,
+    ],
+    imm_layout: Some(&CJ_IMM),
+    nonzero_reg: false,
+    nonzero_imm: false,
+    expansions: &[Expansion {
+        base: "jal",
+        arguments: &[ExpandArg::Reg("ra"), ExpandArg::Operand(0)],
+    }],
+}];
+
+// Compressed instructions RV64 adds
+pub const RV64C_INSTRUCTIONS: [CompressedInstruction; 7] = [
+    CompressedInstruction {
+        format: CompressedFormat::CL,
+        asm_string: "c.ld",
+        opcode_func: MATCH_C_LD,
+        mask: MASK_C_LD,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_DOUBLE_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "ld",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CS,
+        asm_string: "c.sd",
+        opcode_func: MATCH_C_SD,
+        mask: MASK_C_SD,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs2,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_DOUBLE_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "sd",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.addiw",
+        opcode_func: MATCH_C_ADDIW,
+        mask: MASK_C_ADDIW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Imm,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_IMM),
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "addiw",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.subw",
+        opcode_func: MATCH_C_SUBW,
+        mask: MASK_C_SUBW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "subw",
+            arguments: &[
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(0),
+                ExpandArg::Operand(1),
+            ],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CA,
+        asm_string: "c.addw",
+        opcode_func: MATCH_C_ADDW,
+        mask: MASK_C_ADDW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::Rs2,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: None,
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[
+            Expansion {
+                base: "addw",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                ],
+            },
+            Expansion {
+                base: "addw",
+                arguments: &[
+                    ExpandArg::Operand(0),
+                    ExpandArg::Operand(1),
+                    ExpandArg::Operand(0),
+                ],
+            },
+        ],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.ldsp",
+        opcode_func: MATCH_C_LDSP,
+        mask: MASK_C_LDSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rd,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_LDSP_IMM),
+        nonzero_reg: true,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "ld",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CSS,
+        asm_string: "c.sdsp",
+        opcode_func: MATCH_C_SDSP,
+        mask: MASK_C_SDSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::Rs2,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CSS_SDSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "sd",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+];
+
+// Compressed single-precision loads and stores, which RV64 reuses for ld and sd
+pub const RV32FC_INSTRUCTIONS: [CompressedInstruction; 4] = [
+    CompressedInstruction {
+        format: CompressedFormat::CL,
+        asm_string: "c.flw",
+        opcode_func: MATCH_C_FLW,
+        mask: MASK_C_FLW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRd,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_WORD_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "flw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CS,
+        asm_string: "c.fsw",
+        opcode_func: MATCH_C_FSW,
+        mask: MASK_C_FSW,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRs2,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_WORD_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fsw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.flwsp",
+        opcode_func: MATCH_C_FLWSP,
+        mask: MASK_C_FLWSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRd,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_LWSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "flw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CSS,
+        asm_string: "c.fswsp",
+        opcode_func: MATCH_C_FSWSP,
+        mask: MASK_C_FSWSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRs2,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CSS_SWSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fsw",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+];
+
+// Compressed double-precision loads and stores
+pub const DC_INSTRUCTIONS: [CompressedInstruction; 4] = [
+    CompressedInstruction {
+        format: CompressedFormat::CL,
+        asm_string: "c.fld",
+        opcode_func: MATCH_C_FLD,
+        mask: MASK_C_FLD,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRd,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_DOUBLE_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fld",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CS,
+        asm_string: "c.fsd",
+        opcode_func: MATCH_C_FSD,
+        mask: MASK_C_FSD,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRs2,
+            CompressedArgs::Mem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CL_DOUBLE_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fsd",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CI,
+        asm_string: "c.fldsp",
+        opcode_func: MATCH_C_FLDSP,
+        mask: MASK_C_FLDSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRd,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CI_LDSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fld",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+    CompressedInstruction {
+        format: CompressedFormat::CSS,
+        asm_string: "c.fsdsp",
+        opcode_func: MATCH_C_FSDSP,
+        mask: MASK_C_FSDSP,
+        num_of_arguments: 2,
+        arguments: [
+            CompressedArgs::FRs2,
+            CompressedArgs::SpMem,
+            CompressedArgs::NoArg,
+        ],
+        imm_layout: Some(&CSS_SDSP_IMM),
+        nonzero_reg: false,
+        nonzero_imm: false,
+        expansions: &[Expansion {
+            base: "fsd",
+            arguments: &[ExpandArg::Operand(0), ExpandArg::Operand(1)],
+        }],
+    },
+];
+
+pub struct CompressedSet<'a> {
+    // Extension required besides C
+    pub extension: &'a str,
+    pub xlen: Option<Xlen>,
+    pub instructions: &'a [CompressedInstruction<'a>],
+}
+
+pub const COMPRESSED_SETS: [CompressedSet; 5] = [
+    CompressedSet {
+        extension: "c",
+        xlen: None,
+        instructions: &C_INSTRUCTIONS,
+    },
+    CompressedSet {
+        extension: "c",
+        xlen: Some(Xlen::Rv32),
+        instructions: &RV32C_INSTRUCTIONS,
+    },
+    CompressedSet {
+        extension: "c",
+        xlen: Some(Xlen::Rv64),
+        instructions: &RV64C_INSTRUCTIONS,
+    },
+    CompressedSet {
+        extension: "f",
+        xlen: Some(Xlen::Rv32),
+        instructions: &RV32FC_INSTRUCTIONS,
+    },
+    CompressedSet {
+        extension: "d",
+        xlen: None,
+        instructions: &DC_INSTRUCTIONS,
+    },
+];
+
+// Instructions whose lowest two bits are not both set are 16 bits long
+pub fn instruction_size(word: u32) -> u32 {
+    if word & 0b11 == 0b11 {
+        4
+    } else {
+        2
+    }
+}
+
+// CSR names accepted in place of a 12-bit address
+pub const CSR_NAMES: [(&str, u16); 449] = [
+    ("fflags", CSR_FFLAGS),
+    ("frm", CSR_FRM),
+    ("fcsr", CSR_FCSR),
+    ("vstart", CSR_VSTART),
+    ("vxsat", CSR_VXSAT),
+    ("vxrm", CSR_VXRM),
+    ("vcsr", CSR_VCSR),
+    ("seed", CSR_SEED),
+    ("jvt", CSR_JVT),
+    ("cycle", CSR_CYCLE),
+    ("time", CSR_TIME),
+    ("instret", CSR_INSTRET),
+    ("hpmcounter3", CSR_HPMCOUNTER3),
+    ("hpmcounter4", CSR_HPMCOUNTER4),
+    ("hpmcounter5", CSR_HPMCOUNTER5),
+    ("hpmcounter6", CSR_HPMCOUNTER6),
+    ("hpmcounter7", CSR_HPMCOUNTER7),
+    ("hpmcounter8", CSR_HPMCOUNTER8),
+    ("hpmcounter9", CSR_HPMCOUNTER9),
+    ("hpmcounter10", CSR_HPMCOUNTER10),
+    ("hpmcounter11", CSR_HPMCOUNTER11),
+    ("hpmcounter12", CSR_HPMCOUNTER12),
+    ("hpmcounter13", CSR_HPMCOUNTER13),
+    ("hpmcounter14", CSR_HPMCOUNTER14),
+    ("hpmcounter15", CSR_HPMCOUNTER15),
+    ("hpmcounter16", CSR_HPMCOUNTER16),
+    ("hpmcounter17", CSR_HPMCOUNTER17),
+    ("hpmcounter18", CSR_HPMCOUNTER18),
+    ("hpmcounter19", CSR_HPMCOUNTER19),
+    ("hpmcounter20", CSR_HPMCOUNTER20),
+    ("hpmcounter21", CSR_HPMCOUNTER21),
+    ("hpmcounter22", CSR_HPMCOUNTER22),
+    ("hpmcounter23", CSR_HPMCOUNTER23),
+    ("hpmcounter24", CSR_HPMCOUNTER24),
+    ("hpmcounter25", CSR_HPMCOUNTER25),
+    ("hpmcounter26", CSR_HPMCOUNTER26),
+    ("hpmcounter27", CSR_HPMCOUNTER27),
+    ("hpmcounter28", CSR_HPMCOUNTER28),
+    ("hpmcounter29", CSR_HPMCOUNTER29),
+    ("hpmcounter30", CSR_HPMCOUNTER30),
+    ("hpmcounter31", CSR_HPMCOUNTER31),
+    ("vl", CSR_VL),
+    ("vtype", CSR_VTYPE),
+    ("vlenb", CSR_VLENB),
+    ("sstatus", CSR_SSTATUS),
+    ("sedeleg", CSR_SEDELEG),
+    ("sideleg", CSR_SIDELEG),
+    ("sie", CSR_SIE),
+    ("stvec", CSR_STVEC),
+    ("scounteren", CSR_SCOUNTEREN),
+    ("senvcfg", CSR_SENVCFG),
+    ("sstateen0", CSR_SSTATEEN0),
+    ("sstateen1", CSR_SSTATEEN1),
+    ("sstateen2", CSR_SSTATEEN2),
+    ("sstateen3", CSR_SSTATEEN3),
+    ("scountinhibit", CSR_SCOUNTINHIBIT),
+    ("sscratch", CSR_SSCRATCH),
+    ("sepc", CSR_SEPC),
+    ("scause", CSR_SCAUSE),
+    ("stval", CSR_STVAL),
+    ("sip", CSR_SIP),
+    ("stimecmp", CSR_STIMECMP),
+    ("siselect", CSR_SISELECT),
+    ("sireg", CSR_SIREG),
+    ("sireg2", CSR_SIREG2),
+    ("sireg3", CSR_SIREG3),
+    ("sireg4", CSR_SIREG4),
+    ("sireg5", CSR_SIREG5),
+    ("sireg6", CSR_SIREG6),
+    ("stopei", CSR_STOPEI),
+    ("satp", CSR_SATP),
+    ("scontext", CSR_SCONTEXT),
+    ("vsstatus", CSR_VSSTATUS),
+    ("vsie", CSR_VSIE),
+    ("vstvec", CSR_VSTVEC),
+    ("vsscratch", CSR_VSSCRATCH),
+    ("vsepc", CSR_VSEPC),
+    ("vscause", CSR_VSCAUSE),
+    ("vstval", CSR_VSTVAL),
+    ("vsip", CSR_VSIP),
+    ("vstimecmp", CSR_VSTIMECMP),
+    ("vsiselect", CSR_VSISELECT),
+    ("vsireg", CSR_VSIREG),
+    ("vsireg2", CSR_VSIREG2),
+    ("vsireg3", CSR_VSIREG3),
+    ("vsireg4", CSR_VSIREG4),
+    ("vsireg5", CSR_VSIREG5),
+    ("vsireg6", CSR_VSIREG6),
+    ("vstopei", CSR_VSTOPEI),
+    ("vsatp", CSR_VSATP),
+    ("hstatus", CSR_HSTATUS),
+    ("hedeleg", CSR_HEDELEG),
+    ("hideleg", CSR_HIDELEG),
+    ("hie", CSR_HIE),
+    ("htimedelta", CSR_HTIMEDELTA),
+    ("hcounteren", CSR_HCOUNTEREN),
+    ("hgeie", CSR_HGEIE),
+    ("hvien", CSR_HVIEN),
+    ("hvictl", CSR_HVICTL),
+    ("henvcfg", CSR_HENVCFG),
+    ("hstateen0", CSR_HSTATEEN0),
+    ("hstateen1", CSR_HSTATEEN1),
+    ("hstateen2", CSR_HSTATEEN2),
+    ("hstateen3", CSR_HSTATEEN3),
+    ("htval", CSR_HTVAL),
+    ("hip", CSR_HIP),
+    ("hvip", CSR_HVIP),
+    ("hviprio1", CSR_HVIPRIO1),
+    ("hviprio2", CSR_HVIPRIO2),
+    ("htinst", CSR_HTINST),
+    ("hgatp", CSR_HGATP),
+    ("hcontext", CSR_HCONTEXT),
+    ("hgeip", CSR_HGEIP),
+    ("vstopi", CSR_VSTOPI),
+    ("scountovf", CSR_SCOUNTOVF),
+    ("stopi", CSR_STOPI),
+    ("utvt", CSR_UTVT),
+    ("unxti", CSR_UNXTI),
+    ("uintstatus", CSR_UINTSTATUS),
+    ("uscratchcsw", CSR_USCRATCHCSW),
+    ("uscratchcswl", CSR_USCRATCHCSWL),
+    ("stvt", CSR_STVT),
+    ("snxti", CSR_SNXTI),
+    ("sintstatus", CSR_SINTSTATUS),
+    ("sscratchcsw", CSR_SSCRATCHCSW),
+    ("sscratchcswl", CSR_SSCRATCHCSWL),
+    ("mtvt", CSR_MTVT),
+    ("mnxti", CSR_MNXTI),
+    ("mintstatus", CSR_MINTSTATUS),
+    ("mscratchcsw", CSR_MSCRATCHCSW),
+    ("mscratchcswl", CSR_MSCRATCHCSWL),
+    ("mstatus", CSR_MSTATUS),
+    ("misa", CSR_MISA),
+    ("medeleg", CSR_MEDELEG),
+    ("mideleg", CSR_MIDELEG),
+    ("mie", CSR_MIE),
+    ("mtvec", CSR_MTVEC),
+    ("mcounteren", CSR_MCOUNTEREN),
+    ("mvien", CSR_MVIEN),
+    ("mvip", CSR_MVIP),
+    ("menvcfg", CSR_MENVCFG),
+    ("mstateen0", CSR_MSTATEEN0),
+    ("mstateen1", CSR_MSTATEEN1),
+    ("mstateen2", CSR_MSTATEEN2),
+    ("mstateen3", CSR_MSTATEEN3),
+    ("mcountinhibit", CSR_MCOUNTINHIBIT),
+    ("mscratch", CSR_MSCRATCH),
+    ("mepc", CSR_MEPC),
+    ("mcause", CSR_MCAUSE),
+    ("mtval", CSR_MTVAL),
+    ("mip", CSR_MIP),
+    ("mtinst", CSR_MTINST),
+    ("mtval2", CSR_MTVAL2),
+    ("miselect", CSR_MISELECT),
+    ("mireg", CSR_MIREG),
+    ("mireg2", CSR_MIREG2),
+    ("mireg3", CSR_MIREG3),
+    ("mireg4", CSR_MIREG4),
+    ("mireg5", CSR_MIREG5),
+    ("mireg6", CSR_MIREG6),
+    ("mtopei", CSR_MTOPEI),
+    ("pmpcfg0", CSR_PMPCFG0),
+    ("pmpcfg1", CSR_PMPCFG1),
+    ("pmpcfg2", CSR_PMPCFG2),
+    ("pmpcfg3", CSR_PMPCFG3),
+    ("pmpcfg4", CSR_PMPCFG4),
+    ("pmpcfg5", CSR_PMPCFG5),
+    ("pmpcfg6", CSR_PMPCFG6),
+    ("pmpcfg7", CSR_PMPCFG7),
+    ("pmpcfg8", CSR_PMPCFG8),
+    ("pmpcfg9", CSR_PMPCFG9),
+    ("pmpcfg10", CSR_PMPCFG10),
+    ("pmpcfg11", CSR_PMPCFG11),
+    ("pmpcfg12", CSR_PMPCFG12),
+    ("pmpcfg13", CSR_PMPCFG13),
+    ("pmpcfg14", CSR_PMPCFG14),
+    ("pmpcfg15", CSR_PMPCFG15),
+    ("pmpaddr0", CSR_PMPADDR0),
+    ("pmpaddr1", CSR_PMPADDR1),
+    ("pmpaddr2", CSR_PMPADDR2),
+    ("pmpaddr3", CSR_PMPADDR3),
+    ("pmpaddr4", CSR_PMPADDR4),
+    ("pmpaddr5", CSR_PMPADDR5),
+    ("pmpaddr6", CSR_PMPADDR6),
+    ("pmpaddr7", CSR_PMPADDR7),
+    ("pmpaddr8", CSR_PMPADDR8),
+    ("pmpaddr9", CSR_PMPADDR9),
+    ("pmpaddr10", CSR_PMPADDR10),
+    ("pmpaddr11", CSR_PMPADDR11),
+    ("pmpaddr12", CSR_PMPADDR12),
+    ("pmpaddr13", CSR_PMPADDR13),
+    ("pmpaddr14", CSR_PMPADDR14),
+    ("pmpaddr15", CSR_PMPADDR15),
+    ("pmpaddr16", CSR_PMPADDR16),
+    ("pmpaddr17", CSR_PMPADDR17),
+    ("pmpaddr18", CSR_PMPADDR18),
+    ("pmpaddr19", CSR_PMPADDR19),
+    ("pmpaddr20", CSR_PMPADDR20),
+    ("pmpaddr21", CSR_PMPADDR21),
+    ("pmpaddr22", CSR_PMPADDR22),
+    ("pmpaddr23", CSR_PMPADDR23),
+    ("pmpaddr24", CSR_PMPADDR24),
+    ("pmpaddr25", CSR_PMPADDR25),
+    ("pmpaddr26", CSR_PMPADDR26),
+    ("pmpaddr27", CSR_PMPADDR27),
+    ("pmpaddr28", CSR_PMPADDR28),
+    ("pmpaddr29", CSR_PMPADDR29),
+    ("pmpaddr30", CSR_PMPADDR30),
+    ("pmpaddr31", CSR_PMPADDR31),
+    ("pmpaddr32", CSR_PMPADDR32),
+    ("pmpaddr33", CSR_PMPADDR33),
+    ("pmpaddr34", CSR_PMPADDR34),
+    ("pmpaddr35", CSR_PMPADDR35),
+    ("pmpaddr36", CSR_PMPADDR36),
+    ("pmpaddr37", CSR_PMPADDR37),
+    ("pmpaddr38", CSR_PMPADDR38),
+    ("pmpaddr39", CSR_PMPADDR39),
+    ("pmpaddr40", CSR_PMPADDR40),
+    ("pmpaddr41", CSR_PMPADDR41),
+    ("pmpaddr42", CSR_PMPADDR42),
+    ("pmpaddr43", CSR_PMPADDR43),
+    ("pmpaddr44", CSR_PMPADDR44),
+    ("pmpaddr45", CSR_PMPADDR45),
+    ("pmpaddr46", CSR_PMPADDR46),
+    ("pmpaddr47", CSR_PMPADDR47),
+    ("pmpaddr48", CSR_PMPADDR48),
+    ("pmpaddr49", CSR_PMPADDR49),
+    ("pmpaddr50", CSR_PMPADDR50),
+    ("pmpaddr51", CSR_PMPADDR51),
+    ("pmpaddr52", CSR_PMPADDR52),
+    ("pmpaddr53", CSR_PMPADDR53),
+    ("pmpaddr54", CSR_PMPADDR54),
+    ("pmpaddr55", CSR_PMPADDR55),
+    ("pmpaddr56", CSR_PMPADDR56),
+    ("pmpaddr57", CSR_PMPADDR57),
+    ("pmpaddr58", CSR_PMPADDR58),
+    ("pmpaddr59", CSR_PMPADDR59),
+    ("pmpaddr60", CSR_PMPADDR60),
+    ("pmpaddr61", CSR_PMPADDR61),
+    ("pmpaddr62", CSR_PMPADDR62),
+    ("pmpaddr63", CSR_PMPADDR63),
+    ("mseccfg", CSR_MSECCFG),
+    ("tselect", CSR_TSELECT),
+    ("tdata1", CSR_TDATA1),
+    ("tdata2", CSR_TDATA2),
+    ("tdata3", CSR_TDATA3),
+    ("tinfo", CSR_TINFO),
+    ("tcontrol", CSR_TCONTROL),
+    ("mcontext", CSR_MCONTEXT),
+    ("mscontext", CSR_MSCONTEXT),
+    ("dcsr", CSR_DCSR),
+    ("dpc", CSR_DPC),
+    ("dscratch0", CSR_DSCRATCH0),
+    ("dscratch1", CSR_DSCRATCH1),
+    ("mcycle", CSR_MCYCLE),
+    ("minstret", CSR_MINSTRET),
+    ("mhpmcounter3", CSR_MHPMCOUNTER3),
+    ("mhpmcounter4", CSR_MHPMCOUNTER4),
+    ("mhpmcounter5", CSR_MHPMCOUNTER5),
+    ("mhpmcounter6", CSR_MHPMCOUNTER6),
+    ("mhpmcounter7", CSR_MHPMCOUNTER7),
+    ("mhpmcounter8", CSR_MHPMCOUNTER8),
+    ("mhpmcounter9", CSR_MHPMCOUNTER9),
+    ("mhpmcounter10", CSR_MHPMCOUNTER10),
+    ("mhpmcounter11", CSR_MHPMCOUNTER11),
+    ("mhpmcounter12", CSR_MHPMCOUNTER12),
+    ("mhpmcounter13", CSR_MHPMCOUNTER13),
+    ("mhpmcounter14", CSR_MHPMCOUNTER14),
+    ("mhpmcounter15", CSR_MHPMCOUNTER15),
+    ("mhpmcounter16", CSR_MHPMCOUNTER16),
+    ("mhpmcounter17", CSR_MHPMCOUNTER17),
+    ("mhpmcounter18", CSR_MHPMCOUNTER18),
+    ("mhpmcounter19", CSR_MHPMCOUNTER19),
+    ("mhpmcounter20", CSR_MHPMCOUNTER20),
+    ("mhpmcounter21", CSR_MHPMCOUNTER21),
+    ("mhpmcounter22", CSR_MHPMCOUNTER22),
+    ("mhpmcounter23", CSR_MHPMCOUNTER23),
+    ("mhpmcounter24", CSR_MHPMCOUNTER24),
+    ("mhpmcounter25", CSR_MHPMCOUNTER25),
+    ("mhpmcounter26", CSR_MHPMCOUNTER26),
+    ("mhpmcounter27", CSR_MHPMCOUNTER27),
+    ("mhpmcounter28", CSR_MHPMCOUNTER28),
+    ("mhpmcounter29", CSR_MHPMCOUNTER29),
+    ("mhpmcounter30", CSR_MHPMCOUNTER30),
+    ("mhpmcounter31", CSR_MHPMCOUNTER31),
+    ("mcyclecfg", CSR_MCYCLECFG),
+    ("minstretcfg", CSR_MINSTRETCFG),
+    ("mhpmevent3", CSR_MHPMEVENT3),
+    ("mhpmevent4", CSR_MHPMEVENT4),
+    ("mhpmevent5", CSR_MHPMEVENT5),
+    ("mhpmevent6", CSR_MHPMEVENT6),
+    ("mhpmevent7", CSR_MHPMEVENT7),
+    ("mhpmevent8", CSR_MHPMEVENT8),
+    ("mhpmevent9", CSR_MHPMEVENT9),
+    ("mhpmevent10", CSR_MHPMEVENT10),
+    ("mhpmevent11", CSR_MHPMEVENT11),
+    ("mhpmevent12", CSR_MHPMEVENT12),
+    ("mhpmevent13", CSR_MHPMEVENT13),
+    ("mhpmevent14", CSR_MHPMEVENT14),
+    ("mhpmevent15", CSR_MHPMEVENT15),
+    ("mhpmevent16", CSR_MHPMEVENT16),
+    ("mhpmevent17", CSR_MHPMEVENT17),
+    ("mhpmevent18", CSR_MHPMEVENT18),
+    ("mhpmevent19", CSR_MHPMEVENT19),
+    ("mhpmevent20", CSR_MHPMEVENT20),
+    ("mhpmevent21", CSR_MHPMEVENT21),
+    ("mhpmevent22", CSR_MHPMEVENT22),
+    ("mhpmevent23", CSR_MHPMEVENT23),
+    ("mhpmevent24", CSR_MHPMEVENT24),
+    ("mhpmevent25", CSR_MHPMEVENT25),
+    ("mhpmevent26", CSR_MHPMEVENT26),
+    ("mhpmevent27", CSR_MHPMEVENT27),
+    ("mhpmevent28", CSR_MHPMEVENT28),
+    ("mhpmevent29", CSR_MHPMEVENT29),
+    ("mhpmevent30", CSR_MHPMEVENT30),
+    ("mhpmevent31", CSR_MHPMEVENT31),
+    ("mvendorid", CSR_MVENDORID),
+    ("marchid", CSR_MARCHID),
+    ("mimpid", CSR_MIMPID),
+    ("mhartid", CSR_MHARTID),
+    ("mconfigptr", CSR_MCONFIGPTR),
+    ("mtopi", CSR_MTOPI),
+    ("sieh", CSR_SIEH),
+    ("siph", CSR_SIPH),
+    ("stimecmph", CSR_STIMECMPH),
+    ("vsieh", CSR_VSIEH),
+    ("vsiph", CSR_VSIPH),
+    ("vstimecmph", CSR_VSTIMECMPH),
+    ("htimedeltah", CSR_HTIMEDELTAH),
+    ("hidelegh", CSR_HIDELEGH),
+    ("hvienh", CSR_HVIENH),
+    ("henvcfgh", CSR_HENVCFGH),
+    ("hviph", CSR_HVIPH),
+    ("hviprio1h", CSR_HVIPRIO1H),
+    ("hviprio2h", CSR_HVIPRIO2H),
+    ("hstateen0h", CSR_HSTATEEN0H),
+    ("hstateen1h", CSR_HSTATEEN1H),
+    ("hstateen2h", CSR_HSTATEEN2H),
+    ("hstateen3h", CSR_HSTATEEN3H),
+    ("cycleh", CSR_CYCLEH),
+    ("timeh", CSR_TIMEH),
+    ("instreth", CSR_INSTRETH),
+    ("hpmcounter3h", CSR_HPMCOUNTER3H),
+    ("hpmcounter4h", CSR_HPMCOUNTER4H),
+    ("hpmcounter5h", CSR_HPMCOUNTER5H),
+    ("hpmcounter6h", CSR_HPMCOUNTER6H),
+    ("hpmcounter7h", CSR_HPMCOUNTER7H),
+    ("hpmcounter8h", CSR_HPMCOUNTER8H),
+    ("hpmcounter9h", CSR_HPMCOUNTER9H),
+    ("hpmcounter10h", CSR_HPMCOUNTER10H),
+    ("hpmcounter11h", CSR_HPMCOUNTER11H),
+    ("hpmcounter12h", CSR_HPMCOUNTER12H),
+    ("hpmcounter13h", CSR_HPMCOUNTER13H),
+    ("hpmcounter14h", CSR_HPMCOUNTER14H),
+    ("hpmcounter15h", CSR_HPMCOUNTER15H),
+    ("hpmcounter16h", CSR_HPMCOUNTER16H),
+    ("hpmcounter17h", CSR_HPMCOUNTER17H),
+    ("hpmcounter18h", CSR_HPMCOUNTER18H),
+    ("hpmcounter19h", CSR_HPMCOUNTER19H),
+    ("hpmcounter20h", CSR_HPMCOUNTER20H),
+    ("hpmcounter21h", CSR_HPMCOUNTER21H),
+    ("hpmcounter22h", CSR_HPMCOUNTER22H),
+    ("hpmcounter23h", CSR_HPMCOUNTER23H),
+    ("hpmcounter24h", CSR_HPMCOUNTER24H),
+    ("hpmcounter25h", CSR_HPMCOUNTER25H),
+    ("hpmcounter26h", CSR_HPMCOUNTER26H),
+    ("hpmcounter27h", CSR_HPMCOUNTER27H),
+    ("hpmcounter28h", CSR_HPMCOUNTER28H),
+    ("hpmcounter29h", CSR_HPMCOUNTER29H),
+    ("hpmcounter30h", CSR_HPMCOUNTER30H),
+    ("hpmcounter31h", CSR_HPMCOUNTER31H),
+    ("mstatush", CSR_MSTATUSH),
+    ("midelegh", CSR_MIDELEGH),
+    ("mieh", CSR_MIEH),
+    ("mvienh", CSR_MVIENH),
+    ("mviph", CSR_MVIPH),
+    ("menvcfgh", CSR_MENVCFGH),
+    ("mstateen0h", CSR_MSTATEEN0H),
+    ("mstateen1h", CSR_MSTATEEN1H),
+    ("mstateen2h", CSR_MSTATEEN2H),
+    ("mstateen3h", CSR_MSTATEEN3H),
+    ("miph", CSR_MIPH),
+    ("mcyclecfgh", CSR_MCYCLECFGH),
+    ("minstretcfgh", CSR_MINSTRETCFGH),
+    ("mhpmevent3h", CSR_MHPMEVENT3H),
+    ("mhpmevent4h", CSR_MHPMEVENT4H),
+    ("mhpmevent5h", CSR_MHPMEVENT5H),
+    ("mhpmevent6h", CSR_MHPMEVENT6H),
+    ("mhpmevent7h", CSR_MHPMEVENT7H),
+    ("mhpmevent8h", CSR_MHPMEVENT8H),
+    ("mhpmevent9h", CSR_MHPMEVENT9H),
+    ("mhpmevent10h", CSR_MHPMEVENT10H),
+    ("mhpmevent11h", CSR_MHPMEVENT11H),
+    ("mhpmevent12h", CSR_MHPMEVENT12H),
+    ("mhpmevent13h", CSR_MHPMEVENT13H),
+    ("mhpmevent14h", CSR_MHPMEVENT14H),
+    ("mhpmevent15h", CSR_MHPMEVENT15H),
+    ("mhpmevent16h", CSR_MHPMEVENT16H),
+    ("mhpmevent17h", CSR_MHPMEVENT17H),
+    ("mhpmevent18h", CSR_MHPMEVENT18H),
+    ("mhpmevent19h", CSR_MHPMEVENT19H),
+    ("mhpmevent20h", CSR_MHPMEVENT20H),
+    ("mhpmevent21h", CSR_MHPMEVENT21H),
+    ("mhpmevent22h", CSR_MHPMEVENT22H),
+    ("mhpmevent23h", CSR_MHPMEVENT23H),
+    ("mhpmevent24h", CSR_MHPMEVENT24H),
+    ("mhpmevent25h", CSR_MHPMEVENT25H),
+    ("mhpmevent26h", CSR_MHPMEVENT26H),
+    ("mhpmevent27h", CSR_MHPMEVENT27H),
+    ("mhpmevent28h", CSR_MHPMEVENT28H),
+    ("mhpmevent29h", CSR_MHPMEVENT29H),
+    ("mhpmevent30h", CSR_MHPMEVENT30H),
+    ("mhpmevent31h", CSR_MHPMEVENT31H),
+    ("mnscratch", CSR_MNSCRATCH),
+    ("mnepc", CSR_MNEPC),
+    ("mncause", CSR_MNCAUSE),
+    ("mnstatus", CSR_MNSTATUS),
+    ("mseccfgh", CSR_MSECCFGH),
+    ("mcycleh", CSR_MCYCLEH),
+    ("minstreth", CSR_MINSTRETH),
+    ("mhpmcounter3h", CSR_MHPMCOUNTER3H),
+    ("mhpmcounter4h", CSR_MHPMCOUNTER4H),
+    ("mhpmcounter5h", CSR_MHPMCOUNTER5H),
+    ("mhpmcounter6h", CSR_MHPMCOUNTER6H),
+    ("mhpmcounter7h", CSR_MHPMCOUNTER7H),
+    ("mhpmcounter8h", CSR_MHPMCOUNTER8H),
+    ("mhpmcounter9h", CSR_MHPMCOUNTER9H),
+    ("mhpmcounter10h", CSR_MHPMCOUNTER10H),
+    ("mhpmcounter11h", CSR_MHPMCOUNTER11H),
+    ("mhpmcounter12h", CSR_MHPMCOUNTER12H),
+    ("mhpmcounter13h", CSR_MHPMCOUNTER13H),
+    ("mhpmcounter14h", CSR_MHPMCOUNTER14H),
+    ("mhpmcounter15h", CSR_MHPMCOUNTER15H),
+    ("mhpmcounter16h", CSR_MHPMCOUNTER16H),
+    ("mhpmcounter17h", CSR_MHPMCOUNTER17H),
+    ("mhpmcounter18h", CSR_MHPMCOUNTER18H),
+    ("mhpmcounter19h", CSR_MHPMCOUNTER19H),
+    ("mhpmcounter20h", CSR_MHPMCOUNTER20H),
+    ("mhpmcounter21h", CSR_MHPMCOUNTER21H),
+    ("mhpmcounter22h", CSR_MHPMCOUNTER22H),
+    ("mhpmcounter23h", CSR_MHPMCOUNTER23H),
+    ("mhpmcounter24h", CSR_MHPMCOUNTER24H),
+    ("mhpmcounter25h", CSR_MHPMCOUNTER25H),
+    ("mhpmcounter26h", CSR_MHPMCOUNTER26H),
+    ("mhpmcounter27h", CSR_MHPMCOUNTER27H),
+    ("mhpmcounter28h", CSR_MHPMCOUNTER28H),
+    ("mhpmcounter29h", CSR_MHPMCOUNTER29H),
+    ("mhpmcounter30h", CSR_MHPMCOUNTER30H),
+    ("mhpmcounter31h", CSR_MHPMCOUNTER31H),
 ];
 
 /* Automatically generated by parse_opcodes */
 const MATCH_ADD: u32 = 0x33;
-// const MASK_ADD: u32 = 0xfe00707f;
+const MASK_ADD: u32 = 0xfe00707f;
 // const MATCH_ADD16: u32 = 0x40000077;
 // const MASK_ADD16: u32 = 0xfe00707f;
 // const MATCH_ADD32: u32 = 0x40002077;
@@ -302,13 +4017,13 @@ const MATCH_ADD: u32 = 0x33;
 // const MATCH_ADDD: u32 = 0x7b;
 // const MASK_ADDD: u32 = 0xfe00707f;
 const MATCH_ADDI: u32 = 0x13;
-// const MASK_ADDI: u32 = 0x707f;
+const MASK_ADDI: u32 = 0x707f;
 // const MATCH_ADDID: u32 = 0x5b;
 // const MASK_ADDID: u32 = 0x707f;
-// const MATCH_ADDIW: u32 = 0x1b;
-// const MASK_ADDIW: u32 = 0x707f;
-// const MATCH_ADDW: u32 = 0x3b;
-// const MASK_ADDW: u32 = 0xfe00707f;
+const MATCH_ADDIW: u32 = 0x1b;
+const MASK_ADDIW: u32 = 0x707f;
+const MATCH_ADDW: u32 = 0x3b;
+const MASK_ADDW: u32 = 0xfe00707f;
 // const MATCH_AES32DSI: u32 = 0x2a000033;
 // const MASK_AES32DSI: u32 = 0x3e00707f;
 // const MATCH_AES32DSMI: u32 = 0x2e000033;
@@ -333,20 +4048,20 @@ const MATCH_ADDI: u32 = 0x13;
 // const MASK_AES64KS2: u32 = 0xfe00707f;
 // const MATCH_AMOADD_B: u32 = 0x2f;
 // const MASK_AMOADD_B: u32 = 0xf800707f;
-// const MATCH_AMOADD_D: u32 = 0x302f;
-// const MASK_AMOADD_D: u32 = 0xf800707f;
+const MATCH_AMOADD_D: u32 = 0x302f;
+const MASK_AMOADD_D: u32 = 0xf800707f;
 // const MATCH_AMOADD_H: u32 = 0x102f;
 // const MASK_AMOADD_H: u32 = 0xf800707f;
-// const MATCH_AMOADD_W: u32 = 0x202f;
-// const MASK_AMOADD_W: u32 = 0xf800707f;
+const MATCH_AMOADD_W: u32 = 0x202f;
+const MASK_AMOADD_W: u32 = 0xf800707f;
 // const MATCH_AMOAND_B: u32 = 0x6000002f;
 // const MASK_AMOAND_B: u32 = 0xf800707f;
-// const MATCH_AMOAND_D: u32 = 0x6000302f;
-// const MASK_AMOAND_D: u32 = 0xf800707f;
+const MATCH_AMOAND_D: u32 = 0x6000302f;
+const MASK_AMOAND_D: u32 = 0xf800707f;
 // const MATCH_AMOAND_H: u32 = 0x6000102f;
 // const MASK_AMOAND_H: u32 = 0xf800707f;
-// const MATCH_AMOAND_W: u32 = 0x6000202f;
-// const MASK_AMOAND_W: u32 = 0xf800707f;
+const MATCH_AMOAND_W: u32 = 0x6000202f;
+const MASK_AMOAND_W: u32 = 0xf800707f;
 // const MATCH_AMOCAS_B: u32 = 0x2800002f;
 // const MASK_AMOCAS_B: u32 = 0xf800707f;
 // const MATCH_AMOCAS_D: u32 = 0x2800302f;
@@ -359,68 +4074,68 @@ const MATCH_ADDI: u32 = 0x13;
 // const MASK_AMOCAS_W: u32 = 0xf800707f;
 // const MATCH_AMOMAX_B: u32 = 0xa000002f;
 // const MASK_AMOMAX_B: u32 = 0xf800707f;
-// const MATCH_AMOMAX_D: u32 = 0xa000302f;
-// const MASK_AMOMAX_D: u32 = 0xf800707f;
+const MATCH_AMOMAX_D: u32 = 0xa000302f;
+const MASK_AMOMAX_D: u32 = 0xf800707f;
 // const MATCH_AMOMAX_H: u32 = 0xa000102f;
 // const MASK_AMOMAX_H: u32 = 0xf800707f;
-// const MATCH_AMOMAX_W: u32 = 0xa000202f;
-// const MASK_AMOMAX_W: u32 = 0xf800707f;
+const MATCH_AMOMAX_W: u32 = 0xa000202f;
+const MASK_AMOMAX_W: u32 = 0xf800707f;
 // const MATCH_AMOMAXU_B: u32 = 0xe000002f;
 // const MASK_AMOMAXU_B: u32 = 0xf800707f;
-// const MATCH_AMOMAXU_D: u32 = 0xe000302f;
-// const MASK_AMOMAXU_D: u32 = 0xf800707f;
+const MATCH_AMOMAXU_D: u32 = 0xe000302f;
+const MASK_AMOMAXU_D: u32 = 0xf800707f;
 // const MATCH_AMOMAXU_H: u32 = 0xe000102f;
 // const MASK_AMOMAXU_H: u32 = 0xf800707f;
-// const MATCH_AMOMAXU_W: u32 = 0xe000202f;
-// const MASK_AMOMAXU_W: u32 = 0xf800707f;
+const MATCH_AMOMAXU_W: u32 = 0xe000202f;
+const MASK_AMOMAXU_W: u32 = 0xf800707f;
 // const MATCH_AMOMIN_B: u32 = 0x8000002f;
 // const MASK_AMOMIN_B: u32 = 0xf800707f;
-// const MATCH_AMOMIN_D: u32 = 0x8000302f;
-// const MASK_AMOMIN_D: u32 = 0xf800707f;
+const MATCH_AMOMIN_D: u32 = 0x8000302f;
+const MASK_AMOMIN_D: u32 = 0xf800707f;
 // const MATCH_AMOMIN_H: u32 = 0x8000102f;
 // const MASK_AMOMIN_H: u32 = 0xf800707f;
-// const MATCH_AMOMIN_W: u32 = 0x8000202f;
-// const MASK_AMOMIN_W: u32 = 0xf800707f;
+const MATCH_AMOMIN_W: u32 = 0x8000202f;
+const MASK_AMOMIN_W: u32 = 0xf800707f;
 // const MATCH_AMOMINU_B: u32 = 0xc000002f;
 // const MASK_AMOMINU_B: u32 = 0xf800707f;
-// const MATCH_AMOMINU_D: u32 = 0xc000302f;
-// const MASK_AMOMINU_D: u32 = 0xf800707f;
+const MATCH_AMOMINU_D: u32 = 0xc000302f;
+const MASK_AMOMINU_D: u32 = 0xf800707f;
 // const MATCH_AMOMINU_H: u32 = 0xc000102f;
 // const MASK_AMOMINU_H: u32 = 0xf800707f;
-// const MATCH_AMOMINU_W: u32 = 0xc000202f;
-// const MASK_AMOMINU_W: u32 = 0xf800707f;
+const MATCH_AMOMINU_W: u32 = 0xc000202f;
+const MASK_AMOMINU_W: u32 = 0xf800707f;
 // const MATCH_AMOOR_B: u32 = 0x4000002f;
 // const MASK_AMOOR_B: u32 = 0xf800707f;
-// const MATCH_AMOOR_D: u32 = 0x4000302f;
-// const MASK_AMOOR_D: u32 = 0xf800707f;
+const MATCH_AMOOR_D: u32 = 0x4000302f;
+const MASK_AMOOR_D: u32 = 0xf800707f;
 // const MATCH_AMOOR_H: u32 = 0x4000102f;
 // const MASK_AMOOR_H: u32 = 0xf800707f;
-// const MATCH_AMOOR_W: u32 = 0x4000202f;
-// const MASK_AMOOR_W: u32 = 0xf800707f;
+const MATCH_AMOOR_W: u32 = 0x4000202f;
+const MASK_AMOOR_W: u32 = 0xf800707f;
 // const MATCH_AMOSWAP_B: u32 = 0x800002f;
 // const MASK_AMOSWAP_B: u32 = 0xf800707f;
-// const MATCH_AMOSWAP_D: u32 = 0x800302f;
-// const MASK_AMOSWAP_D: u32 = 0xf800707f;
+const MATCH_AMOSWAP_D: u32 = 0x800302f;
+const MASK_AMOSWAP_D: u32 = 0xf800707f;
 // const MATCH_AMOSWAP_H: u32 = 0x800102f;
 // const MASK_AMOSWAP_H: u32 = 0xf800707f;
-// const MATCH_AMOSWAP_W: u32 = 0x800202f;
-// const MASK_AMOSWAP_W: u32 = 0xf800707f;
+const MATCH_AMOSWAP_W: u32 = 0x800202f;
+const MASK_AMOSWAP_W: u32 = 0xf800707f;
 // const MATCH_AMOXOR_B: u32 = 0x2000002f;
 // const MASK_AMOXOR_B: u32 = 0xf800707f;
-// const MATCH_AMOXOR_D: u32 = 0x2000302f;
-// const MASK_AMOXOR_D: u32 = 0xf800707f;
+const MATCH_AMOXOR_D: u32 = 0x2000302f;
+const MASK_AMOXOR_D: u32 = 0xf800707f;
 // const MATCH_AMOXOR_H: u32 = 0x2000102f;
 // const MASK_AMOXOR_H: u32 = 0xf800707f;
-// const MATCH_AMOXOR_W: u32 = 0x2000202f;
-// const MASK_AMOXOR_W: u32 = 0xf800707f;
+const MATCH_AMOXOR_W: u32 = 0x2000202f;
+const MASK_AMOXOR_W: u32 = 0xf800707f;
 const MATCH_AND: u32 = 0x7033;
-// const MASK_AND: u32 = 0xfe00707f;
+const MASK_AND: u32 = 0xfe00707f;
 const MATCH_ANDI: u32 = 0x7013;
-// const MASK_ANDI: u32 = 0x707f;
+const MASK_ANDI: u32 = 0x707f;
 // const MATCH_ANDN: u32 = 0x40007033;
 // const MASK_ANDN: u32 = 0xfe00707f;
 const MATCH_AUIPC: u32 = 0x17;
-// const MASK_AUIPC: u32 = 0x7f;
+const MASK_AUIPC: u32 = 0x7f;
 // const MATCH_AVE: u32 = 0xe0000077;
 // const MASK_AVE: u32 = 0xfe00707f;
 // const MATCH_BCLR: u32 = 0x48001033;
@@ -436,7 +4151,7 @@ const MATCH_AUIPC: u32 = 0x17;
 // const MATCH_BDECOMPRESSW: u32 = 0x4800603b;
 // const MASK_BDECOMPRESSW: u32 = 0xfe00707f;
 const MATCH_BEQ: u32 = 0x63;
-// const MASK_BEQ: u32 = 0x707f;
+const MASK_BEQ: u32 = 0x707f;
 // const MATCH_BEXT: u32 = 0x48005033;
 // const MASK_BEXT: u32 = 0xfe00707f;
 // const MATCH_BEXTI: u32 = 0x48005013;
@@ -446,17 +4161,17 @@ const MATCH_BEQ: u32 = 0x63;
 // const MATCH_BFPW: u32 = 0x4800703b;
 // const MASK_BFPW: u32 = 0xfe00707f;
 const MATCH_BGE: u32 = 0x5063;
-// const MASK_BGE: u32 = 0x707f;
+const MASK_BGE: u32 = 0x707f;
 const MATCH_BGEU: u32 = 0x7063;
-// const MASK_BGEU: u32 = 0x707f;
+const MASK_BGEU: u32 = 0x707f;
 // const MATCH_BINV: u32 = 0x68001033;
 // const MASK_BINV: u32 = 0xfe00707f;
 // const MATCH_BINVI: u32 = 0x68001013;
 // const MASK_BINVI: u32 = 0xfc00707f;
 const MATCH_BLT: u32 = 0x4063;
-// const MASK_BLT: u32 = 0x707f;
+const MASK_BLT: u32 = 0x707f;
 const MATCH_BLTU: u32 = 0x6063;
-// const MASK_BLTU: u32 = 0x707f;
+const MASK_BLTU: u32 = 0x707f;
 // const MATCH_BMATFLIP: u32 = 0x60301013;
 // const MASK_BMATFLIP: u32 = 0xfff0707f;
 // const MATCH_BMATOR: u32 = 0x8003033;
@@ -464,81 +4179,81 @@ const MATCH_BLTU: u32 = 0x6063;
 // const MATCH_BMATXOR: u32 = 0x48003033;
 // const MASK_BMATXOR: u32 = 0xfe00707f;
 const MATCH_BNE: u32 = 0x1063;
-// const MASK_BNE: u32 = 0x707f;
+const MASK_BNE: u32 = 0x707f;
 // const MATCH_BREV8: u32 = 0x68705013;
 // const MASK_BREV8: u32 = 0xfff0707f;
 // const MATCH_BSET: u32 = 0x28001033;
 // const MASK_BSET: u32 = 0xfe00707f;
 // const MATCH_BSETI: u32 = 0x28001013;
 // const MASK_BSETI: u32 = 0xfc00707f;
-// const MATCH_C_ADD: u32 = 0x9002;
-// const MASK_C_ADD: u32 = 0xf003;
-// const MATCH_C_ADDI: u32 = 0x1;
-// const MASK_C_ADDI: u32 = 0xe003;
-// const MATCH_C_ADDI16SP: u32 = 0x6101;
-// const MASK_C_ADDI16SP: u32 = 0xef83;
-// const MATCH_C_ADDI4SPN: u32 = 0x0;
-// const MASK_C_ADDI4SPN: u32 = 0xe003;
-// const MATCH_C_ADDIW: u32 = 0x2001;
-// const MASK_C_ADDIW: u32 = 0xe003;
-// const MATCH_C_ADDW: u32 = 0x9c21;
-// const MASK_C_ADDW: u32 = 0xfc63;
-// const MATCH_C_AND: u32 = 0x8c61;
-// const MASK_C_AND: u32 = 0xfc63;
-// const MATCH_C_ANDI: u32 = 0x8801;
-// const MASK_C_ANDI: u32 = 0xec03;
-// const MATCH_C_BEQZ: u32 = 0xc001;
-// const MASK_C_BEQZ: u32 = 0xe003;
-// const MATCH_C_BNEZ: u32 = 0xe001;
-// const MASK_C_BNEZ: u32 = 0xe003;
-// const MATCH_C_EBREAK: u32 = 0x9002;
-// const MASK_C_EBREAK: u32 = 0xffff;
-// const MATCH_C_FLD: u32 = 0x2000;
-// const MASK_C_FLD: u32 = 0xe003;
-// const MATCH_C_FLDSP: u32 = 0x2002;
-// const MASK_C_FLDSP: u32 = 0xe003;
-// const MATCH_C_FLW: u32 = 0x6000;
-// const MASK_C_FLW: u32 = 0xe003;
-// const MATCH_C_FLWSP: u32 = 0x6002;
-// const MASK_C_FLWSP: u32 = 0xe003;
-// const MATCH_C_FSD: u32 = 0xa000;
-// const MASK_C_FSD: u32 = 0xe003;
-// const MATCH_C_FSDSP: u32 = 0xa002;
-// const MASK_C_FSDSP: u32 = 0xe003;
-// const MATCH_C_FSW: u32 = 0xe000;
-// const MASK_C_FSW: u32 = 0xe003;
-// const MATCH_C_FSWSP: u32 = 0xe002;
-// const MASK_C_FSWSP: u32 = 0xe003;
-// const MATCH_C_J: u32 = 0xa001;
-// const MASK_C_J: u32 = 0xe003;
-// const MATCH_C_JAL: u32 = 0x2001;
-// const MASK_C_JAL: u32 = 0xe003;
-// const MATCH_C_JALR: u32 = 0x9002;
-// const MASK_C_JALR: u32 = 0xf07f;
-// const MATCH_C_JR: u32 = 0x8002;
-// const MASK_C_JR: u32 = 0xf07f;
+const MATCH_C_ADD: u32 = 0x9002;
+const MASK_C_ADD: u32 = 0xf003;
+const MATCH_C_ADDI: u32 = 0x1;
+const MASK_C_ADDI: u32 = 0xe003;
+const MATCH_C_ADDI16SP: u32 = 0x6101;
+const MASK_C_ADDI16SP: u32 = 0xef83;
+const MATCH_C_ADDI4SPN: u32 = 0x0;
+const MASK_C_ADDI4SPN: u32 = 0xe003;
+const MATCH_C_ADDIW: u32 = 0x2001;
+const MASK_C_ADDIW: u32 = 0xe003;
+const MATCH_C_ADDW: u32 = 0x9c21;
+const MASK_C_ADDW: u32 = 0xfc63;
+const MATCH_C_AND: u32 = 0x8c61;
+const MASK_C_AND: u32 = 0xfc63;
+const MATCH_C_ANDI: u32 = 0x8801;
+const MASK_C_ANDI: u32 = 0xec03;
+const MATCH_C_BEQZ: u32 = 0xc001;
+const MASK_C_BEQZ: u32 = 0xe003;
+const MATCH_C_BNEZ: u32 = 0xe001;
+const MASK_C_BNEZ: u32 = 0xe003;
+const MATCH_C_EBREAK: u32 = 0x9002;
+const MASK_C_EBREAK: u32 = 0xffff;
+const MATCH_C_FLD: u32 = 0x2000;
+const MASK_C_FLD: u32 = 0xe003;
+const MATCH_C_FLDSP: u32 = 0x2002;
+const MASK_C_FLDSP: u32 = 0xe003;
+const MATCH_C_FLW: u32 = 0x6000;
+const MASK_C_FLW: u32 = 0xe003;
+const MATCH_C_FLWSP: u32 = 0x6002;
+const MASK_C_FLWSP: u32 = 0xe003;
+const MATCH_C_FSD: u32 = 0xa000;
+const MASK_C_FSD: u32 = 0xe003;
+const MATCH_C_FSDSP: u32 = 0xa002;
+const MASK_C_FSDSP: u32 = 0xe003;
+const MATCH_C_FSW: u32 = 0xe000;
+const MASK_C_FSW: u32 = 0xe003;
+const MATCH_C_FSWSP: u32 = 0xe002;
+const MASK_C_FSWSP: u32 = 0xe003;
+const MATCH_C_J: u32 = 0xa001;
+const MASK_C_J: u32 = 0xe003;
+const MATCH_C_JAL: u32 = 0x2001;
+const MASK_C_JAL: u32 = 0xe003;
+const MATCH_C_JALR: u32 = 0x9002;
+const MASK_C_JALR: u32 = 0xf07f;
+const MATCH_C_JR: u32 = 0x8002;
+const MASK_C_JR: u32 = 0xf07f;
 // const MATCH_C_LBU: u32 = 0x8000;
 // const MASK_C_LBU: u32 = 0xfc03;
-// const MATCH_C_LD: u32 = 0x6000;
-// const MASK_C_LD: u32 = 0xe003;
-// const MATCH_C_LDSP: u32 = 0x6002;
-// const MASK_C_LDSP: u32 = 0xe003;
+const MATCH_C_LD: u32 = 0x6000;
+const MASK_C_LD: u32 = 0xe003;
+const MATCH_C_LDSP: u32 = 0x6002;
+const MASK_C_LDSP: u32 = 0xe003;
 // const MATCH_C_LH: u32 = 0x8440;
 // const MASK_C_LH: u32 = 0xfc43;
 // const MATCH_C_LHU: u32 = 0x8400;
 // const MASK_C_LHU: u32 = 0xfc43;
-// const MATCH_C_LI: u32 = 0x4001;
-// const MASK_C_LI: u32 = 0xe003;
+const MATCH_C_LI: u32 = 0x4001;
+const MASK_C_LI: u32 = 0xe003;
 // const MATCH_C_LQ: u32 = 0x2000;
 // const MASK_C_LQ: u32 = 0xe003;
 // const MATCH_C_LQSP: u32 = 0x2002;
 // const MASK_C_LQSP: u32 = 0xe003;
-// const MATCH_C_LUI: u32 = 0x6001;
-// const MASK_C_LUI: u32 = 0xe003;
-// const MATCH_C_LW: u32 = 0x4000;
-// const MASK_C_LW: u32 = 0xe003;
-// const MATCH_C_LWSP: u32 = 0x4002;
-// const MASK_C_LWSP: u32 = 0xe003;
+const MATCH_C_LUI: u32 = 0x6001;
+const MASK_C_LUI: u32 = 0xe003;
+const MATCH_C_LW: u32 = 0x4000;
+const MASK_C_LW: u32 = 0xe003;
+const MATCH_C_LWSP: u32 = 0x4002;
+const MASK_C_LWSP: u32 = 0xe003;
 // const MATCH_C_MOP_1: u32 = 0x6081;
 // const MASK_C_MOP_1: u32 = 0xffff;
 // const MATCH_C_MOP_11: u32 = 0x6581;
@@ -559,10 +4274,10 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_C_MOP_N: u32 = 0xf8ff;
 // const MATCH_C_MUL: u32 = 0x9c41;
 // const MASK_C_MUL: u32 = 0xfc63;
-// const MATCH_C_MV: u32 = 0x8002;
-// const MASK_C_MV: u32 = 0xf003;
-// const MATCH_C_NOP: u32 = 0x1;
-// const MASK_C_NOP: u32 = 0xef83;
+const MATCH_C_MV: u32 = 0x8002;
+const MASK_C_MV: u32 = 0xf003;
+const MATCH_C_NOP: u32 = 0x1;
+const MASK_C_NOP: u32 = 0xef83;
 // const MATCH_C_NOT: u32 = 0x9c75;
 // const MASK_C_NOT: u32 = 0xfc7f;
 // const MATCH_C_NTL_ALL: u32 = 0x9016;
@@ -573,46 +4288,46 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_C_NTL_PALL: u32 = 0xffff;
 // const MATCH_C_NTL_S1: u32 = 0x9012;
 // const MASK_C_NTL_S1: u32 = 0xffff;
-// const MATCH_C_OR: u32 = 0x8c41;
-// const MASK_C_OR: u32 = 0xfc63;
+const MATCH_C_OR: u32 = 0x8c41;
+const MASK_C_OR: u32 = 0xfc63;
 // const MATCH_C_SB: u32 = 0x8800;
 // const MASK_C_SB: u32 = 0xfc03;
-// const MATCH_C_SD: u32 = 0xe000;
-// const MASK_C_SD: u32 = 0xe003;
-// const MATCH_C_SDSP: u32 = 0xe002;
-// const MASK_C_SDSP: u32 = 0xe003;
+const MATCH_C_SD: u32 = 0xe000;
+const MASK_C_SD: u32 = 0xe003;
+const MATCH_C_SDSP: u32 = 0xe002;
+const MASK_C_SDSP: u32 = 0xe003;
 // const MATCH_C_SEXT_B: u32 = 0x9c65;
 // const MASK_C_SEXT_B: u32 = 0xfc7f;
 // const MATCH_C_SEXT_H: u32 = 0x9c6d;
 // const MASK_C_SEXT_H: u32 = 0xfc7f;
 // const MATCH_C_SH: u32 = 0x8c00;
 // const MASK_C_SH: u32 = 0xfc43;
-// const MATCH_C_SLLI: u32 = 0x2;
-// const MASK_C_SLLI: u32 = 0xe003;
+const MATCH_C_SLLI: u32 = 0x2;
+const MASK_C_SLLI: u32 = 0xe003;
 // const MATCH_C_SLLI_RV32: u32 = 0x2;
 // const MASK_C_SLLI_RV32: u32 = 0xf003;
 // const MATCH_C_SQ: u32 = 0xa000;
 // const MASK_C_SQ: u32 = 0xe003;
 // const MATCH_C_SQSP: u32 = 0xa002;
 // const MASK_C_SQSP: u32 = 0xe003;
-// const MATCH_C_SRAI: u32 = 0x8401;
-// const MASK_C_SRAI: u32 = 0xec03;
+const MATCH_C_SRAI: u32 = 0x8401;
+const MASK_C_SRAI: u32 = 0xec03;
 // const MATCH_C_SRAI_RV32: u32 = 0x8401;
 // const MASK_C_SRAI_RV32: u32 = 0xfc03;
-// const MATCH_C_SRLI: u32 = 0x8001;
-// const MASK_C_SRLI: u32 = 0xec03;
+const MATCH_C_SRLI: u32 = 0x8001;
+const MASK_C_SRLI: u32 = 0xec03;
 // const MATCH_C_SRLI_RV32: u32 = 0x8001;
 // const MASK_C_SRLI_RV32: u32 = 0xfc03;
-// const MATCH_C_SUB: u32 = 0x8c01;
-// const MASK_C_SUB: u32 = 0xfc63;
-// const MATCH_C_SUBW: u32 = 0x9c01;
-// const MASK_C_SUBW: u32 = 0xfc63;
-// const MATCH_C_SW: u32 = 0xc000;
-// const MASK_C_SW: u32 = 0xe003;
-// const MATCH_C_SWSP: u32 = 0xc002;
-// const MASK_C_SWSP: u32 = 0xe003;
-// const MATCH_C_XOR: u32 = 0x8c21;
-// const MASK_C_XOR: u32 = 0xfc63;
+const MATCH_C_SUB: u32 = 0x8c01;
+const MASK_C_SUB: u32 = 0xfc63;
+const MATCH_C_SUBW: u32 = 0x9c01;
+const MASK_C_SUBW: u32 = 0xfc63;
+const MATCH_C_SW: u32 = 0xc000;
+const MASK_C_SW: u32 = 0xe003;
+const MATCH_C_SWSP: u32 = 0xc002;
+const MASK_C_SWSP: u32 = 0xe003;
+const MATCH_C_XOR: u32 = 0x8c21;
+const MASK_C_XOR: u32 = 0xfc63;
 // const MATCH_C_ZEXT_B: u32 = 0x9c61;
 // const MASK_C_ZEXT_B: u32 = 0xfc7f;
 // const MATCH_C_ZEXT_H: u32 = 0x9c69;
@@ -701,18 +4416,18 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_CRSA16: u32 = 0xfe00707f;
 // const MATCH_CRSA32: u32 = 0x46002077;
 // const MASK_CRSA32: u32 = 0xfe00707f;
-// const MATCH_CSRRC: u32 = 0x3073;
-// const MASK_CSRRC: u32 = 0x707f;
-// const MATCH_CSRRCI: u32 = 0x7073;
-// const MASK_CSRRCI: u32 = 0x707f;
-// const MATCH_CSRRS: u32 = 0x2073;
-// const MASK_CSRRS: u32 = 0x707f;
-// const MATCH_CSRRSI: u32 = 0x6073;
-// const MASK_CSRRSI: u32 = 0x707f;
-// const MATCH_CSRRW: u32 = 0x1073;
-// const MASK_CSRRW: u32 = 0x707f;
-// const MATCH_CSRRWI: u32 = 0x5073;
-// const MASK_CSRRWI: u32 = 0x707f;
+const MATCH_CSRRC: u32 = 0x3073;
+const MASK_CSRRC: u32 = 0x707f;
+const MATCH_CSRRCI: u32 = 0x7073;
+const MASK_CSRRCI: u32 = 0x707f;
+const MATCH_CSRRS: u32 = 0x2073;
+const MASK_CSRRS: u32 = 0x707f;
+const MATCH_CSRRSI: u32 = 0x6073;
+const MASK_CSRRSI: u32 = 0x707f;
+const MATCH_CSRRW: u32 = 0x1073;
+const MASK_CSRRW: u32 = 0x707f;
+const MATCH_CSRRWI: u32 = 0x5073;
+const MASK_CSRRWI: u32 = 0x707f;
 // const MATCH_CTZ: u32 = 0x60101013;
 // const MASK_CTZ: u32 = 0xfff0707f;
 // const MATCH_CTZW: u32 = 0x6010101b;
@@ -721,52 +4436,52 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_CZERO_EQZ: u32 = 0xfe00707f;
 // const MATCH_CZERO_NEZ: u32 = 0xe007033;
 // const MASK_CZERO_NEZ: u32 = 0xfe00707f;
-// const MATCH_DIV: u32 = 0x2004033;
-// const MASK_DIV: u32 = 0xfe00707f;
-// const MATCH_DIVU: u32 = 0x2005033;
-// const MASK_DIVU: u32 = 0xfe00707f;
-// const MATCH_DIVUW: u32 = 0x200503b;
-// const MASK_DIVUW: u32 = 0xfe00707f;
-// const MATCH_DIVW: u32 = 0x200403b;
-// const MASK_DIVW: u32 = 0xfe00707f;
+const MATCH_DIV: u32 = 0x2004033;
+const MASK_DIV: u32 = 0xfe00707f;
+const MATCH_DIVU: u32 = 0x2005033;
+const MASK_DIVU: u32 = 0xfe00707f;
+const MATCH_DIVUW: u32 = 0x200503b;
+const MASK_DIVUW: u32 = 0xfe00707f;
+const MATCH_DIVW: u32 = 0x200403b;
+const MASK_DIVW: u32 = 0xfe00707f;
 // const MATCH_DRET: u32 = 0x7b200073;
 // const MASK_DRET: u32 = 0xffffffff;
-// const MATCH_EBREAK: u32 = 0x100073;
-// const MASK_EBREAK: u32 = 0xffffffff;
-// const MATCH_ECALL: u32 = 0x73;
-// const MASK_ECALL: u32 = 0xffffffff;
-// const MATCH_FADD_D: u32 = 0x2000053;
-// const MASK_FADD_D: u32 = 0xfe00007f;
+const MATCH_EBREAK: u32 = 0x100073;
+const MASK_EBREAK: u32 = 0xffffffff;
+const MATCH_ECALL: u32 = 0x73;
+const MASK_ECALL: u32 = 0xffffffff;
+const MATCH_FADD_D: u32 = 0x2000053;
+const MASK_FADD_D: u32 = 0xfe00007f;
 // const MATCH_FADD_H: u32 = 0x4000053;
 // const MASK_FADD_H: u32 = 0xfe00007f;
 // const MATCH_FADD_Q: u32 = 0x6000053;
 // const MASK_FADD_Q: u32 = 0xfe00007f;
-// const MATCH_FADD_S: u32 = 0x53;
-// const MASK_FADD_S: u32 = 0xfe00007f;
-// const MATCH_FCLASS_D: u32 = 0xe2001053;
-// const MASK_FCLASS_D: u32 = 0xfff0707f;
+const MATCH_FADD_S: u32 = 0x53;
+const MASK_FADD_S: u32 = 0xfe00007f;
+const MATCH_FCLASS_D: u32 = 0xe2001053;
+const MASK_FCLASS_D: u32 = 0xfff0707f;
 // const MATCH_FCLASS_H: u32 = 0xe4001053;
 // const MASK_FCLASS_H: u32 = 0xfff0707f;
 // const MATCH_FCLASS_Q: u32 = 0xe6001053;
 // const MASK_FCLASS_Q: u32 = 0xfff0707f;
-// const MATCH_FCLASS_S: u32 = 0xe0001053;
-// const MASK_FCLASS_S: u32 = 0xfff0707f;
+const MATCH_FCLASS_S: u32 = 0xe0001053;
+const MASK_FCLASS_S: u32 = 0xfff0707f;
 // const MATCH_FCVT_BF16_S: u32 = 0x44800053;
 // const MASK_FCVT_BF16_S: u32 = 0xfff0007f;
 // const MATCH_FCVT_D_H: u32 = 0x42200053;
 // const MASK_FCVT_D_H: u32 = 0xfff0007f;
-// const MATCH_FCVT_D_L: u32 = 0xd2200053;
-// const MASK_FCVT_D_L: u32 = 0xfff0007f;
-// const MATCH_FCVT_D_LU: u32 = 0xd2300053;
-// const MASK_FCVT_D_LU: u32 = 0xfff0007f;
+const MATCH_FCVT_D_L: u32 = 0xd2200053;
+const MASK_FCVT_D_L: u32 = 0xfff0007f;
+const MATCH_FCVT_D_LU: u32 = 0xd2300053;
+const MASK_FCVT_D_LU: u32 = 0xfff0007f;
 // const MATCH_FCVT_D_Q: u32 = 0x42300053;
 // const MASK_FCVT_D_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_D_S: u32 = 0x42000053;
-// const MASK_FCVT_D_S: u32 = 0xfff0007f;
-// const MATCH_FCVT_D_W: u32 = 0xd2000053;
-// const MASK_FCVT_D_W: u32 = 0xfff0007f;
-// const MATCH_FCVT_D_WU: u32 = 0xd2100053;
-// const MASK_FCVT_D_WU: u32 = 0xfff0007f;
+const MATCH_FCVT_D_S: u32 = 0x42000053;
+const MASK_FCVT_D_S: u32 = 0xfff0007f;
+const MATCH_FCVT_D_W: u32 = 0xd2000053;
+const MASK_FCVT_D_W: u32 = 0xfff0007f;
+const MATCH_FCVT_D_WU: u32 = 0xd2100053;
+const MASK_FCVT_D_WU: u32 = 0xfff0007f;
 // const MATCH_FCVT_H_D: u32 = 0x44100053;
 // const MASK_FCVT_H_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_H_L: u32 = 0xd4200053;
@@ -781,22 +4496,22 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FCVT_H_W: u32 = 0xfff0007f;
 // const MATCH_FCVT_H_WU: u32 = 0xd4100053;
 // const MASK_FCVT_H_WU: u32 = 0xfff0007f;
-// const MATCH_FCVT_L_D: u32 = 0xc2200053;
-// const MASK_FCVT_L_D: u32 = 0xfff0007f;
+const MATCH_FCVT_L_D: u32 = 0xc2200053;
+const MASK_FCVT_L_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_L_H: u32 = 0xc4200053;
 // const MASK_FCVT_L_H: u32 = 0xfff0007f;
 // const MATCH_FCVT_L_Q: u32 = 0xc6200053;
 // const MASK_FCVT_L_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_L_S: u32 = 0xc0200053;
-// const MASK_FCVT_L_S: u32 = 0xfff0007f;
-// const MATCH_FCVT_LU_D: u32 = 0xc2300053;
-// const MASK_FCVT_LU_D: u32 = 0xfff0007f;
+const MATCH_FCVT_L_S: u32 = 0xc0200053;
+const MASK_FCVT_L_S: u32 = 0xfff0007f;
+const MATCH_FCVT_LU_D: u32 = 0xc2300053;
+const MASK_FCVT_LU_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_LU_H: u32 = 0xc4300053;
 // const MASK_FCVT_LU_H: u32 = 0xfff0007f;
 // const MATCH_FCVT_LU_Q: u32 = 0xc6300053;
 // const MASK_FCVT_LU_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_LU_S: u32 = 0xc0300053;
-// const MASK_FCVT_LU_S: u32 = 0xfff0007f;
+const MATCH_FCVT_LU_S: u32 = 0xc0300053;
+const MASK_FCVT_LU_S: u32 = 0xfff0007f;
 // const MATCH_FCVT_Q_D: u32 = 0x46100053;
 // const MASK_FCVT_Q_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_Q_H: u32 = 0x46200053;
@@ -813,70 +4528,70 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FCVT_Q_WU: u32 = 0xfff0007f;
 // const MATCH_FCVT_S_BF16: u32 = 0x40600053;
 // const MASK_FCVT_S_BF16: u32 = 0xfff0007f;
-// const MATCH_FCVT_S_D: u32 = 0x40100053;
-// const MASK_FCVT_S_D: u32 = 0xfff0007f;
+const MATCH_FCVT_S_D: u32 = 0x40100053;
+const MASK_FCVT_S_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_S_H: u32 = 0x40200053;
 // const MASK_FCVT_S_H: u32 = 0xfff0007f;
-// const MATCH_FCVT_S_L: u32 = 0xd0200053;
-// const MASK_FCVT_S_L: u32 = 0xfff0007f;
-// const MATCH_FCVT_S_LU: u32 = 0xd0300053;
-// const MASK_FCVT_S_LU: u32 = 0xfff0007f;
+const MATCH_FCVT_S_L: u32 = 0xd0200053;
+const MASK_FCVT_S_L: u32 = 0xfff0007f;
+const MATCH_FCVT_S_LU: u32 = 0xd0300053;
+const MASK_FCVT_S_LU: u32 = 0xfff0007f;
 // const MATCH_FCVT_S_Q: u32 = 0x40300053;
 // const MASK_FCVT_S_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_S_W: u32 = 0xd0000053;
-// const MASK_FCVT_S_W: u32 = 0xfff0007f;
-// const MATCH_FCVT_S_WU: u32 = 0xd0100053;
-// const MASK_FCVT_S_WU: u32 = 0xfff0007f;
-// const MATCH_FCVT_W_D: u32 = 0xc2000053;
-// const MASK_FCVT_W_D: u32 = 0xfff0007f;
+const MATCH_FCVT_S_W: u32 = 0xd0000053;
+const MASK_FCVT_S_W: u32 = 0xfff0007f;
+const MATCH_FCVT_S_WU: u32 = 0xd0100053;
+const MASK_FCVT_S_WU: u32 = 0xfff0007f;
+const MATCH_FCVT_W_D: u32 = 0xc2000053;
+const MASK_FCVT_W_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_W_H: u32 = 0xc4000053;
 // const MASK_FCVT_W_H: u32 = 0xfff0007f;
 // const MATCH_FCVT_W_Q: u32 = 0xc6000053;
 // const MASK_FCVT_W_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_W_S: u32 = 0xc0000053;
-// const MASK_FCVT_W_S: u32 = 0xfff0007f;
-// const MATCH_FCVT_WU_D: u32 = 0xc2100053;
-// const MASK_FCVT_WU_D: u32 = 0xfff0007f;
+const MATCH_FCVT_W_S: u32 = 0xc0000053;
+const MASK_FCVT_W_S: u32 = 0xfff0007f;
+const MATCH_FCVT_WU_D: u32 = 0xc2100053;
+const MASK_FCVT_WU_D: u32 = 0xfff0007f;
 // const MATCH_FCVT_WU_H: u32 = 0xc4100053;
 // const MASK_FCVT_WU_H: u32 = 0xfff0007f;
 // const MATCH_FCVT_WU_Q: u32 = 0xc6100053;
 // const MASK_FCVT_WU_Q: u32 = 0xfff0007f;
-// const MATCH_FCVT_WU_S: u32 = 0xc0100053;
-// const MASK_FCVT_WU_S: u32 = 0xfff0007f;
+const MATCH_FCVT_WU_S: u32 = 0xc0100053;
+const MASK_FCVT_WU_S: u32 = 0xfff0007f;
 // const MATCH_FCVTMOD_W_D: u32 = 0xc2801053;
 // const MASK_FCVTMOD_W_D: u32 = 0xfff0707f;
-// const MATCH_FDIV_D: u32 = 0x1a000053;
-// const MASK_FDIV_D: u32 = 0xfe00007f;
+const MATCH_FDIV_D: u32 = 0x1a000053;
+const MASK_FDIV_D: u32 = 0xfe00007f;
 // const MATCH_FDIV_H: u32 = 0x1c000053;
 // const MASK_FDIV_H: u32 = 0xfe00007f;
 // const MATCH_FDIV_Q: u32 = 0x1e000053;
 // const MASK_FDIV_Q: u32 = 0xfe00007f;
-// const MATCH_FDIV_S: u32 = 0x18000053;
-// const MASK_FDIV_S: u32 = 0xfe00007f;
-// const MATCH_FENCE: u32 = 0xf;
-// const MASK_FENCE: u32 = 0x707f;
-// const MATCH_FENCE_I: u32 = 0x100f;
-// const MASK_FENCE_I: u32 = 0x707f;
-// const MATCH_FENCE_TSO: u32 = 0x8330000f;
-// const MASK_FENCE_TSO: u32 = 0xfff0707f;
-// const MATCH_FEQ_D: u32 = 0xa2002053;
-// const MASK_FEQ_D: u32 = 0xfe00707f;
+const MATCH_FDIV_S: u32 = 0x18000053;
+const MASK_FDIV_S: u32 = 0xfe00007f;
+const MATCH_FENCE: u32 = 0xf;
+const MASK_FENCE: u32 = 0x707f;
+const MATCH_FENCE_I: u32 = 0x100f;
+const MASK_FENCE_I: u32 = 0x707f;
+const MATCH_FENCE_TSO: u32 = 0x8330000f;
+const MASK_FENCE_TSO: u32 = 0xfff0707f;
+const MATCH_FEQ_D: u32 = 0xa2002053;
+const MASK_FEQ_D: u32 = 0xfe00707f;
 // const MATCH_FEQ_H: u32 = 0xa4002053;
 // const MASK_FEQ_H: u32 = 0xfe00707f;
 // const MATCH_FEQ_Q: u32 = 0xa6002053;
 // const MASK_FEQ_Q: u32 = 0xfe00707f;
-// const MATCH_FEQ_S: u32 = 0xa0002053;
-// const MASK_FEQ_S: u32 = 0xfe00707f;
-// const MATCH_FLD: u32 = 0x3007;
-// const MASK_FLD: u32 = 0x707f;
-// const MATCH_FLE_D: u32 = 0xa2000053;
-// const MASK_FLE_D: u32 = 0xfe00707f;
+const MATCH_FEQ_S: u32 = 0xa0002053;
+const MASK_FEQ_S: u32 = 0xfe00707f;
+const MATCH_FLD: u32 = 0x3007;
+const MASK_FLD: u32 = 0x707f;
+const MATCH_FLE_D: u32 = 0xa2000053;
+const MASK_FLE_D: u32 = 0xfe00707f;
 // const MATCH_FLE_H: u32 = 0xa4000053;
 // const MASK_FLE_H: u32 = 0xfe00707f;
 // const MATCH_FLE_Q: u32 = 0xa6000053;
 // const MASK_FLE_Q: u32 = 0xfe00707f;
-// const MATCH_FLE_S: u32 = 0xa0000053;
-// const MASK_FLE_S: u32 = 0xfe00707f;
+const MATCH_FLE_S: u32 = 0xa0000053;
+const MASK_FLE_S: u32 = 0xfe00707f;
 // const MATCH_FLEQ_D: u32 = 0xa2004053;
 // const MASK_FLEQ_D: u32 = 0xfe00707f;
 // const MATCH_FLEQ_H: u32 = 0xa4004053;
@@ -897,14 +4612,14 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FLI_S: u32 = 0xfff0707f;
 // const MATCH_FLQ: u32 = 0x4007;
 // const MASK_FLQ: u32 = 0x707f;
-// const MATCH_FLT_D: u32 = 0xa2001053;
-// const MASK_FLT_D: u32 = 0xfe00707f;
+const MATCH_FLT_D: u32 = 0xa2001053;
+const MASK_FLT_D: u32 = 0xfe00707f;
 // const MATCH_FLT_H: u32 = 0xa4001053;
 // const MASK_FLT_H: u32 = 0xfe00707f;
 // const MATCH_FLT_Q: u32 = 0xa6001053;
 // const MASK_FLT_Q: u32 = 0xfe00707f;
-// const MATCH_FLT_S: u32 = 0xa0001053;
-// const MASK_FLT_S: u32 = 0xfe00707f;
+const MATCH_FLT_S: u32 = 0xa0001053;
+const MASK_FLT_S: u32 = 0xfe00707f;
 // const MATCH_FLTQ_D: u32 = 0xa2005053;
 // const MASK_FLTQ_D: u32 = 0xfe00707f;
 // const MATCH_FLTQ_H: u32 = 0xa4005053;
@@ -913,24 +4628,24 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FLTQ_Q: u32 = 0xfe00707f;
 // const MATCH_FLTQ_S: u32 = 0xa0005053;
 // const MASK_FLTQ_S: u32 = 0xfe00707f;
-// const MATCH_FLW: u32 = 0x2007;
-// const MASK_FLW: u32 = 0x707f;
-// const MATCH_FMADD_D: u32 = 0x2000043;
-// const MASK_FMADD_D: u32 = 0x600007f;
+const MATCH_FLW: u32 = 0x2007;
+const MASK_FLW: u32 = 0x707f;
+const MATCH_FMADD_D: u32 = 0x2000043;
+const MASK_FMADD_D: u32 = 0x600007f;
 // const MATCH_FMADD_H: u32 = 0x4000043;
 // const MASK_FMADD_H: u32 = 0x600007f;
 // const MATCH_FMADD_Q: u32 = 0x6000043;
 // const MASK_FMADD_Q: u32 = 0x600007f;
-// const MATCH_FMADD_S: u32 = 0x43;
-// const MASK_FMADD_S: u32 = 0x600007f;
-// const MATCH_FMAX_D: u32 = 0x2a001053;
-// const MASK_FMAX_D: u32 = 0xfe00707f;
+const MATCH_FMADD_S: u32 = 0x43;
+const MASK_FMADD_S: u32 = 0x600007f;
+const MATCH_FMAX_D: u32 = 0x2a001053;
+const MASK_FMAX_D: u32 = 0xfe00707f;
 // const MATCH_FMAX_H: u32 = 0x2c001053;
 // const MASK_FMAX_H: u32 = 0xfe00707f;
 // const MATCH_FMAX_Q: u32 = 0x2e001053;
 // const MASK_FMAX_Q: u32 = 0xfe00707f;
-// const MATCH_FMAX_S: u32 = 0x28001053;
-// const MASK_FMAX_S: u32 = 0xfe00707f;
+const MATCH_FMAX_S: u32 = 0x28001053;
+const MASK_FMAX_S: u32 = 0xfe00707f;
 // const MATCH_FMAXM_D: u32 = 0x2a003053;
 // const MASK_FMAXM_D: u32 = 0xfe00707f;
 // const MATCH_FMAXM_H: u32 = 0x2c003053;
@@ -939,14 +4654,14 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FMAXM_Q: u32 = 0xfe00707f;
 // const MATCH_FMAXM_S: u32 = 0x28003053;
 // const MASK_FMAXM_S: u32 = 0xfe00707f;
-// const MATCH_FMIN_D: u32 = 0x2a000053;
-// const MASK_FMIN_D: u32 = 0xfe00707f;
+const MATCH_FMIN_D: u32 = 0x2a000053;
+const MASK_FMIN_D: u32 = 0xfe00707f;
 // const MATCH_FMIN_H: u32 = 0x2c000053;
 // const MASK_FMIN_H: u32 = 0xfe00707f;
 // const MATCH_FMIN_Q: u32 = 0x2e000053;
 // const MASK_FMIN_Q: u32 = 0xfe00707f;
-// const MATCH_FMIN_S: u32 = 0x28000053;
-// const MASK_FMIN_S: u32 = 0xfe00707f;
+const MATCH_FMIN_S: u32 = 0x28000053;
+const MASK_FMIN_S: u32 = 0xfe00707f;
 // const MATCH_FMINM_D: u32 = 0x2a002053;
 // const MASK_FMINM_D: u32 = 0xfe00707f;
 // const MATCH_FMINM_H: u32 = 0x2c002053;
@@ -955,38 +4670,38 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FMINM_Q: u32 = 0xfe00707f;
 // const MATCH_FMINM_S: u32 = 0x28002053;
 // const MASK_FMINM_S: u32 = 0xfe00707f;
-// const MATCH_FMSUB_D: u32 = 0x2000047;
-// const MASK_FMSUB_D: u32 = 0x600007f;
+const MATCH_FMSUB_D: u32 = 0x2000047;
+const MASK_FMSUB_D: u32 = 0x600007f;
 // const MATCH_FMSUB_H: u32 = 0x4000047;
 // const MASK_FMSUB_H: u32 = 0x600007f;
 // const MATCH_FMSUB_Q: u32 = 0x6000047;
 // const MASK_FMSUB_Q: u32 = 0x600007f;
-// const MATCH_FMSUB_S: u32 = 0x47;
-// const MASK_FMSUB_S: u32 = 0x600007f;
-// const MATCH_FMUL_D: u32 = 0x12000053;
-// const MASK_FMUL_D: u32 = 0xfe00007f;
+const MATCH_FMSUB_S: u32 = 0x47;
+const MASK_FMSUB_S: u32 = 0x600007f;
+const MATCH_FMUL_D: u32 = 0x12000053;
+const MASK_FMUL_D: u32 = 0xfe00007f;
 // const MATCH_FMUL_H: u32 = 0x14000053;
 // const MASK_FMUL_H: u32 = 0xfe00007f;
 // const MATCH_FMUL_Q: u32 = 0x16000053;
 // const MASK_FMUL_Q: u32 = 0xfe00007f;
-// const MATCH_FMUL_S: u32 = 0x10000053;
-// const MASK_FMUL_S: u32 = 0xfe00007f;
-// const MATCH_FMV_D_X: u32 = 0xf2000053;
-// const MASK_FMV_D_X: u32 = 0xfff0707f;
+const MATCH_FMUL_S: u32 = 0x10000053;
+const MASK_FMUL_S: u32 = 0xfe00007f;
+const MATCH_FMV_D_X: u32 = 0xf2000053;
+const MASK_FMV_D_X: u32 = 0xfff0707f;
 // const MATCH_FMV_H_X: u32 = 0xf4000053;
 // const MASK_FMV_H_X: u32 = 0xfff0707f;
 // const MATCH_FMV_S_X: u32 = 0xf0000053;
 // const MASK_FMV_S_X: u32 = 0xfff0707f;
-// const MATCH_FMV_W_X: u32 = 0xf0000053;
-// const MASK_FMV_W_X: u32 = 0xfff0707f;
-// const MATCH_FMV_X_D: u32 = 0xe2000053;
-// const MASK_FMV_X_D: u32 = 0xfff0707f;
+const MATCH_FMV_W_X: u32 = 0xf0000053;
+const MASK_FMV_W_X: u32 = 0xfff0707f;
+const MATCH_FMV_X_D: u32 = 0xe2000053;
+const MASK_FMV_X_D: u32 = 0xfff0707f;
 // const MATCH_FMV_X_H: u32 = 0xe4000053;
 // const MASK_FMV_X_H: u32 = 0xfff0707f;
 // const MATCH_FMV_X_S: u32 = 0xe0000053;
 // const MASK_FMV_X_S: u32 = 0xfff0707f;
-// const MATCH_FMV_X_W: u32 = 0xe0000053;
-// const MASK_FMV_X_W: u32 = 0xfff0707f;
+const MATCH_FMV_X_W: u32 = 0xe0000053;
+const MASK_FMV_X_W: u32 = 0xfff0707f;
 // const MATCH_FMVH_X_D: u32 = 0xe2100053;
 // const MASK_FMVH_X_D: u32 = 0xfff0707f;
 // const MATCH_FMVH_X_Q: u32 = 0xe6100053;
@@ -995,22 +4710,22 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FMVP_D_X: u32 = 0xfe00707f;
 // const MATCH_FMVP_Q_X: u32 = 0xb6000053;
 // const MASK_FMVP_Q_X: u32 = 0xfe00707f;
-// const MATCH_FNMADD_D: u32 = 0x200004f;
-// const MASK_FNMADD_D: u32 = 0x600007f;
+const MATCH_FNMADD_D: u32 = 0x200004f;
+const MASK_FNMADD_D: u32 = 0x600007f;
 // const MATCH_FNMADD_H: u32 = 0x400004f;
 // const MASK_FNMADD_H: u32 = 0x600007f;
 // const MATCH_FNMADD_Q: u32 = 0x600004f;
 // const MASK_FNMADD_Q: u32 = 0x600007f;
-// const MATCH_FNMADD_S: u32 = 0x4f;
-// const MASK_FNMADD_S: u32 = 0x600007f;
-// const MATCH_FNMSUB_D: u32 = 0x200004b;
-// const MASK_FNMSUB_D: u32 = 0x600007f;
+const MATCH_FNMADD_S: u32 = 0x4f;
+const MASK_FNMADD_S: u32 = 0x600007f;
+const MATCH_FNMSUB_D: u32 = 0x200004b;
+const MASK_FNMSUB_D: u32 = 0x600007f;
 // const MATCH_FNMSUB_H: u32 = 0x400004b;
 // const MASK_FNMSUB_H: u32 = 0x600007f;
 // const MATCH_FNMSUB_Q: u32 = 0x600004b;
 // const MASK_FNMSUB_Q: u32 = 0x600007f;
-// const MATCH_FNMSUB_S: u32 = 0x4b;
-// const MASK_FNMSUB_S: u32 = 0x600007f;
+const MATCH_FNMSUB_S: u32 = 0x4b;
+const MASK_FNMSUB_S: u32 = 0x600007f;
 // const MATCH_FRCSR: u32 = 0x302073;
 // const MASK_FRCSR: u32 = 0xfffff07f;
 // const MATCH_FRFLAGS: u32 = 0x102073;
@@ -1035,36 +4750,36 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FRRM: u32 = 0xfffff07f;
 // const MATCH_FSCSR: u32 = 0x301073;
 // const MASK_FSCSR: u32 = 0xfff0707f;
-// const MATCH_FSD: u32 = 0x3027;
-// const MASK_FSD: u32 = 0x707f;
+const MATCH_FSD: u32 = 0x3027;
+const MASK_FSD: u32 = 0x707f;
 // const MATCH_FSFLAGS: u32 = 0x101073;
 // const MASK_FSFLAGS: u32 = 0xfff0707f;
 // const MATCH_FSFLAGSI: u32 = 0x105073;
 // const MASK_FSFLAGSI: u32 = 0xfff0707f;
-// const MATCH_FSGNJ_D: u32 = 0x22000053;
-// const MASK_FSGNJ_D: u32 = 0xfe00707f;
+const MATCH_FSGNJ_D: u32 = 0x22000053;
+const MASK_FSGNJ_D: u32 = 0xfe00707f;
 // const MATCH_FSGNJ_H: u32 = 0x24000053;
 // const MASK_FSGNJ_H: u32 = 0xfe00707f;
 // const MATCH_FSGNJ_Q: u32 = 0x26000053;
 // const MASK_FSGNJ_Q: u32 = 0xfe00707f;
-// const MATCH_FSGNJ_S: u32 = 0x20000053;
-// const MASK_FSGNJ_S: u32 = 0xfe00707f;
-// const MATCH_FSGNJN_D: u32 = 0x22001053;
-// const MASK_FSGNJN_D: u32 = 0xfe00707f;
+const MATCH_FSGNJ_S: u32 = 0x20000053;
+const MASK_FSGNJ_S: u32 = 0xfe00707f;
+const MATCH_FSGNJN_D: u32 = 0x22001053;
+const MASK_FSGNJN_D: u32 = 0xfe00707f;
 // const MATCH_FSGNJN_H: u32 = 0x24001053;
 // const MASK_FSGNJN_H: u32 = 0xfe00707f;
 // const MATCH_FSGNJN_Q: u32 = 0x26001053;
 // const MASK_FSGNJN_Q: u32 = 0xfe00707f;
-// const MATCH_FSGNJN_S: u32 = 0x20001053;
-// const MASK_FSGNJN_S: u32 = 0xfe00707f;
-// const MATCH_FSGNJX_D: u32 = 0x22002053;
-// const MASK_FSGNJX_D: u32 = 0xfe00707f;
+const MATCH_FSGNJN_S: u32 = 0x20001053;
+const MASK_FSGNJN_S: u32 = 0xfe00707f;
+const MATCH_FSGNJX_D: u32 = 0x22002053;
+const MASK_FSGNJX_D: u32 = 0xfe00707f;
 // const MATCH_FSGNJX_H: u32 = 0x24002053;
 // const MASK_FSGNJX_H: u32 = 0xfe00707f;
 // const MATCH_FSGNJX_Q: u32 = 0x26002053;
 // const MASK_FSGNJX_Q: u32 = 0xfe00707f;
-// const MATCH_FSGNJX_S: u32 = 0x20002053;
-// const MASK_FSGNJX_S: u32 = 0xfe00707f;
+const MATCH_FSGNJX_S: u32 = 0x20002053;
+const MASK_FSGNJX_S: u32 = 0xfe00707f;
 // const MATCH_FSH: u32 = 0x1027;
 // const MASK_FSH: u32 = 0x707f;
 // const MATCH_FSL: u32 = 0x4001033;
@@ -1073,14 +4788,14 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FSLW: u32 = 0x600707f;
 // const MATCH_FSQ: u32 = 0x4027;
 // const MASK_FSQ: u32 = 0x707f;
-// const MATCH_FSQRT_D: u32 = 0x5a000053;
-// const MASK_FSQRT_D: u32 = 0xfff0007f;
+const MATCH_FSQRT_D: u32 = 0x5a000053;
+const MASK_FSQRT_D: u32 = 0xfff0007f;
 // const MATCH_FSQRT_H: u32 = 0x5c000053;
 // const MASK_FSQRT_H: u32 = 0xfff0007f;
 // const MATCH_FSQRT_Q: u32 = 0x5e000053;
 // const MASK_FSQRT_Q: u32 = 0xfff0007f;
-// const MATCH_FSQRT_S: u32 = 0x58000053;
-// const MASK_FSQRT_S: u32 = 0xfff0007f;
+const MATCH_FSQRT_S: u32 = 0x58000053;
+const MASK_FSQRT_S: u32 = 0xfff0007f;
 // const MATCH_FSR: u32 = 0x4005033;
 // const MASK_FSR: u32 = 0x600707f;
 // const MATCH_FSRI: u32 = 0x4005013;
@@ -1093,16 +4808,16 @@ const MATCH_BNE: u32 = 0x1063;
 // const MASK_FSRMI: u32 = 0xfff0707f;
 // const MATCH_FSRW: u32 = 0x400503b;
 // const MASK_FSRW: u32 = 0x600707f;
-// const MATCH_FSUB_D: u32 = 0xa000053;
-// const MASK_FSUB_D: u32 = 0xfe00007f;
+const MATCH_FSUB_D: u32 = 0xa000053;
+const MASK_FSUB_D: u32 = 0xfe00007f;
 // const MATCH_FSUB_H: u32 = 0xc000053;
 // const MASK_FSUB_H: u32 = 0xfe00007f;
 // const MATCH_FSUB_Q: u32 = 0xe000053;
 // const MASK_FSUB_Q: u32 = 0xfe00007f;
-// const MATCH_FSUB_S: u32 = 0x8000053;
-// const MASK_FSUB_S: u32 = 0xfe00007f;
-// const MATCH_FSW: u32 = 0x2027;
-// const MASK_FSW: u32 = 0x707f;
+const MATCH_FSUB_S: u32 = 0x8000053;
+const MASK_FSUB_S: u32 = 0xfe00007f;
+const MATCH_FSW: u32 = 0x2027;
+const MASK_FSW: u32 = 0x707f;
 // const MATCH_GORC: u32 = 0x28005033;
 // const MASK_GORC: u32 = 0xfe00707f;
 // const MATCH_GORCI: u32 = 0x28005013;
@@ -1156,9 +4871,9 @@ const MATCH_BNE: u32 = 0x1063;
 // const MATCH_INSB: u32 = 0xac000077;
 // const MASK_INSB: u32 = 0xff80707f;
 const MATCH_JAL: u32 = 0x6f;
-// const MASK_JAL: u32 = 0x7f;
+const MASK_JAL: u32 = 0x7f;
 const MATCH_JALR: u32 = 0x67;
-// const MASK_JALR: u32 = 0x707f;
+const MASK_JALR: u32 = 0x707f;
 // const MATCH_KABS16: u32 = 0xad100077;
 // const MASK_KABS16: u32 = 0xfff0707f;
 // const MATCH_KABS32: u32 = 0xad200077;
@@ -1370,37 +5085,37 @@ const MATCH_JALR: u32 = 0x67;
 // const MATCH_KWMMUL_U: u32 = 0x72001077;
 // const MASK_KWMMUL_U: u32 = 0xfe00707f;
 const MATCH_LB: u32 = 0x3;
-// const MASK_LB: u32 = 0x707f;
+const MASK_LB: u32 = 0x707f;
 // const MATCH_LB_AQ: u32 = 0x3400002f;
 // const MASK_LB_AQ: u32 = 0xfdf0707f;
 const MATCH_LBU: u32 = 0x4003;
-// const MASK_LBU: u32 = 0x707f;
-// const MATCH_LD: u32 = 0x3003;
-// const MASK_LD: u32 = 0x707f;
+const MASK_LBU: u32 = 0x707f;
+const MATCH_LD: u32 = 0x3003;
+const MASK_LD: u32 = 0x707f;
 // const MATCH_LD_AQ: u32 = 0x3400302f;
 // const MASK_LD_AQ: u32 = 0xfdf0707f;
 // const MATCH_LDU: u32 = 0x7003;
 // const MASK_LDU: u32 = 0x707f;
 const MATCH_LH: u32 = 0x1003;
-// const MASK_LH: u32 = 0x707f;
+const MASK_LH: u32 = 0x707f;
 // const MATCH_LH_AQ: u32 = 0x3400102f;
 // const MASK_LH_AQ: u32 = 0xfdf0707f;
 const MATCH_LHU: u32 = 0x5003;
-// const MASK_LHU: u32 = 0x707f;
+const MASK_LHU: u32 = 0x707f;
 // const MATCH_LQ: u32 = 0x300f;
 // const MASK_LQ: u32 = 0x707f;
-// const MATCH_LR_D: u32 = 0x1000302f;
-// const MASK_LR_D: u32 = 0xf9f0707f;
-// const MATCH_LR_W: u32 = 0x1000202f;
-// const MASK_LR_W: u32 = 0xf9f0707f;
+const MATCH_LR_D: u32 = 0x1000302f;
+const MASK_LR_D: u32 = 0xf9f0707f;
+const MATCH_LR_W: u32 = 0x1000202f;
+const MASK_LR_W: u32 = 0xf9f0707f;
 const MATCH_LUI: u32 = 0x37;
-// const MASK_LUI: u32 = 0x7f;
+const MASK_LUI: u32 = 0x7f;
 const MATCH_LW: u32 = 0x2003;
-// const MASK_LW: u32 = 0x707f;
+const MASK_LW: u32 = 0x707f;
 // const MATCH_LW_AQ: u32 = 0x3400202f;
 // const MASK_LW_AQ: u32 = 0xfdf0707f;
-// const MATCH_LWU: u32 = 0x6003;
-// const MASK_LWU: u32 = 0x707f;
+const MATCH_LWU: u32 = 0x6003;
+const MASK_LWU: u32 = 0x707f;
 // const MATCH_MADDR32: u32 = 0xc4001077;
 // const MASK_MADDR32: u32 = 0xfe00707f;
 // const MATCH_MAX: u32 = 0xa006033;
@@ -1497,24 +5212,24 @@ const MATCH_LW: u32 = 0x2003;
 // const MASK_MOP_RR_7: u32 = 0xfe00707f;
 // const MATCH_MOP_RR_N: u32 = 0x82004073;
 // const MASK_MOP_RR_N: u32 = 0xb200707f;
-// const MATCH_MRET: u32 = 0x30200073;
-// const MASK_MRET: u32 = 0xffffffff;
+const MATCH_MRET: u32 = 0x30200073;
+const MASK_MRET: u32 = 0xffffffff;
 // const MATCH_MSUBR32: u32 = 0xc6001077;
 // const MASK_MSUBR32: u32 = 0xfe00707f;
-// const MATCH_MUL: u32 = 0x2000033;
-// const MASK_MUL: u32 = 0xfe00707f;
-// const MATCH_MULH: u32 = 0x2001033;
-// const MASK_MULH: u32 = 0xfe00707f;
-// const MATCH_MULHSU: u32 = 0x2002033;
-// const MASK_MULHSU: u32 = 0xfe00707f;
-// const MATCH_MULHU: u32 = 0x2003033;
-// const MASK_MULHU: u32 = 0xfe00707f;
+const MATCH_MUL: u32 = 0x2000033;
+const MASK_MUL: u32 = 0xfe00707f;
+const MATCH_MULH: u32 = 0x2001033;
+const MASK_MULH: u32 = 0xfe00707f;
+const MATCH_MULHSU: u32 = 0x2002033;
+const MASK_MULHSU: u32 = 0xfe00707f;
+const MATCH_MULHU: u32 = 0x2003033;
+const MASK_MULHU: u32 = 0xfe00707f;
 // const MATCH_MULR64: u32 = 0xf0001077;
 // const MASK_MULR64: u32 = 0xfe00707f;
 // const MATCH_MULSR64: u32 = 0xe0001077;
 // const MASK_MULSR64: u32 = 0xfe00707f;
-// const MATCH_MULW: u32 = 0x200003b;
-// const MASK_MULW: u32 = 0xfe00707f;
+const MATCH_MULW: u32 = 0x200003b;
+const MASK_MULW: u32 = 0xfe00707f;
 // const MATCH_NTL_ALL: u32 = 0x500033;
 // const MASK_NTL_ALL: u32 = 0xffffffff;
 // const MATCH_NTL_P1: u32 = 0x200033;
@@ -1524,11 +5239,11 @@ const MATCH_LW: u32 = 0x2003;
 // const MATCH_NTL_S1: u32 = 0x400033;
 // const MASK_NTL_S1: u32 = 0xffffffff;
 const MATCH_OR: u32 = 0x6033;
-// const MASK_OR: u32 = 0xfe00707f;
+const MASK_OR: u32 = 0xfe00707f;
 // const MATCH_ORC_B: u32 = 0x28705013;
 // const MASK_ORC_B: u32 = 0xfff0707f;
 const MATCH_ORI: u32 = 0x6013;
-// const MASK_ORI: u32 = 0x707f;
+const MASK_ORI: u32 = 0x707f;
 // const MATCH_ORN: u32 = 0x40006033;
 // const MASK_ORN: u32 = 0xfe00707f;
 // const MATCH_PACK: u32 = 0x8004033;
@@ -1597,14 +5312,14 @@ const MATCH_ORI: u32 = 0x6013;
 // const MASK_RDTIME: u32 = 0xfffff07f;
 // const MATCH_RDTIMEH: u32 = 0xc8102073;
 // const MASK_RDTIMEH: u32 = 0xfffff07f;
-// const MATCH_REM: u32 = 0x2006033;
-// const MASK_REM: u32 = 0xfe00707f;
-// const MATCH_REMU: u32 = 0x2007033;
-// const MASK_REMU: u32 = 0xfe00707f;
-// const MATCH_REMUW: u32 = 0x200703b;
-// const MASK_REMUW: u32 = 0xfe00707f;
-// const MATCH_REMW: u32 = 0x200603b;
-// const MASK_REMW: u32 = 0xfe00707f;
+const MATCH_REM: u32 = 0x2006033;
+const MASK_REM: u32 = 0xfe00707f;
+const MATCH_REMU: u32 = 0x2007033;
+const MASK_REMU: u32 = 0xfe00707f;
+const MATCH_REMUW: u32 = 0x200703b;
+const MASK_REMUW: u32 = 0xfe00707f;
+const MATCH_REMW: u32 = 0x200603b;
+const MASK_REMW: u32 = 0xfe00707f;
 // const MATCH_REV: u32 = 0x6bf05013;
 // const MASK_REV: u32 = 0xfff0707f;
 // const MATCH_REV8: u32 = 0x6b805013;
@@ -1642,15 +5357,15 @@ const MATCH_ORI: u32 = 0x6013;
 // const MATCH_RSUBW: u32 = 0x22001077;
 // const MASK_RSUBW: u32 = 0xfe00707f;
 const MATCH_SB: u32 = 0x23;
-// const MASK_SB: u32 = 0x707f;
+const MASK_SB: u32 = 0x707f;
 // const MATCH_SB_RL: u32 = 0x3a00002f;
 // const MASK_SB_RL: u32 = 0xfa007fff;
 // const MATCH_SBREAK: u32 = 0x100073;
 // const MASK_SBREAK: u32 = 0xffffffff;
-// const MATCH_SC_D: u32 = 0x1800302f;
-// const MASK_SC_D: u32 = 0xf800707f;
-// const MATCH_SC_W: u32 = 0x1800202f;
-// const MASK_SC_W: u32 = 0xf800707f;
+const MATCH_SC_D: u32 = 0x1800302f;
+const MASK_SC_D: u32 = 0xf800707f;
+const MATCH_SC_W: u32 = 0x1800202f;
+const MASK_SC_W: u32 = 0xf800707f;
 // const MATCH_SCALL: u32 = 0x73;
 // const MASK_SCALL: u32 = 0xffffffff;
 // const MATCH_SCLIP16: u32 = 0x84000077;
@@ -1667,8 +5382,8 @@ const MATCH_SB: u32 = 0x23;
 // const MASK_SCMPLT16: u32 = 0xfe00707f;
 // const MATCH_SCMPLT8: u32 = 0xe000077;
 // const MASK_SCMPLT8: u32 = 0xfe00707f;
-// const MATCH_SD: u32 = 0x3023;
-// const MASK_SD: u32 = 0x707f;
+const MATCH_SD: u32 = 0x3023;
+const MASK_SD: u32 = 0x707f;
 // const MATCH_SD_RL: u32 = 0x3a00302f;
 // const MASK_SD_RL: u32 = 0xfa007fff;
 // const MATCH_SEXT_B: u32 = 0x60401013;
@@ -1682,7 +5397,7 @@ const MATCH_SB: u32 = 0x23;
 // const MATCH_SFENCE_W_INVAL: u32 = 0x18000073;
 // const MASK_SFENCE_W_INVAL: u32 = 0xffffffff;
 const MATCH_SH: u32 = 0x1023;
-// const MASK_SH: u32 = 0x707f;
+const MASK_SH: u32 = 0x707f;
 // const MATCH_SH1ADD: u32 = 0x20002033;
 // const MASK_SH1ADD: u32 = 0xfe00707f;
 // const MATCH_SH1ADD_UW: u32 = 0x2000203b;
@@ -1734,7 +5449,7 @@ const MATCH_SH: u32 = 0x1023;
 // const MATCH_SINVAL_VMA: u32 = 0x16000073;
 // const MASK_SINVAL_VMA: u32 = 0xfe007fff;
 const MATCH_SLL: u32 = 0x1033;
-// const MASK_SLL: u32 = 0xfe00707f;
+const MASK_SLL: u32 = 0xfe00707f;
 // const MATCH_SLL16: u32 = 0x54000077;
 // const MASK_SLL16: u32 = 0xfe00707f;
 // const MATCH_SLL32: u32 = 0x54002077;
@@ -1744,7 +5459,7 @@ const MATCH_SLL: u32 = 0x1033;
 // const MATCH_SLLD: u32 = 0x107b;
 // const MASK_SLLD: u32 = 0xfe00707f;
 const MATCH_SLLI: u32 = 0x1013;
-// const MASK_SLLI: u32 = 0xfc00707f;
+const MASK_SLLI: u32 = 0xfc00707f;
 // const MATCH_SLLI16: u32 = 0x74000077;
 // const MASK_SLLI16: u32 = 0xff00707f;
 // const MATCH_SLLI32: u32 = 0x74002077;
@@ -1759,10 +5474,10 @@ const MATCH_SLLI: u32 = 0x1013;
 // const MASK_SLLI_UW: u32 = 0xfc00707f;
 // const MATCH_SLLID: u32 = 0x105b;
 // const MASK_SLLID: u32 = 0xfc00707f;
-// const MATCH_SLLIW: u32 = 0x101b;
-// const MASK_SLLIW: u32 = 0xfe00707f;
-// const MATCH_SLLW: u32 = 0x103b;
-// const MASK_SLLW: u32 = 0xfe00707f;
+const MATCH_SLLIW: u32 = 0x101b;
+const MASK_SLLIW: u32 = 0xfe00707f;
+const MATCH_SLLW: u32 = 0x103b;
+const MASK_SLLW: u32 = 0xfe00707f;
 // const MATCH_SLO: u32 = 0x20001033;
 // const MASK_SLO: u32 = 0xfe00707f;
 // const MATCH_SLOI: u32 = 0x20001013;
@@ -1772,13 +5487,13 @@ const MATCH_SLLI: u32 = 0x1013;
 // const MATCH_SLOW: u32 = 0x2000103b;
 // const MASK_SLOW: u32 = 0xfe00707f;
 const MATCH_SLT: u32 = 0x2033;
-// const MASK_SLT: u32 = 0xfe00707f;
+const MASK_SLT: u32 = 0xfe00707f;
 const MATCH_SLTI: u32 = 0x2013;
-// const MASK_SLTI: u32 = 0x707f;
+const MASK_SLTI: u32 = 0x707f;
 const MATCH_SLTIU: u32 = 0x3013;
-// const MASK_SLTIU: u32 = 0x707f;
+const MASK_SLTIU: u32 = 0x707f;
 const MATCH_SLTU: u32 = 0x3033;
-// const MASK_SLTU: u32 = 0xfe00707f;
+const MASK_SLTU: u32 = 0xfe00707f;
 // const MATCH_SM3P0: u32 = 0x10801013;
 // const MASK_SM3P0: u32 = 0xfff0707f;
 // const MATCH_SM3P1: u32 = 0x10901013;
@@ -1874,7 +5589,7 @@ const MATCH_SLTU: u32 = 0x3033;
 // const MATCH_SQ: u32 = 0x4023;
 // const MASK_SQ: u32 = 0x707f;
 const MATCH_SRA: u32 = 0x40005033;
-// const MASK_SRA: u32 = 0xfe00707f;
+const MASK_SRA: u32 = 0xfe00707f;
 // const MATCH_SRA16: u32 = 0x50000077;
 // const MASK_SRA16: u32 = 0xfe00707f;
 // const MATCH_SRA16_U: u32 = 0x60000077;
@@ -1892,7 +5607,7 @@ const MATCH_SRA: u32 = 0x40005033;
 // const MATCH_SRAD: u32 = 0x4000507b;
 // const MASK_SRAD: u32 = 0xfe00707f;
 const MATCH_SRAI: u32 = 0x40005013;
-// const MASK_SRAI: u32 = 0xfc00707f;
+const MASK_SRAI: u32 = 0xfc00707f;
 // const MATCH_SRAI16: u32 = 0x70000077;
 // const MASK_SRAI16: u32 = 0xff00707f;
 // const MATCH_SRAI16_U: u32 = 0x71000077;
@@ -1913,16 +5628,16 @@ const MATCH_SRAI: u32 = 0x40005013;
 // const MASK_SRAI_U: u32 = 0xfc00707f;
 // const MATCH_SRAID: u32 = 0x4000505b;
 // const MASK_SRAID: u32 = 0xfc00707f;
-// const MATCH_SRAIW: u32 = 0x4000501b;
-// const MASK_SRAIW: u32 = 0xfe00707f;
+const MATCH_SRAIW: u32 = 0x4000501b;
+const MASK_SRAIW: u32 = 0xfe00707f;
 // const MATCH_SRAIW_U: u32 = 0x34001077;
 // const MASK_SRAIW_U: u32 = 0xfe00707f;
-// const MATCH_SRAW: u32 = 0x4000503b;
-// const MASK_SRAW: u32 = 0xfe00707f;
-// const MATCH_SRET: u32 = 0x10200073;
-// const MASK_SRET: u32 = 0xffffffff;
+const MATCH_SRAW: u32 = 0x4000503b;
+const MASK_SRAW: u32 = 0xfe00707f;
+const MATCH_SRET: u32 = 0x10200073;
+const MASK_SRET: u32 = 0xffffffff;
 const MATCH_SRL: u32 = 0x5033;
-// const MASK_SRL: u32 = 0xfe00707f;
+const MASK_SRL: u32 = 0xfe00707f;
 // const MATCH_SRL16: u32 = 0x52000077;
 // const MASK_SRL16: u32 = 0xfe00707f;
 // const MATCH_SRL16_U: u32 = 0x62000077;
@@ -1938,7 +5653,7 @@ const MATCH_SRL: u32 = 0x5033;
 // const MATCH_SRLD: u32 = 0x507b;
 // const MASK_SRLD: u32 = 0xfe00707f;
 const MATCH_SRLI: u32 = 0x5013;
-// const MASK_SRLI: u32 = 0xfc00707f;
+const MASK_SRLI: u32 = 0xfc00707f;
 // const MATCH_SRLI16: u32 = 0x72000077;
 // const MASK_SRLI16: u32 = 0xff00707f;
 // const MATCH_SRLI16_U: u32 = 0x73000077;
@@ -1957,10 +5672,10 @@ const MATCH_SRLI: u32 = 0x5013;
 // const MASK_SRLI_RV32: u32 = 0xfe00707f;
 // const MATCH_SRLID: u32 = 0x505b;
 // const MASK_SRLID: u32 = 0xfc00707f;
-// const MATCH_SRLIW: u32 = 0x501b;
-// const MASK_SRLIW: u32 = 0xfe00707f;
-// const MATCH_SRLW: u32 = 0x503b;
-// const MASK_SRLW: u32 = 0xfe00707f;
+const MATCH_SRLIW: u32 = 0x501b;
+const MASK_SRLIW: u32 = 0xfe00707f;
+const MATCH_SRLW: u32 = 0x503b;
+const MASK_SRLW: u32 = 0xfe00707f;
 // const MATCH_SRO: u32 = 0x20005033;
 // const MASK_SRO: u32 = 0xfe00707f;
 // const MATCH_SROI: u32 = 0x20005013;
@@ -1978,7 +5693,7 @@ const MATCH_SRLI: u32 = 0x5013;
 // const MATCH_STSA32: u32 = 0xf2002077;
 // const MASK_STSA32: u32 = 0xfe00707f;
 const MATCH_SUB: u32 = 0x40000033;
-// const MASK_SUB: u32 = 0xfe00707f;
+const MASK_SUB: u32 = 0xfe00707f;
 // const MATCH_SUB16: u32 = 0x42000077;
 // const MASK_SUB16: u32 = 0xfe00707f;
 // const MATCH_SUB32: u32 = 0x42002077;
@@ -1989,8 +5704,8 @@ const MATCH_SUB: u32 = 0x40000033;
 // const MASK_SUB8: u32 = 0xfe00707f;
 // const MATCH_SUBD: u32 = 0x4000007b;
 // const MASK_SUBD: u32 = 0xfe00707f;
-// const MATCH_SUBW: u32 = 0x4000003b;
-// const MASK_SUBW: u32 = 0xfe00707f;
+const MATCH_SUBW: u32 = 0x4000003b;
+const MASK_SUBW: u32 = 0xfe00707f;
 // const MATCH_SUNPKD810: u32 = 0xac800077;
 // const MASK_SUNPKD810: u32 = 0xfff0707f;
 // const MATCH_SUNPKD820: u32 = 0xac900077;
@@ -2002,7 +5717,7 @@ const MATCH_SUB: u32 = 0x40000033;
 // const MATCH_SUNPKD832: u32 = 0xad300077;
 // const MASK_SUNPKD832: u32 = 0xfff0707f;
 const MATCH_SW: u32 = 0x2023;
-// const MASK_SW: u32 = 0x707f;
+const MASK_SW: u32 = 0x707f;
 // const MATCH_SW_RL: u32 = 0x3a00202f;
 // const MASK_SW_RL: u32 = 0xfa007fff;
 // const MATCH_UCLIP16: u32 = 0x85000077;
@@ -3071,8 +6786,8 @@ const MATCH_SW: u32 = 0x2023;
 // const MASK_VZEXT_VF4: u32 = 0xfc0ff07f;
 // const MATCH_VZEXT_VF8: u32 = 0x48012057;
 // const MASK_VZEXT_VF8: u32 = 0xfc0ff07f;
-// const MATCH_WFI: u32 = 0x10500073;
-// const MASK_WFI: u32 = 0xffffffff;
+const MATCH_WFI: u32 = 0x10500073;
+const MASK_WFI: u32 = 0xffffffff;
 // const MATCH_WRS_NTO: u32 = 0xd00073;
 // const MASK_WRS_NTO: u32 = 0xffffffff;
 // const MATCH_WRS_STO: u32 = 0x1d00073;
@@ -3080,9 +6795,9 @@ const MATCH_SW: u32 = 0x2023;
 // const MATCH_XNOR: u32 = 0x40004033;
 // const MASK_XNOR: u32 = 0xfe00707f;
 const MATCH_XOR: u32 = 0x4033;
-// const MASK_XOR: u32 = 0xfe00707f;
+const MASK_XOR: u32 = 0xfe00707f;
 const MATCH_XORI: u32 = 0x4013;
-// const MASK_XORI: u32 = 0x707f;
+const MASK_XORI: u32 = 0x707f;
 // const MATCH_XPERM16: u32 = 0x28006033;
 // const MASK_XPERM16: u32 = 0xfe00707f;
 // const MATCH_XPERM32: u32 = 0x28000033;
@@ -3105,455 +6820,455 @@ const MATCH_XORI: u32 = 0x4013;
 // const MASK_ZUNPKD831: u32 = 0xfff0707f;
 // const MATCH_ZUNPKD832: u32 = 0xad700077;
 // const MASK_ZUNPKD832: u32 = 0xfff0707f;
-// const CSR_FFLAGS: u16 = 0x1;
-// const CSR_FRM: u16 = 0x2;
-// const CSR_FCSR: u16 = 0x3;
-// const CSR_VSTART: u16 = 0x8;
-// const CSR_VXSAT: u16 = 0x9;
-// const CSR_VXRM: u16 = 0xa;
-// const CSR_VCSR: u16 = 0xf;
-// const CSR_SEED: u16 = 0x15;
-// const CSR_JVT: u16 = 0x17;
-// const CSR_CYCLE: u16 = 0xc00;
-// const CSR_TIME: u16 = 0xc01;
-// const CSR_INSTRET: u16 = 0xc02;
-// const CSR_HPMCOUNTER3: u16 = 0xc03;
-// const CSR_HPMCOUNTER4: u16 = 0xc04;
-// const CSR_HPMCOUNTER5: u16 = 0xc05;
-// const CSR_HPMCOUNTER6: u16 = 0xc06;
-// const CSR_HPMCOUNTER7: u16 = 0xc07;
-// const CSR_HPMCOUNTER8: u16 = 0xc08;
-// const CSR_HPMCOUNTER9: u16 = 0xc09;
-// const CSR_HPMCOUNTER10: u16 = 0xc0a;
-// const CSR_HPMCOUNTER11: u16 = 0xc0b;
-// const CSR_HPMCOUNTER12: u16 = 0xc0c;
-// const CSR_HPMCOUNTER13: u16 = 0xc0d;
-// const CSR_HPMCOUNTER14: u16 = 0xc0e;
-// const CSR_HPMCOUNTER15: u16 = 0xc0f;
-// const CSR_HPMCOUNTER16: u16 = 0xc10;
-// const CSR_HPMCOUNTER17: u16 = 0xc11;
-// const CSR_HPMCOUNTER18: u16 = 0xc12;
-// const CSR_HPMCOUNTER19: u16 = 0xc13;
-// const CSR_HPMCOUNTER20: u16 = 0xc14;
-// const CSR_HPMCOUNTER21: u16 = 0xc15;
-// const CSR_HPMCOUNTER22: u16 = 0xc16;
-// const CSR_HPMCOUNTER23: u16 = 0xc17;
-// const CSR_HPMCOUNTER24: u16 = 0xc18;
-// const CSR_HPMCOUNTER25: u16 = 0xc19;
-// const CSR_HPMCOUNTER26: u16 = 0xc1a;
-// const CSR_HPMCOUNTER27: u16 = 0xc1b;
-// const CSR_HPMCOUNTER28: u16 = 0xc1c;
-// const CSR_HPMCOUNTER29: u16 = 0xc1d;
-// const CSR_HPMCOUNTER30: u16 = 0xc1e;
-// const CSR_HPMCOUNTER31: u16 = 0xc1f;
-// const CSR_VL: u16 = 0xc20;
-// const CSR_VTYPE: u16 = 0xc21;
-// const CSR_VLENB: u16 = 0xc22;
-// const CSR_SSTATUS: u16 = 0x100;
-// const CSR_SEDELEG: u16 = 0x102;
-// const CSR_SIDELEG: u16 = 0x103;
-// const CSR_SIE: u16 = 0x104;
-// const CSR_STVEC: u16 = 0x105;
-// const CSR_SCOUNTEREN: u16 = 0x106;
-// const CSR_SENVCFG: u16 = 0x10a;
-// const CSR_SSTATEEN0: u16 = 0x10c;
-// const CSR_SSTATEEN1: u16 = 0x10d;
-// const CSR_SSTATEEN2: u16 = 0x10e;
-// const CSR_SSTATEEN3: u16 = 0x10f;
-// const CSR_SCOUNTINHIBIT: u16 = 0x120;
-// const CSR_SSCRATCH: u16 = 0x140;
-// const CSR_SEPC: u16 = 0x141;
-// const CSR_SCAUSE: u16 = 0x142;
-// const CSR_STVAL: u16 = 0x143;
-// const CSR_SIP: u16 = 0x144;
-// const CSR_STIMECMP: u16 = 0x14d;
-// const CSR_SISELECT: u16 = 0x150;
-// const CSR_SIREG: u16 = 0x151;
-// const CSR_SIREG2: u16 = 0x152;
-// const CSR_SIREG3: u16 = 0x153;
-// const CSR_SIREG4: u16 = 0x155;
-// const CSR_SIREG5: u16 = 0x156;
-// const CSR_SIREG6: u16 = 0x157;
-// const CSR_STOPEI: u16 = 0x15c;
-// const CSR_SATP: u16 = 0x180;
-// const CSR_SCONTEXT: u16 = 0x5a8;
-// const CSR_VSSTATUS: u16 = 0x200;
-// const CSR_VSIE: u16 = 0x204;
-// const CSR_VSTVEC: u16 = 0x205;
-// const CSR_VSSCRATCH: u16 = 0x240;
-// const CSR_VSEPC: u16 = 0x241;
-// const CSR_VSCAUSE: u16 = 0x242;
-// const CSR_VSTVAL: u16 = 0x243;
-// const CSR_VSIP: u16 = 0x244;
-// const CSR_VSTIMECMP: u16 = 0x24d;
-// const CSR_VSISELECT: u16 = 0x250;
-// const CSR_VSIREG: u16 = 0x251;
-// const CSR_VSIREG2: u16 = 0x252;
-// const CSR_VSIREG3: u16 = 0x253;
-// const CSR_VSIREG4: u16 = 0x255;
-// const CSR_VSIREG5: u16 = 0x256;
-// const CSR_VSIREG6: u16 = 0x257;
-// const CSR_VSTOPEI: u16 = 0x25c;
-// const CSR_VSATP: u16 = 0x280;
-// const CSR_HSTATUS: u16 = 0x600;
-// const CSR_HEDELEG: u16 = 0x602;
-// const CSR_HIDELEG: u16 = 0x603;
-// const CSR_HIE: u16 = 0x604;
-// const CSR_HTIMEDELTA: u16 = 0x605;
-// const CSR_HCOUNTEREN: u16 = 0x606;
-// const CSR_HGEIE: u16 = 0x607;
-// const CSR_HVIEN: u16 = 0x608;
-// const CSR_HVICTL: u16 = 0x609;
-// const CSR_HENVCFG: u16 = 0x60a;
-// const CSR_HSTATEEN0: u16 = 0x60c;
-// const CSR_HSTATEEN1: u16 = 0x60d;
-// const CSR_HSTATEEN2: u16 = 0x60e;
-// const CSR_HSTATEEN3: u16 = 0x60f;
-// const CSR_HTVAL: u16 = 0x643;
-// const CSR_HIP: u16 = 0x644;
-// const CSR_HVIP: u16 = 0x645;
-// const CSR_HVIPRIO1: u16 = 0x646;
-// const CSR_HVIPRIO2: u16 = 0x647;
-// const CSR_HTINST: u16 = 0x64a;
-// const CSR_HGATP: u16 = 0x680;
-// const CSR_HCONTEXT: u16 = 0x6a8;
-// const CSR_HGEIP: u16 = 0xe12;
-// const CSR_VSTOPI: u16 = 0xeb0;
-// const CSR_SCOUNTOVF: u16 = 0xda0;
-// const CSR_STOPI: u16 = 0xdb0;
-// const CSR_UTVT: u16 = 0x7;
-// const CSR_UNXTI: u16 = 0x45;
-// const CSR_UINTSTATUS: u16 = 0x46;
-// const CSR_USCRATCHCSW: u16 = 0x48;
-// const CSR_USCRATCHCSWL: u16 = 0x49;
-// const CSR_STVT: u16 = 0x107;
-// const CSR_SNXTI: u16 = 0x145;
-// const CSR_SINTSTATUS: u16 = 0x146;
-// const CSR_SSCRATCHCSW: u16 = 0x148;
-// const CSR_SSCRATCHCSWL: u16 = 0x149;
-// const CSR_MTVT: u16 = 0x307;
-// const CSR_MNXTI: u16 = 0x345;
-// const CSR_MINTSTATUS: u16 = 0x346;
-// const CSR_MSCRATCHCSW: u16 = 0x348;
-// const CSR_MSCRATCHCSWL: u16 = 0x349;
-// const CSR_MSTATUS: u16 = 0x300;
-// const CSR_MISA: u16 = 0x301;
-// const CSR_MEDELEG: u16 = 0x302;
-// const CSR_MIDELEG: u16 = 0x303;
-// const CSR_MIE: u16 = 0x304;
-// const CSR_MTVEC: u16 = 0x305;
-// const CSR_MCOUNTEREN: u16 = 0x306;
-// const CSR_MVIEN: u16 = 0x308;
-// const CSR_MVIP: u16 = 0x309;
-// const CSR_MENVCFG: u16 = 0x30a;
-// const CSR_MSTATEEN0: u16 = 0x30c;
-// const CSR_MSTATEEN1: u16 = 0x30d;
-// const CSR_MSTATEEN2: u16 = 0x30e;
-// const CSR_MSTATEEN3: u16 = 0x30f;
-// const CSR_MCOUNTINHIBIT: u16 = 0x320;
-// const CSR_MSCRATCH: u16 = 0x340;
-// const CSR_MEPC: u16 = 0x341;
-// const CSR_MCAUSE: u16 = 0x342;
-// const CSR_MTVAL: u16 = 0x343;
-// const CSR_MIP: u16 = 0x344;
-// const CSR_MTINST: u16 = 0x34a;
-// const CSR_MTVAL2: u16 = 0x34b;
-// const CSR_MISELECT: u16 = 0x350;
-// const CSR_MIREG: u16 = 0x351;
-// const CSR_MIREG2: u16 = 0x352;
-// const CSR_MIREG3: u16 = 0x353;
-// const CSR_MIREG4: u16 = 0x355;
-// const CSR_MIREG5: u16 = 0x356;
-// const CSR_MIREG6: u16 = 0x357;
-// const CSR_MTOPEI: u16 = 0x35c;
-// const CSR_PMPCFG0: u16 = 0x3a0;
-// const CSR_PMPCFG1: u16 = 0x3a1;
-// const CSR_PMPCFG2: u16 = 0x3a2;
-// const CSR_PMPCFG3: u16 = 0x3a3;
-// const CSR_PMPCFG4: u16 = 0x3a4;
-// const CSR_PMPCFG5: u16 = 0x3a5;
-// const CSR_PMPCFG6: u16 = 0x3a6;
-// const CSR_PMPCFG7: u16 = 0x3a7;
-// const CSR_PMPCFG8: u16 = 0x3a8;
-// const CSR_PMPCFG9: u16 = 0x3a9;
-// const CSR_PMPCFG10: u16 = 0x3aa;
-// const CSR_PMPCFG11: u16 = 0x3ab;
-// const CSR_PMPCFG12: u16 = 0x3ac;
-// const CSR_PMPCFG13: u16 = 0x3ad;
-// const CSR_PMPCFG14: u16 = 0x3ae;
-// const CSR_PMPCFG15: u16 = 0x3af;
-// const CSR_PMPADDR0: u16 = 0x3b0;
-// const CSR_PMPADDR1: u16 = 0x3b1;
-// const CSR_PMPADDR2: u16 = 0x3b2;
-// const CSR_PMPADDR3: u16 = 0x3b3;
-// const CSR_PMPADDR4: u16 = 0x3b4;
-// const CSR_PMPADDR5: u16 = 0x3b5;
-// const CSR_PMPADDR6: u16 = 0x3b6;
-// const CSR_PMPADDR7: u16 = 0x3b7;
-// const CSR_PMPADDR8: u16 = 0x3b8;
-// const CSR_PMPADDR9: u16 = 0x3b9;
-// const CSR_PMPADDR10: u16 = 0x3ba;
-// const CSR_PMPADDR11: u16 = 0x3bb;
-// const CSR_PMPADDR12: u16 = 0x3bc;
-// const CSR_PMPADDR13: u16 = 0x3bd;
-// const CSR_PMPADDR14: u16 = 0x3be;
-// const CSR_PMPADDR15: u16 = 0x3bf;
-// const CSR_PMPADDR16: u16 = 0x3c0;
-// const CSR_PMPADDR17: u16 = 0x3c1;
-// const CSR_PMPADDR18: u16 = 0x3c2;
-// const CSR_PMPADDR19: u16 = 0x3c3;
-// const CSR_PMPADDR20: u16 = 0x3c4;
-// const CSR_PMPADDR21: u16 = 0x3c5;
-// const CSR_PMPADDR22: u16 = 0x3c6;
-// const CSR_PMPADDR23: u16 = 0x3c7;
-// const CSR_PMPADDR24: u16 = 0x3c8;
-// const CSR_PMPADDR25: u16 = 0x3c9;
-// const CSR_PMPADDR26: u16 = 0x3ca;
-// const CSR_PMPADDR27: u16 = 0x3cb;
-// const CSR_PMPADDR28: u16 = 0x3cc;
-// const CSR_PMPADDR29: u16 = 0x3cd;
-// const CSR_PMPADDR30: u16 = 0x3ce;
-// const CSR_PMPADDR31: u16 = 0x3cf;
-// const CSR_PMPADDR32: u16 = 0x3d0;
-// const CSR_PMPADDR33: u16 = 0x3d1;
-// const CSR_PMPADDR34: u16 = 0x3d2;
-// const CSR_PMPADDR35: u16 = 0x3d3;
-// const CSR_PMPADDR36: u16 = 0x3d4;
-// const CSR_PMPADDR37: u16 = 0x3d5;
-// const CSR_PMPADDR38: u16 = 0x3d6;
-// const CSR_PMPADDR39: u16 = 0x3d7;
-// const CSR_PMPADDR40: u16 = 0x3d8;
-// const CSR_PMPADDR41: u16 = 0x3d9;
-// const CSR_PMPADDR42: u16 = 0x3da;
-// const CSR_PMPADDR43: u16 = 0x3db;
-// const CSR_PMPADDR44: u16 = 0x3dc;
-// const CSR_PMPADDR45: u16 = 0x3dd;
-// const CSR_PMPADDR46: u16 = 0x3de;
-// const CSR_PMPADDR47: u16 = 0x3df;
-// const CSR_PMPADDR48: u16 = 0x3e0;
-// const CSR_PMPADDR49: u16 = 0x3e1;
-// const CSR_PMPADDR50: u16 = 0x3e2;
-// const CSR_PMPADDR51: u16 = 0x3e3;
-// const CSR_PMPADDR52: u16 = 0x3e4;
-// const CSR_PMPADDR53: u16 = 0x3e5;
-// const CSR_PMPADDR54: u16 = 0x3e6;
-// const CSR_PMPADDR55: u16 = 0x3e7;
-// const CSR_PMPADDR56: u16 = 0x3e8;
-// const CSR_PMPADDR57: u16 = 0x3e9;
-// const CSR_PMPADDR58: u16 = 0x3ea;
-// const CSR_PMPADDR59: u16 = 0x3eb;
-// const CSR_PMPADDR60: u16 = 0x3ec;
-// const CSR_PMPADDR61: u16 = 0x3ed;
-// const CSR_PMPADDR62: u16 = 0x3ee;
-// const CSR_PMPADDR63: u16 = 0x3ef;
-// const CSR_MSECCFG: u16 = 0x747;
-// const CSR_TSELECT: u16 = 0x7a0;
-// const CSR_TDATA1: u16 = 0x7a1;
-// const CSR_TDATA2: u16 = 0x7a2;
-// const CSR_TDATA3: u16 = 0x7a3;
-// const CSR_TINFO: u16 = 0x7a4;
-// const CSR_TCONTROL: u16 = 0x7a5;
-// const CSR_MCONTEXT: u16 = 0x7a8;
-// const CSR_MSCONTEXT: u16 = 0x7aa;
-// const CSR_DCSR: u16 = 0x7b0;
-// const CSR_DPC: u16 = 0x7b1;
-// const CSR_DSCRATCH0: u16 = 0x7b2;
-// const CSR_DSCRATCH1: u16 = 0x7b3;
-// const CSR_MCYCLE: u16 = 0xb00;
-// const CSR_MINSTRET: u16 = 0xb02;
-// const CSR_MHPMCOUNTER3: u16 = 0xb03;
-// const CSR_MHPMCOUNTER4: u16 = 0xb04;
-// const CSR_MHPMCOUNTER5: u16 = 0xb05;
-// const CSR_MHPMCOUNTER6: u16 = 0xb06;
-// const CSR_MHPMCOUNTER7: u16 = 0xb07;
-// const CSR_MHPMCOUNTER8: u16 = 0xb08;
-// const CSR_MHPMCOUNTER9: u16 = 0xb09;
-// const CSR_MHPMCOUNTER10: u16 = 0xb0a;
-// const CSR_MHPMCOUNTER11: u16 = 0xb0b;
-// const CSR_MHPMCOUNTER12: u16 = 0xb0c;
-// const CSR_MHPMCOUNTER13: u16 = 0xb0d;
-// const CSR_MHPMCOUNTER14: u16 = 0xb0e;
-// const CSR_MHPMCOUNTER15: u16 = 0xb0f;
-// const CSR_MHPMCOUNTER16: u16 = 0xb10;
-// const CSR_MHPMCOUNTER17: u16 = 0xb11;
-// const CSR_MHPMCOUNTER18: u16 = 0xb12;
-// const CSR_MHPMCOUNTER19: u16 = 0xb13;
-// const CSR_MHPMCOUNTER20: u16 = 0xb14;
-// const CSR_MHPMCOUNTER21: u16 = 0xb15;
-// const CSR_MHPMCOUNTER22: u16 = 0xb16;
-// const CSR_MHPMCOUNTER23: u16 = 0xb17;
-// const CSR_MHPMCOUNTER24: u16 = 0xb18;
-// const CSR_MHPMCOUNTER25: u16 = 0xb19;
-// const CSR_MHPMCOUNTER26: u16 = 0xb1a;
-// const CSR_MHPMCOUNTER27: u16 = 0xb1b;
-// const CSR_MHPMCOUNTER28: u16 = 0xb1c;
-// const CSR_MHPMCOUNTER29: u16 = 0xb1d;
-// const CSR_MHPMCOUNTER30: u16 = 0xb1e;
-// const CSR_MHPMCOUNTER31: u16 = 0xb1f;
-// const CSR_MCYCLECFG: u16 = 0x321;
-// const CSR_MINSTRETCFG: u16 = 0x322;
-// const CSR_MHPMEVENT3: u16 = 0x323;
-// const CSR_MHPMEVENT4: u16 = 0x324;
-// const CSR_MHPMEVENT5: u16 = 0x325;
-// const CSR_MHPMEVENT6: u16 = 0x326;
-// const CSR_MHPMEVENT7: u16 = 0x327;
-// const CSR_MHPMEVENT8: u16 = 0x328;
-// const CSR_MHPMEVENT9: u16 = 0x329;
-// const CSR_MHPMEVENT10: u16 = 0x32a;
-// const CSR_MHPMEVENT11: u16 = 0x32b;
-// const CSR_MHPMEVENT12: u16 = 0x32c;
-// const CSR_MHPMEVENT13: u16 = 0x32d;
-// const CSR_MHPMEVENT14: u16 = 0x32e;
-// const CSR_MHPMEVENT15: u16 = 0x32f;
-// const CSR_MHPMEVENT16: u16 = 0x330;
-// const CSR_MHPMEVENT17: u16 = 0x331;
-// const CSR_MHPMEVENT18: u16 = 0x332;
-// const CSR_MHPMEVENT19: u16 = 0x333;
-// const CSR_MHPMEVENT20: u16 = 0x334;
-// const CSR_MHPMEVENT21: u16 = 0x335;
-// const CSR_MHPMEVENT22: u16 = 0x336;
-// const CSR_MHPMEVENT23: u16 = 0x337;
-// const CSR_MHPMEVENT24: u16 = 0x338;
-// const CSR_MHPMEVENT25: u16 = 0x339;
-// const CSR_MHPMEVENT26: u16 = 0x33a;
-// const CSR_MHPMEVENT27: u16 = 0x33b;
-// const CSR_MHPMEVENT28: u16 = 0x33c;
-// const CSR_MHPMEVENT29: u16 = 0x33d;
-// const CSR_MHPMEVENT30: u16 = 0x33e;
-// const CSR_MHPMEVENT31: u16 = 0x33f;
-// const CSR_MVENDORID: u16 = 0xf11;
-// const CSR_MARCHID: u16 = 0xf12;
-// const CSR_MIMPID: u16 = 0xf13;
-// const CSR_MHARTID: u16 = 0xf14;
-// const CSR_MCONFIGPTR: u16 = 0xf15;
-// const CSR_MTOPI: u16 = 0xfb0;
-// const CSR_SIEH: u16 = 0x114;
-// const CSR_SIPH: u16 = 0x154;
-// const CSR_STIMECMPH: u16 = 0x15d;
-// const CSR_VSIEH: u16 = 0x214;
-// const CSR_VSIPH: u16 = 0x254;
-// const CSR_VSTIMECMPH: u16 = 0x25d;
-// const CSR_HTIMEDELTAH: u16 = 0x615;
-// const CSR_HIDELEGH: u16 = 0x613;
-// const CSR_HVIENH: u16 = 0x618;
-// const CSR_HENVCFGH: u16 = 0x61a;
-// const CSR_HVIPH: u16 = 0x655;
-// const CSR_HVIPRIO1H: u16 = 0x656;
-// const CSR_HVIPRIO2H: u16 = 0x657;
-// const CSR_HSTATEEN0H: u16 = 0x61c;
-// const CSR_HSTATEEN1H: u16 = 0x61d;
-// const CSR_HSTATEEN2H: u16 = 0x61e;
-// const CSR_HSTATEEN3H: u16 = 0x61f;
-// const CSR_CYCLEH: u16 = 0xc80;
-// const CSR_TIMEH: u16 = 0xc81;
-// const CSR_INSTRETH: u16 = 0xc82;
-// const CSR_HPMCOUNTER3H: u16 = 0xc83;
-// const CSR_HPMCOUNTER4H: u16 = 0xc84;
-// const CSR_HPMCOUNTER5H: u16 = 0xc85;
-// const CSR_HPMCOUNTER6H: u16 = 0xc86;
-// const CSR_HPMCOUNTER7H: u16 = 0xc87;
-// const CSR_HPMCOUNTER8H: u16 = 0xc88;
-// const CSR_HPMCOUNTER9H: u16 = 0xc89;
-// const CSR_HPMCOUNTER10H: u16 = 0xc8a;
-// const CSR_HPMCOUNTER11H: u16 = 0xc8b;
-// const CSR_HPMCOUNTER12H: u16 = 0xc8c;
-// const CSR_HPMCOUNTER13H: u16 = 0xc8d;
-// const CSR_HPMCOUNTER14H: u16 = 0xc8e;
-// const CSR_HPMCOUNTER15H: u16 = 0xc8f;
-// const CSR_HPMCOUNTER16H: u16 = 0xc90;
-// const CSR_HPMCOUNTER17H: u16 = 0xc91;
-// const CSR_HPMCOUNTER18H: u16 = 0xc92;
-// const CSR_HPMCOUNTER19H: u16 = 0xc93;
-// const CSR_HPMCOUNTER20H: u16 = 0xc94;
-// const CSR_HPMCOUNTER21H: u16 = 0xc95;
-// const CSR_HPMCOUNTER22H: u16 = 0xc96;
-// const CSR_HPMCOUNTER23H: u16 = 0xc97;
-// const CSR_HPMCOUNTER24H: u16 = 0xc98;
-// const CSR_HPMCOUNTER25H: u16 = 0xc99;
-// const CSR_HPMCOUNTER26H: u16 = 0xc9a;
-// const CSR_HPMCOUNTER27H: u16 = 0xc9b;
-// const CSR_HPMCOUNTER28H: u16 = 0xc9c;
-// const CSR_HPMCOUNTER29H: u16 = 0xc9d;
-// const CSR_HPMCOUNTER30H: u16 = 0xc9e;
-// const CSR_HPMCOUNTER31H: u16 = 0xc9f;
-// const CSR_MSTATUSH: u16 = 0x310;
-// const CSR_MIDELEGH: u16 = 0x313;
-// const CSR_MIEH: u16 = 0x314;
-// const CSR_MVIENH: u16 = 0x318;
-// const CSR_MVIPH: u16 = 0x319;
-// const CSR_MENVCFGH: u16 = 0x31a;
-// const CSR_MSTATEEN0H: u16 = 0x31c;
-// const CSR_MSTATEEN1H: u16 = 0x31d;
-// const CSR_MSTATEEN2H: u16 = 0x31e;
-// const CSR_MSTATEEN3H: u16 = 0x31f;
-// const CSR_MIPH: u16 = 0x354;
-// const CSR_MCYCLECFGH: u16 = 0x721;
-// const CSR_MINSTRETCFGH: u16 = 0x722;
-// const CSR_MHPMEVENT3H: u16 = 0x723;
-// const CSR_MHPMEVENT4H: u16 = 0x724;
-// const CSR_MHPMEVENT5H: u16 = 0x725;
-// const CSR_MHPMEVENT6H: u16 = 0x726;
-// const CSR_MHPMEVENT7H: u16 = 0x727;
-// const CSR_MHPMEVENT8H: u16 = 0x728;
-// const CSR_MHPMEVENT9H: u16 = 0x729;
-// const CSR_MHPMEVENT10H: u16 = 0x72a;
-// const CSR_MHPMEVENT11H: u16 = 0x72b;
-// const CSR_MHPMEVENT12H: u16 = 0x72c;
-// const CSR_MHPMEVENT13H: u16 = 0x72d;
-// const CSR_MHPMEVENT14H: u16 = 0x72e;
-// const CSR_MHPMEVENT15H: u16 = 0x72f;
-// const CSR_MHPMEVENT16H: u16 = 0x730;
-// const CSR_MHPMEVENT17H: u16 = 0x731;
-// const CSR_MHPMEVENT18H: u16 = 0x732;
-// const CSR_MHPMEVENT19H: u16 = 0x733;
-// const CSR_MHPMEVENT20H: u16 = 0x734;
-// const CSR_MHPMEVENT21H: u16 = 0x735;
-// const CSR_MHPMEVENT22H: u16 = 0x736;
-// const CSR_MHPMEVENT23H: u16 = 0x737;
-// const CSR_MHPMEVENT24H: u16 = 0x738;
-// const CSR_MHPMEVENT25H: u16 = 0x739;
-// const CSR_MHPMEVENT26H: u16 = 0x73a;
-// const CSR_MHPMEVENT27H: u16 = 0x73b;
-// const CSR_MHPMEVENT28H: u16 = 0x73c;
-// const CSR_MHPMEVENT29H: u16 = 0x73d;
-// const CSR_MHPMEVENT30H: u16 = 0x73e;
-// const CSR_MHPMEVENT31H: u16 = 0x73f;
-// const CSR_MNSCRATCH: u16 = 0x740;
-// const CSR_MNEPC: u16 = 0x741;
-// const CSR_MNCAUSE: u16 = 0x742;
-// const CSR_MNSTATUS: u16 = 0x744;
-// const CSR_MSECCFGH: u16 = 0x757;
-// const CSR_MCYCLEH: u16 = 0xb80;
-// const CSR_MINSTRETH: u16 = 0xb82;
-// const CSR_MHPMCOUNTER3H: u16 = 0xb83;
-// const CSR_MHPMCOUNTER4H: u16 = 0xb84;
-// const CSR_MHPMCOUNTER5H: u16 = 0xb85;
-// const CSR_MHPMCOUNTER6H: u16 = 0xb86;
-// const CSR_MHPMCOUNTER7H: u16 = 0xb87;
-// const CSR_MHPMCOUNTER8H: u16 = 0xb88;
-// const CSR_MHPMCOUNTER9H: u16 = 0xb89;
-// const CSR_MHPMCOUNTER10H: u16 = 0xb8a;
-// const CSR_MHPMCOUNTER11H: u16 = 0xb8b;
-// const CSR_MHPMCOUNTER12H: u16 = 0xb8c;
-// const CSR_MHPMCOUNTER13H: u16 = 0xb8d;
-// const CSR_MHPMCOUNTER14H: u16 = 0xb8e;
-// const CSR_MHPMCOUNTER15H: u16 = 0xb8f;
-// const CSR_MHPMCOUNTER16H: u16 = 0xb90;
-// const CSR_MHPMCOUNTER17H: u16 = 0xb91;
-// const CSR_MHPMCOUNTER18H: u16 = 0xb92;
-// const CSR_MHPMCOUNTER19H: u16 = 0xb93;
-// const CSR_MHPMCOUNTER20H: u16 = 0xb94;
-// const CSR_MHPMCOUNTER21H: u16 = 0xb95;
-// const CSR_MHPMCOUNTER22H: u16 = 0xb96;
-// const CSR_MHPMCOUNTER23H: u16 = 0xb97;
-// const CSR_MHPMCOUNTER24H: u16 = 0xb98;
-// const CSR_MHPMCOUNTER25H: u16 = 0xb99;
-// const CSR_MHPMCOUNTER26H: u16 = 0xb9a;
-// const CSR_MHPMCOUNTER27H: u16 = 0xb9b;
-// const CSR_MHPMCOUNTER28H: u16 = 0xb9c;
-// const CSR_MHPMCOUNTER29H: u16 = 0xb9d;
-// const CSR_MHPMCOUNTER30H: u16 = 0xb9e;
-// const CSR_MHPMCOUNTER31H: u16 = 0xb9f;
+const CSR_FFLAGS: u16 = 0x1;
+const CSR_FRM: u16 = 0x2;
+const CSR_FCSR: u16 = 0x3;
+const CSR_VSTART: u16 = 0x8;
+const CSR_VXSAT: u16 = 0x9;
+const CSR_VXRM: u16 = 0xa;
+const CSR_VCSR: u16 = 0xf;
+const CSR_SEED: u16 = 0x15;
+const CSR_JVT: u16 = 0x17;
+const CSR_CYCLE: u16 = 0xc00;
+const CSR_TIME: u16 = 0xc01;
+const CSR_INSTRET: u16 = 0xc02;
+const CSR_HPMCOUNTER3: u16 = 0xc03;
+const CSR_HPMCOUNTER4: u16 = 0xc04;
+const CSR_HPMCOUNTER5: u16 = 0xc05;
+const CSR_HPMCOUNTER6: u16 = 0xc06;
+const CSR_HPMCOUNTER7: u16 = 0xc07;
+const CSR_HPMCOUNTER8: u16 = 0xc08;
+const CSR_HPMCOUNTER9: u16 = 0xc09;
+const CSR_HPMCOUNTER10: u16 = 0xc0a;
+const CSR_HPMCOUNTER11: u16 = 0xc0b;
+const CSR_HPMCOUNTER12: u16 = 0xc0c;
+const CSR_HPMCOUNTER13: u16 = 0xc0d;
+const CSR_HPMCOUNTER14: u16 = 0xc0e;
+const CSR_HPMCOUNTER15: u16 = 0xc0f;
+const CSR_HPMCOUNTER16: u16 = 0xc10;
+const CSR_HPMCOUNTER17: u16 = 0xc11;
+const CSR_HPMCOUNTER18: u16 = 0xc12;
+const CSR_HPMCOUNTER19: u16 = 0xc13;
+const CSR_HPMCOUNTER20: u16 = 0xc14;
+const CSR_HPMCOUNTER21: u16 = 0xc15;
+const CSR_HPMCOUNTER22: u16 = 0xc16;
+const CSR_HPMCOUNTER23: u16 = 0xc17;
+const CSR_HPMCOUNTER24: u16 = 0xc18;
+const CSR_HPMCOUNTER25: u16 = 0xc19;
+const CSR_HPMCOUNTER26: u16 = 0xc1a;
+const CSR_HPMCOUNTER27: u16 = 0xc1b;
+const CSR_HPMCOUNTER28: u16 = 0xc1c;
+const CSR_HPMCOUNTER29: u16 = 0xc1d;
+const CSR_HPMCOUNTER30: u16 = 0xc1e;
+const CSR_HPMCOUNTER31: u16 = 0xc1f;
+const CSR_VL: u16 = 0xc20;
+const CSR_VTYPE: u16 = 0xc21;
+const CSR_VLENB: u16 = 0xc22;
+const CSR_SSTATUS: u16 = 0x100;
+const CSR_SEDELEG: u16 = 0x102;
+const CSR_SIDELEG: u16 = 0x103;
+const CSR_SIE: u16 = 0x104;
+const CSR_STVEC: u16 = 0x105;
+const CSR_SCOUNTEREN: u16 = 0x106;
+const CSR_SENVCFG: u16 = 0x10a;
+const CSR_SSTATEEN0: u16 = 0x10c;
+const CSR_SSTATEEN1: u16 = 0x10d;
+const CSR_SSTATEEN2: u16 = 0x10e;
+const CSR_SSTATEEN3: u16 = 0x10f;
+const CSR_SCOUNTINHIBIT: u16 = 0x120;
+const CSR_SSCRATCH: u16 = 0x140;
+const CSR_SEPC: u16 = 0x141;
+const CSR_SCAUSE: u16 = 0x142;
+const CSR_STVAL: u16 = 0x143;
+const CSR_SIP: u16 = 0x144;
+const CSR_STIMECMP: u16 = 0x14d;
+const CSR_SISELECT: u16 = 0x150;
+const CSR_SIREG: u16 = 0x151;
+const CSR_SIREG2: u16 = 0x152;
+const CSR_SIREG3: u16 = 0x153;
+const CSR_SIREG4: u16 = 0x155;
+const CSR_SIREG5: u16 = 0x156;
+const CSR_SIREG6: u16 = 0x157;
+const CSR_STOPEI: u16 = 0x15c;
+const CSR_SATP: u16 = 0x180;
+const CSR_SCONTEXT: u16 = 0x5a8;
+const CSR_VSSTATUS: u16 = 0x200;
+const CSR_VSIE: u16 = 0x204;
+const CSR_VSTVEC: u16 = 0x205;
+const CSR_VSSCRATCH: u16 = 0x240;
+const CSR_VSEPC: u16 = 0x241;
+const CSR_VSCAUSE: u16 = 0x242;
+const CSR_VSTVAL: u16 = 0x243;
+const CSR_VSIP: u16 = 0x244;
+const CSR_VSTIMECMP: u16 = 0x24d;
+const CSR_VSISELECT: u16 = 0x250;
+const CSR_VSIREG: u16 = 0x251;
+const CSR_VSIREG2: u16 = 0x252;
+const CSR_VSIREG3: u16 = 0x253;
+const CSR_VSIREG4: u16 = 0x255;
+const CSR_VSIREG5: u16 = 0x256;
+const CSR_VSIREG6: u16 = 0x257;
+const CSR_VSTOPEI: u16 = 0x25c;
+const CSR_VSATP: u16 = 0x280;
+const CSR_HSTATUS: u16 = 0x600;
+const CSR_HEDELEG: u16 = 0x602;
+const CSR_HIDELEG: u16 = 0x603;
+const CSR_HIE: u16 = 0x604;
+const CSR_HTIMEDELTA: u16 = 0x605;
+const CSR_HCOUNTEREN: u16 = 0x606;
+const CSR_HGEIE: u16 = 0x607;
+const CSR_HVIEN: u16 = 0x608;
+const CSR_HVICTL: u16 = 0x609;
+const CSR_HENVCFG: u16 = 0x60a;
+const CSR_HSTATEEN0: u16 = 0x60c;
+const CSR_HSTATEEN1: u16 = 0x60d;
+const CSR_HSTATEEN2: u16 = 0x60e;
+const CSR_HSTATEEN3: u16 = 0x60f;
+const CSR_HTVAL: u16 = 0x643;
+const CSR_HIP: u16 = 0x644;
+const CSR_HVIP: u16 = 0x645;
+const CSR_HVIPRIO1: u16 = 0x646;
+const CSR_HVIPRIO2: u16 = 0x647;
+const CSR_HTINST: u16 = 0x64a;
+const CSR_HGATP: u16 = 0x680;
+const CSR_HCONTEXT: u16 = 0x6a8;
+const CSR_HGEIP: u16 = 0xe12;
+const CSR_VSTOPI: u16 = 0xeb0;
+const CSR_SCOUNTOVF: u16 = 0xda0;
+const CSR_STOPI: u16 = 0xdb0;
+const CSR_UTVT: u16 = 0x7;
+const CSR_UNXTI: u16 = 0x45;
+const CSR_UINTSTATUS: u16 = 0x46;
+const CSR_USCRATCHCSW: u16 = 0x48;
+const CSR_USCRATCHCSWL: u16 = 0x49;
+const CSR_STVT: u16 = 0x107;
+const CSR_SNXTI: u16 = 0x145;
+const CSR_SINTSTATUS: u16 = 0x146;
+const CSR_SSCRATCHCSW: u16 = 0x148;
+const CSR_SSCRATCHCSWL: u16 = 0x149;
+const CSR_MTVT: u16 = 0x307;
+const CSR_MNXTI: u16 = 0x345;
+const CSR_MINTSTATUS: u16 = 0x346;
+const CSR_MSCRATCHCSW: u16 = 0x348;
+const CSR_MSCRATCHCSWL: u16 = 0x349;
+const CSR_MSTATUS: u16 = 0x300;
+const CSR_MISA: u16 = 0x301;
+const CSR_MEDELEG: u16 = 0x302;
+const CSR_MIDELEG: u16 = 0x303;
+const CSR_MIE: u16 = 0x304;
+const CSR_MTVEC: u16 = 0x305;
+const CSR_MCOUNTEREN: u16 = 0x306;
+const CSR_MVIEN: u16 = 0x308;
+const CSR_MVIP: u16 = 0x309;
+const CSR_MENVCFG: u16 = 0x30a;
+const CSR_MSTATEEN0: u16 = 0x30c;
+const CSR_MSTATEEN1: u16 = 0x30d;
+const CSR_MSTATEEN2: u16 = 0x30e;
+const CSR_MSTATEEN3: u16 = 0x30f;
+const CSR_MCOUNTINHIBIT: u16 = 0x320;
+const CSR_MSCRATCH: u16 = 0x340;
+const CSR_MEPC: u16 = 0x341;
+const CSR_MCAUSE: u16 = 0x342;
+const CSR_MTVAL: u16 = 0x343;
+const CSR_MIP: u16 = 0x344;
+const CSR_MTINST: u16 = 0x34a;
+const CSR_MTVAL2: u16 = 0x34b;
+const CSR_MISELECT: u16 = 0x350;
+const CSR_MIREG: u16 = 0x351;
+const CSR_MIREG2: u16 = 0x352;
+const CSR_MIREG3: u16 = 0x353;
+const CSR_MIREG4: u16 = 0x355;
+const CSR_MIREG5: u16 = 0x356;
+const CSR_MIREG6: u16 = 0x357;
+const CSR_MTOPEI: u16 = 0x35c;
+const CSR_PMPCFG0: u16 = 0x3a0;
+const CSR_PMPCFG1: u16 = 0x3a1;
+const CSR_PMPCFG2: u16 = 0x3a2;
+const CSR_PMPCFG3: u16 = 0x3a3;
+const CSR_PMPCFG4: u16 = 0x3a4;
+const CSR_PMPCFG5: u16 = 0x3a5;
+const CSR_PMPCFG6: u16 = 0x3a6;
+const CSR_PMPCFG7: u16 = 0x3a7;
+const CSR_PMPCFG8: u16 = 0x3a8;
+const CSR_PMPCFG9: u16 = 0x3a9;
+const CSR_PMPCFG10: u16 = 0x3aa;
+const CSR_PMPCFG11: u16 = 0x3ab;
+const CSR_PMPCFG12: u16 = 0x3ac;
+const CSR_PMPCFG13: u16 = 0x3ad;
+const CSR_PMPCFG14: u16 = 0x3ae;
+const CSR_PMPCFG15: u16 = 0x3af;
+const CSR_PMPADDR0: u16 = 0x3b0;
+const CSR_PMPADDR1: u16 = 0x3b1;
+const CSR_PMPADDR2: u16 = 0x3b2;
+const CSR_PMPADDR3: u16 = 0x3b3;
+const CSR_PMPADDR4: u16 = 0x3b4;
+const CSR_PMPADDR5: u16 = 0x3b5;
+const CSR_PMPADDR6: u16 = 0x3b6;
+const CSR_PMPADDR7: u16 = 0x3b7;
+const CSR_PMPADDR8: u16 = 0x3b8;
+const CSR_PMPADDR9: u16 = 0x3b9;
+const CSR_PMPADDR10: u16 = 0x3ba;
+const CSR_PMPADDR11: u16 = 0x3bb;
+const CSR_PMPADDR12: u16 = 0x3bc;
+const CSR_PMPADDR13: u16 = 0x3bd;
+const CSR_PMPADDR14: u16 = 0x3be;
+const CSR_PMPADDR15: u16 = 0x3bf;
+const CSR_PMPADDR16: u16 = 0x3c0;
+const CSR_PMPADDR17: u16 = 0x3c1;
+const CSR_PMPADDR18: u16 = 0x3c2;
+const CSR_PMPADDR19: u16 = 0x3c3;
+const CSR_PMPADDR20: u16 = 0x3c4;
+const CSR_PMPADDR21: u16 = 0x3c5;
+const CSR_PMPADDR22: u16 = 0x3c6;
+const CSR_PMPADDR23: u16 = 0x3c7;
+const CSR_PMPADDR24: u16 = 0x3c8;
+const CSR_PMPADDR25: u16 = 0x3c9;
+const CSR_PMPADDR26: u16 = 0x3ca;
+const CSR_PMPADDR27: u16 = 0x3cb;
+const CSR_PMPADDR28: u16 = 0x3cc;
+const CSR_PMPADDR29: u16 = 0x3cd;
+const CSR_PMPADDR30: u16 = 0x3ce;
+const CSR_PMPADDR31: u16 = 0x3cf;
+const CSR_PMPADDR32: u16 = 0x3d0;
+const CSR_PMPADDR33: u16 = 0x3d1;
+const CSR_PMPADDR34: u16 = 0x3d2;
+const CSR_PMPADDR35: u16 = 0x3d3;
+const CSR_PMPADDR36: u16 = 0x3d4;
+const CSR_PMPADDR37: u16 = 0x3d5;
+const CSR_PMPADDR38: u16 = 0x3d6;
+const CSR_PMPADDR39: u16 = 0x3d7;
+const CSR_PMPADDR40: u16 = 0x3d8;
+const CSR_PMPADDR41: u16 = 0x3d9;
+const CSR_PMPADDR42: u16 = 0x3da;
+const CSR_PMPADDR43: u16 = 0x3db;
+const CSR_PMPADDR44: u16 = 0x3dc;
+const CSR_PMPADDR45: u16 = 0x3dd;
+const CSR_PMPADDR46: u16 = 0x3de;
+const CSR_PMPADDR47: u16 = 0x3df;
+const CSR_PMPADDR48: u16 = 0x3e0;
+const CSR_PMPADDR49: u16 = 0x3e1;
+const CSR_PMPADDR50: u16 = 0x3e2;
+const CSR_PMPADDR51: u16 = 0x3e3;
+const CSR_PMPADDR52: u16 = 0x3e4;
+const CSR_PMPADDR53: u16 = 0x3e5;
+const CSR_PMPADDR54: u16 = 0x3e6;
+const CSR_PMPADDR55: u16 = 0x3e7;
+const CSR_PMPADDR56: u16 = 0x3e8;
+const CSR_PMPADDR57: u16 = 0x3e9;
+const CSR_PMPADDR58: u16 = 0x3ea;
+const CSR_PMPADDR59: u16 = 0x3eb;
+const CSR_PMPADDR60: u16 = 0x3ec;
+const CSR_PMPADDR61: u16 = 0x3ed;
+const CSR_PMPADDR62: u16 = 0x3ee;
+const CSR_PMPADDR63: u16 = 0x3ef;
+const CSR_MSECCFG: u16 = 0x747;
+const CSR_TSELECT: u16 = 0x7a0;
+const CSR_TDATA1: u16 = 0x7a1;
+const CSR_TDATA2: u16 = 0x7a2;
+const CSR_TDATA3: u16 = 0x7a3;
+const CSR_TINFO: u16 = 0x7a4;
+const CSR_TCONTROL: u16 = 0x7a5;
+const CSR_MCONTEXT: u16 = 0x7a8;
+const CSR_MSCONTEXT: u16 = 0x7aa;
+const CSR_DCSR: u16 = 0x7b0;
+const CSR_DPC: u16 = 0x7b1;
+const CSR_DSCRATCH0: u16 = 0x7b2;
+const CSR_DSCRATCH1: u16 = 0x7b3;
+const CSR_MCYCLE: u16 = 0xb00;
+const CSR_MINSTRET: u16 = 0xb02;
+const CSR_MHPMCOUNTER3: u16 = 0xb03;
+const CSR_MHPMCOUNTER4: u16 = 0xb04;
+const CSR_MHPMCOUNTER5: u16 = 0xb05;
+const CSR_MHPMCOUNTER6: u16 = 0xb06;
+const CSR_MHPMCOUNTER7: u16 = 0xb07;
+const CSR_MHPMCOUNTER8: u16 = 0xb08;
+const CSR_MHPMCOUNTER9: u16 = 0xb09;
+const CSR_MHPMCOUNTER10: u16 = 0xb0a;
+const CSR_MHPMCOUNTER11: u16 = 0xb0b;
+const CSR_MHPMCOUNTER12: u16 = 0xb0c;
+const CSR_MHPMCOUNTER13: u16 = 0xb0d;
+const CSR_MHPMCOUNTER14: u16 = 0xb0e;
+const CSR_MHPMCOUNTER15: u16 = 0xb0f;
+const CSR_MHPMCOUNTER16: u16 = 0xb10;
+const CSR_MHPMCOUNTER17: u16 = 0xb11;
+const CSR_MHPMCOUNTER18: u16 = 0xb12;
+const CSR_MHPMCOUNTER19: u16 = 0xb13;
+const CSR_MHPMCOUNTER20: u16 = 0xb14;
+const CSR_MHPMCOUNTER21: u16 = 0xb15;
+const CSR_MHPMCOUNTER22: u16 = 0xb16;
+const CSR_MHPMCOUNTER23: u16 = 0xb17;
+const CSR_MHPMCOUNTER24: u16 = 0xb18;
+const CSR_MHPMCOUNTER25: u16 = 0xb19;
+const CSR_MHPMCOUNTER26: u16 = 0xb1a;
+const CSR_MHPMCOUNTER27: u16 = 0xb1b;
+const CSR_MHPMCOUNTER28: u16 = 0xb1c;
+const CSR_MHPMCOUNTER29: u16 = 0xb1d;
+const CSR_MHPMCOUNTER30: u16 = 0xb1e;
+const CSR_MHPMCOUNTER31: u16 = 0xb1f;
+const CSR_MCYCLECFG: u16 = 0x321;
+const CSR_MINSTRETCFG: u16 = 0x322;
+const CSR_MHPMEVENT3: u16 = 0x323;
+const CSR_MHPMEVENT4: u16 = 0x324;
+const CSR_MHPMEVENT5: u16 = 0x325;
+const CSR_MHPMEVENT6: u16 = 0x326;
+const CSR_MHPMEVENT7: u16 = 0x327;
+const CSR_MHPMEVENT8: u16 = 0x328;
+const CSR_MHPMEVENT9: u16 = 0x329;
+const CSR_MHPMEVENT10: u16 = 0x32a;
+const CSR_MHPMEVENT11: u16 = 0x32b;
+const CSR_MHPMEVENT12: u16 = 0x32c;
+const CSR_MHPMEVENT13: u16 = 0x32d;
+const CSR_MHPMEVENT14: u16 = 0x32e;
+const CSR_MHPMEVENT15: u16 = 0x32f;
+const CSR_MHPMEVENT16: u16 = 0x330;
+const CSR_MHPMEVENT17: u16 = 0x331;
+const CSR_MHPMEVENT18: u16 = 0x332;
+const CSR_MHPMEVENT19: u16 = 0x333;
+const CSR_MHPMEVENT20: u16 = 0x334;
+const CSR_MHPMEVENT21: u16 = 0x335;
+const CSR_MHPMEVENT22: u16 = 0x336;
+const CSR_MHPMEVENT23: u16 = 0x337;
+const CSR_MHPMEVENT24: u16 = 0x338;
+const CSR_MHPMEVENT25: u16 = 0x339;
+const CSR_MHPMEVENT26: u16 = 0x33a;
+const CSR_MHPMEVENT27: u16 = 0x33b;
+const CSR_MHPMEVENT28: u16 = 0x33c;
+const CSR_MHPMEVENT29: u16 = 0x33d;
+const CSR_MHPMEVENT30: u16 = 0x33e;
+const CSR_MHPMEVENT31: u16 = 0x33f;
+const CSR_MVENDORID: u16 = 0xf11;
+const CSR_MARCHID: u16 = 0xf12;
+const CSR_MIMPID: u16 = 0xf13;
+const CSR_MHARTID: u16 = 0xf14;
+const CSR_MCONFIGPTR: u16 = 0xf15;
+const CSR_MTOPI: u16 = 0xfb0;
+const CSR_SIEH: u16 = 0x114;
+const CSR_SIPH: u16 = 0x154;
+const CSR_STIMECMPH: u16 = 0x15d;
+const CSR_VSIEH: u16 = 0x214;
+const CSR_VSIPH: u16 = 0x254;
+const CSR_VSTIMECMPH: u16 = 0x25d;
+const CSR_HTIMEDELTAH: u16 = 0x615;
+const CSR_HIDELEGH: u16 = 0x613;
+const CSR_HVIENH: u16 = 0x618;
+const CSR_HENVCFGH: u16 = 0x61a;
+const CSR_HVIPH: u16 = 0x655;
+const CSR_HVIPRIO1H: u16 = 0x656;
+const CSR_HVIPRIO2H: u16 = 0x657;
+const CSR_HSTATEEN0H: u16 = 0x61c;
+const CSR_HSTATEEN1H: u16 = 0x61d;
+const CSR_HSTATEEN2H: u16 = 0x61e;
+const CSR_HSTATEEN3H: u16 = 0x61f;
+const CSR_CYCLEH: u16 = 0xc80;
+const CSR_TIMEH: u16 = 0xc81;
+const CSR_INSTRETH: u16 = 0xc82;
+const CSR_HPMCOUNTER3H: u16 = 0xc83;
+const CSR_HPMCOUNTER4H: u16 = 0xc84;
+const CSR_HPMCOUNTER5H: u16 = 0xc85;
+const CSR_HPMCOUNTER6H: u16 = 0xc86;
+const CSR_HPMCOUNTER7H: u16 = 0xc87;
+const CSR_HPMCOUNTER8H: u16 = 0xc88;
+const CSR_HPMCOUNTER9H: u16 = 0xc89;
+const CSR_HPMCOUNTER10H: u16 = 0xc8a;
+const CSR_HPMCOUNTER11H: u16 = 0xc8b;
+const CSR_HPMCOUNTER12H: u16 = 0xc8c;
+const CSR_HPMCOUNTER13H: u16 = 0xc8d;
+const CSR_HPMCOUNTER14H: u16 = 0xc8e;
+const CSR_HPMCOUNTER15H: u16 = 0xc8f;
+const CSR_HPMCOUNTER16H: u16 = 0xc90;
+const CSR_HPMCOUNTER17H: u16 = 0xc91;
+const CSR_HPMCOUNTER18H: u16 = 0xc92;
+const CSR_HPMCOUNTER19H: u16 = 0xc93;
+const CSR_HPMCOUNTER20H: u16 = 0xc94;
+const CSR_HPMCOUNTER21H: u16 = 0xc95;
+const CSR_HPMCOUNTER22H: u16 = 0xc96;
+const CSR_HPMCOUNTER23H: u16 = 0xc97;
+const CSR_HPMCOUNTER24H: u16 = 0xc98;
+const CSR_HPMCOUNTER25H: u16 = 0xc99;
+const CSR_HPMCOUNTER26H: u16 = 0xc9a;
+const CSR_HPMCOUNTER27H: u16 = 0xc9b;
+const CSR_HPMCOUNTER28H: u16 = 0xc9c;
+const CSR_HPMCOUNTER29H: u16 = 0xc9d;
+const CSR_HPMCOUNTER30H: u16 = 0xc9e;
+const CSR_HPMCOUNTER31H: u16 = 0xc9f;
+const CSR_MSTATUSH: u16 = 0x310;
+const CSR_MIDELEGH: u16 = 0x313;
+const CSR_MIEH: u16 = 0x314;
+const CSR_MVIENH: u16 = 0x318;
+const CSR_MVIPH: u16 = 0x319;
+const CSR_MENVCFGH: u16 = 0x31a;
+const CSR_MSTATEEN0H: u16 = 0x31c;
+const CSR_MSTATEEN1H: u16 = 0x31d;
+const CSR_MSTATEEN2H: u16 = 0x31e;
+const CSR_MSTATEEN3H: u16 = 0x31f;
+const CSR_MIPH: u16 = 0x354;
+const CSR_MCYCLECFGH: u16 = 0x721;
+const CSR_MINSTRETCFGH: u16 = 0x722;
+const CSR_MHPMEVENT3H: u16 = 0x723;
+const CSR_MHPMEVENT4H: u16 = 0x724;
+const CSR_MHPMEVENT5H: u16 = 0x725;
+const CSR_MHPMEVENT6H: u16 = 0x726;
+const CSR_MHPMEVENT7H: u16 = 0x727;
+const CSR_MHPMEVENT8H: u16 = 0x728;
+const CSR_MHPMEVENT9H: u16 = 0x729;
+const CSR_MHPMEVENT10H: u16 = 0x72a;
+const CSR_MHPMEVENT11H: u16 = 0x72b;
+const CSR_MHPMEVENT12H: u16 = 0x72c;
+const CSR_MHPMEVENT13H: u16 = 0x72d;
+const CSR_MHPMEVENT14H: u16 = 0x72e;
+const CSR_MHPMEVENT15H: u16 = 0x72f;
+const CSR_MHPMEVENT16H: u16 = 0x730;
+const CSR_MHPMEVENT17H: u16 = 0x731;
+const CSR_MHPMEVENT18H: u16 = 0x732;
+const CSR_MHPMEVENT19H: u16 = 0x733;
+const CSR_MHPMEVENT20H: u16 = 0x734;
+const CSR_MHPMEVENT21H: u16 = 0x735;
+const CSR_MHPMEVENT22H: u16 = 0x736;
+const CSR_MHPMEVENT23H: u16 = 0x737;
+const CSR_MHPMEVENT24H: u16 = 0x738;
+const CSR_MHPMEVENT25H: u16 = 0x739;
+const CSR_MHPMEVENT26H: u16 = 0x73a;
+const CSR_MHPMEVENT27H: u16 = 0x73b;
+const CSR_MHPMEVENT28H: u16 = 0x73c;
+const CSR_MHPMEVENT29H: u16 = 0x73d;
+const CSR_MHPMEVENT30H: u16 = 0x73e;
+const CSR_MHPMEVENT31H: u16 = 0x73f;
+const CSR_MNSCRATCH: u16 = 0x740;
+const CSR_MNEPC: u16 = 0x741;
+const CSR_MNCAUSE: u16 = 0x742;
+const CSR_MNSTATUS: u16 = 0x744;
+const CSR_MSECCFGH: u16 = 0x757;
+const CSR_MCYCLEH: u16 = 0xb80;
+const CSR_MINSTRETH: u16 = 0xb82;
+const CSR_MHPMCOUNTER3H: u16 = 0xb83;
+const CSR_MHPMCOUNTER4H: u16 = 0xb84;
+const CSR_MHPMCOUNTER5H: u16 = 0xb85;
+const CSR_MHPMCOUNTER6H: u16 = 0xb86;
+const CSR_MHPMCOUNTER7H: u16 = 0xb87;
+const CSR_MHPMCOUNTER8H: u16 = 0xb88;
+const CSR_MHPMCOUNTER9H: u16 = 0xb89;
+const CSR_MHPMCOUNTER10H: u16 = 0xb8a;
+const CSR_MHPMCOUNTER11H: u16 = 0xb8b;
+const CSR_MHPMCOUNTER12H: u16 = 0xb8c;
+const CSR_MHPMCOUNTER13H: u16 = 0xb8d;
+const CSR_MHPMCOUNTER14H: u16 = 0xb8e;
+const CSR_MHPMCOUNTER15H: u16 = 0xb8f;
+const CSR_MHPMCOUNTER16H: u16 = 0xb90;
+const CSR_MHPMCOUNTER17H: u16 = 0xb91;
+const CSR_MHPMCOUNTER18H: u16 = 0xb92;
+const CSR_MHPMCOUNTER19H: u16 = 0xb93;
+const CSR_MHPMCOUNTER20H: u16 = 0xb94;
+const CSR_MHPMCOUNTER21H: u16 = 0xb95;
+const CSR_MHPMCOUNTER22H: u16 = 0xb96;
+const CSR_MHPMCOUNTER23H: u16 = 0xb97;
+const CSR_MHPMCOUNTER24H: u16 = 0xb98;
+const CSR_MHPMCOUNTER25H: u16 = 0xb99;
+const CSR_MHPMCOUNTER26H: u16 = 0xb9a;
+const CSR_MHPMCOUNTER27H: u16 = 0xb9b;
+const CSR_MHPMCOUNTER28H: u16 = 0xb9c;
+const CSR_MHPMCOUNTER29H: u16 = 0xb9d;
+const CSR_MHPMCOUNTER30H: u16 = 0xb9e;
+const CSR_MHPMCOUNTER31H: u16 = 0xb9f;
 // const CAUSE_MISALIGNED_FETCH: u8 = 0x0;
 // const CAUSE_FETCH_ACCESS: u8 = 0x1;
 // const CAUSE_ILLEGAL_INSTRUCTION: u8 = 0x2;
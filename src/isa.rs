@@ -0,0 +1,159 @@
+use std::fmt;
+
+// Extensions rubbler knows how to assemble
+const SUPPORTED_EXTENSIONS: &[&str] = &["i", "m", "a", "f", "d", "c", "zicsr", "zifencei"];
+
+// Compressed instructions change the size of the output, so they are left out unless asked for
+const DEFAULT_EXTENSIONS: &[&str] = &["i", "m", "a", "f", "d", "zicsr", "zifencei"];
+
+// Extensions that bring in another one, e.g. D builds on the F registers
+const IMPLIED_EXTENSIONS: &[(&str, &str)] = &[("d", "f"), ("f", "zicsr")];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Xlen {
+    Rv32,
+    Rv64,
+}
+
+/// Target ISA, e.g. `rv32i`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Isa {
+    pub xlen: Xlen,
+    extensions: Vec<String>,
+}
+
+impl Default for Isa {
+    fn default() -> Isa {
+        Isa {
+            xlen: Xlen::Rv32,
+            extensions: DEFAULT_EXTENSIONS.iter().map(|e| e.to_string()).collect(),
+        }
+    }
+}
+
+impl Isa {
+    /// Parse an ISA string as accepted by `-march`
+    pub fn parse(isa_string: &str) -> Result<Isa, String> {
+        let isa_string = isa_string.to_ascii_lowercase();
+        let (xlen, rest) = if let Some(rest) = isa_string.strip_prefix("rv32") {
+            (Xlen::Rv32, rest)
+        } else if let Some(rest) = isa_string.strip_prefix("rv64") {
+            (Xlen::Rv64, rest)
+        } else {
+            return Err(format!(
+                "ISA string '{isa_string}' must start with rv32 or rv64"
+            ));
+        };
+        // Single letter extensions come first, multi-letter ones are separated by '_'
+        let mut parts = rest.split('_');
+        let single = parts.next().unwrap_or("");
+        let mut names = vec![];
+        let mut chars = single.chars().peekable();
+        match chars.next() {
+            Some('i') => names.push("i".to_string()),
+            Some('g') => {
+                for name in ["i", "m", "a", "f", "d", "zicsr", "zifencei"] {
+                    names.push(name.to_string());
+                }
+            }
+            _ => return Err(format!("ISA string '{isa_string}' must have base i or g")),
+        }
+        Self::skip_version(&mut chars);
+        while let Some(c) = chars.next() {
+            if !c.is_ascii_alphabetic() {
+                return Err(format!("Unexpected '{c}' in ISA string '{isa_string}'"));
+            }
+            names.push(c.to_string());
+            Self::skip_version(&mut chars);
+        }
+        for part in parts.filter(|p| !p.is_empty()) {
+            let name = part.trim_end_matches(|c: char| c.is_ascii_digit() || c == 'p');
+            names.push(name.to_string());
+        }
+
+        let mut extensions: Vec<String> = vec![];
+        for name in names {
+            if !SUPPORTED_EXTENSIONS.contains(&name.as_str()) {
+                return Err(format!("Extension '{name}' is not supported"));
+            }
+            if !extensions.contains(&name) {
+                extensions.push(name);
+            }
+        }
+        for (extension, implied) in IMPLIED_EXTENSIONS {
+            if extensions.iter().any(|e| e == extension) && !extensions.iter().any(|e| e == implied)
+            {
+                extensions.push(implied.to_string());
+            }
+        }
+        Ok(Isa { xlen, extensions })
+    }
+
+    pub fn has(&self, extension: &str) -> bool {
+        self.extensions.iter().any(|e| e == extension)
+    }
+
+    // Version numbers such as `2p1` are accepted but ignored
+    fn skip_version(chars: &mut std::iter::Peekable<std::str::Chars>) {
+        while let Some(c) = chars.peek() {
+            if c.is_ascii_digit() {
+                chars.next();
+            } else if *c == 'p' {
+                let mut lookahead = chars.clone();
+                lookahead.next();
+                if !matches!(lookahead.peek(), Some(c) if c.is_ascii_digit()) {
+                    break;
+                }
+                chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+impl fmt::Display for Isa {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.xlen {
+            Xlen::Rv32 => write!(f, "rv32")?,
+            Xlen::Rv64 => write!(f, "rv64")?,
+        }
+        for extension in self.extensions.iter().filter(|e| e.len() == 1) {
+            write!(f, "{extension}")?;
+        }
+        for extension in self.extensions.iter().filter(|e| e.len() > 1) {
+            write!(f, "_{extension}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Isa;
+    use super::Xlen;
+
+    #[test]
+    fn parse() {
+        let isa = Isa::parse("RV32I").unwrap();
+        assert_eq!(isa.xlen, Xlen::Rv32);
+        assert!(isa.has("i"));
+        assert_eq!(isa.to_string(), "rv32i");
+        assert_eq!(Isa::parse("rv32i2p1").unwrap(), isa);
+        assert!(Isa::parse("rv32").is_err());
+        assert_eq!(
+            Isa::parse("rv64imacv").unwrap_err(),
+            "Extension 'v' is not supported"
+        );
+        assert_eq!(Isa::parse("rv64ima").unwrap().xlen, Xlen::Rv64);
+        assert!(Isa::parse("x86").is_err());
+        assert!(Isa::parse("rv32i_xfoo").is_err());
+        assert!(Isa::parse("rv32i_zicsr_zifencei").unwrap().has("zicsr"));
+        assert_eq!(Isa::parse("rv32im").unwrap().to_string(), "rv32im");
+        let isa = Isa::parse("rv32imd").unwrap();
+        assert!(isa.has("f") && isa.has("zicsr"));
+        assert_eq!(isa.to_string(), "rv32imdf_zicsr");
+        assert!(Isa::parse("rv32imc").unwrap().has("c"));
+        assert!(!Isa::default().has("c"));
+    }
+}
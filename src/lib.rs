@@ -1,86 +1,586 @@
+// Instruction encodings are written with their bits grouped by field
+#![allow(clippy::unusual_byte_groupings)]
+
+mod assembler;
+mod compressed;
+mod disassembler;
+mod elf;
+pub mod error;
+mod expr;
 mod inst;
+pub mod isa;
+pub mod object;
+pub mod parser;
+mod pseudo;
 mod reg;
-mod scanner;
+#[cfg(test)]
+mod round_trip;
+pub mod scanner;
 
+pub use assembler::assemble;
+pub use assembler::assemble_object;
+pub use assembler::assemble_with_options;
+pub use assembler::Options;
+pub use disassembler::disassemble_word;
+pub use disassembler::disassemble_word_for;
+pub use disassembler::disassemble_word_with;
+pub use error::AssembleError;
+pub use error::DisassembleError;
+pub use error::Span;
+use expr::evaluate_expr;
+use expr::is_address;
+use expr::symbol_offset;
+use expr::Symbols;
 use inst::*;
+pub use isa::Isa;
+use isa::Xlen;
+use object::Relocation;
+use object::RelocationKind;
+use parser::ast::*;
+pub use reg::RegisterNames;
 use reg::*;
-use regex::Regex;
 use std::ffi::c_char;
 use std::ffi::CStr;
 
+/// # Safety
+///
+/// `asm_line` must point to a valid, NUL-terminated C string.
 #[no_mangle]
-pub extern "C" fn decode_asm_line_ffi(asm_line: *const c_char) -> u32 {
+pub unsafe extern "C" fn decode_asm_line_ffi(asm_line: *const c_char) -> u32 {
     let c_str = unsafe { CStr::from_ptr(asm_line) };
     decode_asm_line(c_str.to_str().unwrap()).unwrap()
 }
 
-pub fn decode_asm_line(asm_line: &str) -> Result<u32, &str> {
+pub fn decode_asm_line(asm_line: &str) -> Result<u32, AssembleError> {
     // Grab lines and ensure it only contains one line
     let lines: Vec<&str> = asm_line.lines().collect();
     if lines.len() != 1 {
-        return Err("Found multiple lines");
+        return Err(AssembleError::Syntax {
+            description: "Expected exactly one line".to_string(),
+            lexeme: "".to_string(),
+            span: Span {
+                line: lines.len().min(2),
+                column: 1,
+                length: 1,
+            },
+        });
     }
 
-    // Grab instruction string
-    let mut tokens: Vec<&str> = lines[0].split(' ').collect();
-    if tokens.len() == 0 {
-        return Err("Line empty");
+    // Scan and parse the line
+    let tokens = scanner::Scanner::new(lines[0].to_string()).scan_tokens()?;
+    let program = parser::Parser::new(tokens).parse()?;
+
+    // Grab instruction
+    let Some((statement, mnemonic, operands)) =
+        program.statements.iter().find_map(|s| match &s.kind {
+            StatementKind::Instruction { mnemonic, operands } => Some((s, mnemonic, operands)),
+            _ => None,
+        })
+    else {
+        return Err(AssembleError::Syntax {
+            description: "Expected an instruction".to_string(),
+            lexeme: "".to_string(),
+            span: Span {
+                line: 1,
+                column: 1,
+                length: lines[0].chars().count().max(1),
+            },
+        });
+    };
+
+    let mut context = Context {
+        pc: 0,
+        section: 0,
+        symbols: &Symbols::default(),
+        relocations: None,
+        isa: &Isa::default(),
+        compress: false,
+    };
+    let words = pseudo::encode_statement(mnemonic, statement.span, operands, &mut context)?;
+    if words.len() != 1 {
+        return Err(AssembleError::Syntax {
+            description: format!("Expands to {} instructions", words.len()),
+            lexeme: mnemonic.clone(),
+            span: statement.span,
+        });
     }
-    let inst_string = tokens[0];
-    tokens.remove(0);
+    Ok(words[0])
+}
 
-    // Clone and sort INSTRUCTIONS by asm_string
-    let mut instructions = INSTRUCTIONS;
-    instructions.sort_by_key(|i| i.asm_string);
+// Where an instruction is encoded and how its symbols are resolved
+struct Context<'a> {
+    pc: u32,
+    // Index of the section the instruction is placed in
+    section: usize,
+    symbols: &'a Symbols,
+    // Undefined branch and jump targets are left to the linker when set
+    relocations: Option<&'a mut Vec<Relocation>>,
+    isa: &'a Isa,
+    // Replace eligible instructions by their compressed form
+    compress: bool,
+}
 
-    // Find instruction
-    let inst_idx = match instructions.binary_search_by_key(&inst_string, |i| i.asm_string) {
-        Ok(i) => i,
-        Err(_) => return Err("Invalid instruction"),
+fn encode_instruction(
+    mnemonic: &str,
+    mnemonic_span: Span,
+    operands: &[Operand],
+    context: &mut Context,
+) -> Result<u32, AssembleError> {
+    if compressed::find_compressed(mnemonic).is_some() {
+        return compressed::encode_compressed(mnemonic, mnemonic_span, operands, context);
+    }
+
+    // Find instruction, atomics may carry an ordering suffix such as `.aqrl`
+    let (base_mnemonic, ordering) = ORDERINGS
+        .iter()
+        .find_map(|(suffix, bits)| mnemonic.strip_suffix(suffix).map(|m| (m, *bits)))
+        .unwrap_or((mnemonic, 0));
+    let Some((set, inst)) =
+        find_instruction(base_mnemonic).filter(|(set, _)| ordering == 0 || set.extension == "a")
+    else {
+        return Err(AssembleError::UnknownMnemonic {
+            mnemonic: mnemonic.to_string(),
+            span: mnemonic_span,
+        });
     };
-    let inst = &instructions[inst_idx];
+    if !context.isa.has(set.extension) {
+        return Err(AssembleError::MissingExtension {
+            mnemonic: mnemonic.to_string(),
+            extension: set.extension.to_string(),
+            span: mnemonic_span,
+        });
+    }
+    if set.xlen.is_some_and(|xlen| xlen != context.isa.xlen) {
+        return Err(AssembleError::Rv64Only {
+            mnemonic: mnemonic.to_string(),
+            span: mnemonic_span,
+        });
+    }
 
     // Set instruction bits
-    let mut inst_bits: u32 = inst.opcode_func;
+    let mut inst_bits: u32 = inst.opcode_func | ordering;
 
-    // Parse arguments
-    if inst.num_of_arguments != tokens.len() {
-        return Err("Wrong number of arguments");
+    // Parse arguments, floating-point computations may end with a rounding mode
+    let all_operands = operands;
+    let (operands, rounding_mode) = match operands.split_last() {
+        Some((last, rest))
+            if takes_rounding_mode(inst) && operands.len() == inst.num_of_arguments + 1 =>
+        {
+            (rest, rounding_mode(last)?)
+        }
+        _ => (operands, default_rounding_mode(inst)),
+    };
+    if takes_rounding_mode(inst) {
+        inst_bits |= rounding_mode << 12;
+    }
+    if inst.num_of_arguments != operands.len() {
+        return Err(AssembleError::WrongOperandCount {
+            mnemonic: mnemonic.to_string(),
+            expected: inst.num_of_arguments,
+            found: operands.len(),
+            span: mnemonic_span,
+        });
     }
-    for (arg, token) in inst.arguments.iter().zip(tokens.iter()) {
+    for (arg, operand) in inst.arguments.iter().zip(operands.iter()) {
         match arg {
-            AsmArgs::RegSrc1 => set_reg(&mut inst_bits, token, RegFunc::Src1)?,
-            AsmArgs::RegSrc2 => set_reg(&mut inst_bits, token, RegFunc::Src2)?,
-            AsmArgs::RegDest => set_reg(&mut inst_bits, token, RegFunc::Dest)?,
-            AsmArgs::Imm => set_imm(&mut inst_bits, token, &inst.inst_type)?,
-            AsmArgs::Mem => set_mem(&mut inst_bits, token, &inst.inst_type)?,
+            AsmArgs::RegSrc1 => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Src1,
+                &REG_FILE,
+            )?,
+            AsmArgs::RegSrc2 => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Src2,
+                &REG_FILE,
+            )?,
+            AsmArgs::RegDest => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Dest,
+                &REG_FILE,
+            )?,
+            AsmArgs::FRegSrc1 => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Src1,
+                &F_REG_FILE,
+            )?,
+            AsmArgs::FRegSrc2 => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Src2,
+                &F_REG_FILE,
+            )?,
+            AsmArgs::FRegSrc3 => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Src3,
+                &F_REG_FILE,
+            )?,
+            AsmArgs::FRegDest => set_reg(
+                &mut inst_bits,
+                operand_register(operand)?,
+                operand.span,
+                RegFunc::Dest,
+                &F_REG_FILE,
+            )?,
+            AsmArgs::Imm => {
+                let OperandKind::Expression(expr) = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected an immediate"));
+                };
+                let imm = resolve_imm(expr, operand, &inst.inst_type, context)?;
+                check_imm(imm, operand, &inst.inst_type)?;
+                set_imm(&mut inst_bits, imm, &inst.inst_type)
+            }
+            AsmArgs::Shamt | AsmArgs::ShamtW => {
+                let OperandKind::Expression(expr) = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected a shift amount"));
+                };
+                let shamt = evaluate_expr(expr, operand, context.symbols)?;
+                let max = match (arg, context.isa.xlen) {
+                    (AsmArgs::Shamt, Xlen::Rv64) => SHAMT_MAX_RV64,
+                    _ => SHAMT_MAX,
+                };
+                if !(0..=max).contains(&shamt) {
+                    return Err(AssembleError::ImmediateOutOfRange {
+                        lexeme: operand.lexeme.clone(),
+                        value: shamt,
+                        min: 0,
+                        max,
+                        span: operand.span,
+                    });
+                }
+                // Only the shamt field is set so that funct7 is left untouched
+                inst_bits |= (shamt as u32) << 20;
+            }
+            AsmArgs::Mem => {
+                let OperandKind::Memory { offset, base } = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected a memory operand"));
+                };
+                set_reg(&mut inst_bits, base, operand.span, RegFunc::Src1, &REG_FILE)?;
+                let imm = match offset {
+                    Some(expr) => resolve_imm(expr, operand, &inst.inst_type, context)?,
+                    None => 0,
+                };
+                check_imm(imm, operand, &inst.inst_type)?;
+                set_imm(&mut inst_bits, imm, &inst.inst_type)
+            }
+            AsmArgs::Pred => inst_bits |= fence_set(operand)? << 24,
+            AsmArgs::Succ => inst_bits |= fence_set(operand)? << 20,
+            AsmArgs::Csr => inst_bits |= csr_address(operand, context.symbols)? << 20,
+            AsmArgs::Uimm => {
+                let OperandKind::Expression(expr) = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected an immediate"));
+                };
+                let uimm = evaluate_expr(expr, operand, context.symbols)?;
+                if !(0..=UIMM_MAX).contains(&uimm) {
+                    return Err(AssembleError::ImmediateOutOfRange {
+                        lexeme: operand.lexeme.clone(),
+                        value: uimm,
+                        min: 0,
+                        max: UIMM_MAX,
+                        span: operand.span,
+                    });
+                }
+                inst_bits |= (uimm as u32) << 15;
+            }
+            AsmArgs::Addr => {
+                let OperandKind::Memory { offset, base } = &operand.kind else {
+                    return Err(invalid_operand(operand, "Expected an address such as (a0)"));
+                };
+                if !matches!(offset, None | Some(Expr::Number(0))) {
+                    return Err(invalid_operand(operand, "Atomics take no address offset"));
+                }
+                set_reg(&mut inst_bits, base, operand.span, RegFunc::Src1, &REG_FILE)?;
+            }
             AsmArgs::NoArg => break,
         }
     }
 
+    // Instructions left to the linker keep their full size
+    let relocated = context
+        .relocations
+        .as_ref()
+        .and_then(|r| r.last())
+        .is_some_and(|r| r.offset == context.pc as u64);
+    if context.compress && !relocated {
+        if let Some(compressed_bits) = compressed::compress(mnemonic, all_operands, context) {
+            return Ok(compressed_bits);
+        }
+    }
     Ok(inst_bits)
 }
 
-fn set_mem(
-    inst_bits: &mut u32,
-    mem_string: &str,
+fn operand_register(operand: &Operand) -> Result<&str, AssembleError> {
+    match &operand.kind {
+        OperandKind::Register(reg) => Ok(reg),
+        _ => Err(AssembleError::InvalidRegister {
+            lexeme: operand.lexeme.clone(),
+            span: operand.span,
+        }),
+    }
+}
+
+// CSR names take precedence over symbols of the same name
+fn csr_address(operand: &Operand, symbols: &Symbols) -> Result<u32, AssembleError> {
+    let OperandKind::Expression(expr) = &operand.kind else {
+        return Err(invalid_operand(operand, "Expected a CSR"));
+    };
+    if let Expr::Symbol(name) = expr {
+        if let Some((_, address)) = CSR_NAMES.iter().find(|(csr, _)| csr == name) {
+            return Ok(*address as u32);
+        }
+    }
+    let address = evaluate_expr(expr, operand, symbols)?;
+    if !(0..=0xFFF).contains(&address) {
+        return Err(AssembleError::ImmediateOutOfRange {
+            lexeme: operand.lexeme.clone(),
+            value: address,
+            min: 0,
+            max: 0xFFF,
+            span: operand.span,
+        });
+    }
+    Ok(address as u32)
+}
+
+fn rounding_mode(operand: &Operand) -> Result<u32, AssembleError> {
+    match &operand.kind {
+        OperandKind::Expression(Expr::Symbol(name)) => ROUNDING_MODES
+            .iter()
+            .find(|(mode, _)| mode == name)
+            .map(|(_, bits)| *bits)
+            .ok_or_else(|| invalid_operand(operand, "Expected a rounding mode")),
+        _ => Err(invalid_operand(operand, "Expected a rounding mode")),
+    }
+}
+
+// Bits of a fence set such as `rw`, `0` stands for the empty set
+fn fence_set(operand: &Operand) -> Result<u32, AssembleError> {
+    let name = match &operand.kind {
+        OperandKind::Expression(Expr::Symbol(name)) => name.as_str(),
+        OperandKind::Expression(Expr::Number(0)) => return Ok(0),
+        _ => {
+            return Err(invalid_operand(
+                operand,
+                "Expected a fence set of i, o, r and w",
+            ))
+        }
+    };
+    let mut bits = 0;
+    for access in name.chars() {
+        let Some(position) = FENCE_ACCESSES.iter().position(|&a| a == access) else {
+            return Err(invalid_operand(
+                operand,
+                "Expected a fence set of i, o, r and w",
+            ));
+        };
+        let bit = 1 << (FENCE_ACCESSES.len() - 1 - position);
+        if bits & bit != 0 {
+            return Err(invalid_operand(operand, "Access type given more than once"));
+        }
+        bits |= bit;
+    }
+    Ok(bits)
+}
+
+fn invalid_operand(operand: &Operand, description: &str) -> AssembleError {
+    AssembleError::InvalidOperand {
+        description: description.to_string(),
+        lexeme: operand.lexeme.clone(),
+        span: operand.span,
+    }
+}
+
+fn resolve_imm(
+    expr: &Expr,
+    operand: &Operand,
     inst_type: &InstructionType,
-) -> Result<(), &'static str> {
-    let re = Regex::new(r"(?<imm>.+)\((?<reg>\w+)\)").unwrap();
-    let Some(captures) = re.captures(mem_string) else {
-        return Err("Parse failed");
+    context: &mut Context,
+) -> Result<i64, AssembleError> {
+    if let Expr::Relocation { function, argument } = expr {
+        return resolve_relocation(function, argument, operand, inst_type, context);
+    }
+    // Branch and jump targets given as labels are encoded relative to the instruction
+    let relocation_kind = match inst_type {
+        InstructionType::B => RelocationKind::Branch,
+        InstructionType::J => RelocationKind::Jal,
+        _ => return evaluate_expr(expr, operand, context.symbols),
     };
-    set_reg(inst_bits, &captures["reg"], RegFunc::Src1)?;
-    set_imm(inst_bits, &captures["imm"], inst_type)?;
-    Ok(())
+    if !is_address(expr, context.symbols) {
+        return evaluate_expr(expr, operand, context.symbols);
+    }
+    if relocate(expr, operand, relocation_kind, context) {
+        return Ok(0);
+    }
+    Ok(evaluate_expr(expr, operand, context.symbols)? - context.pc as i64)
 }
 
-fn set_imm(
-    inst_bits: &mut u32,
-    imm_string: &str,
+// Value of `%function(argument)`. The upper parts are rounded up whenever the sign-extended
+// lower part is negative, so that adding the two gives back the value.
+fn resolve_relocation(
+    function: &str,
+    argument: &Expr,
+    operand: &Operand,
     inst_type: &InstructionType,
-) -> Result<(), &'static str> {
-    let imm = imm_string_to_i32(imm_string)?;
+    context: &mut Context,
+) -> Result<i64, AssembleError> {
+    let kind = match (function, inst_type) {
+        ("hi", InstructionType::U) => RelocationKind::Hi20,
+        ("lo", InstructionType::I) => RelocationKind::Lo12I,
+        ("lo", InstructionType::S) => RelocationKind::Lo12S,
+        ("pcrel_hi", InstructionType::U) => RelocationKind::PcrelHi20,
+        ("pcrel_lo", InstructionType::I) => RelocationKind::PcrelLo12I,
+        ("pcrel_lo", InstructionType::S) => RelocationKind::PcrelLo12S,
+        ("hi" | "pcrel_hi", _) => {
+            return Err(invalid_operand(
+                operand,
+                &format!("%{function} is only accepted by lui and auipc"),
+            ))
+        }
+        ("lo" | "pcrel_lo", _) => {
+            return Err(invalid_operand(
+                operand,
+                &format!("%{function} is only accepted by I and S-type instructions"),
+            ))
+        }
+        _ => return Err(invalid_operand(operand, "Unknown relocation function")),
+    };
+    let value = match kind {
+        RelocationKind::PcrelLo12I | RelocationKind::PcrelLo12S => {
+            // The argument labels the auipc, whose target this is the lower part of
+            let Expr::Symbol(label) = argument else {
+                return Err(invalid_operand(operand, "Expected the label of an auipc"));
+            };
+            let label = label.clone();
+            let Some(&auipc) = context.symbols.labels.get(&label) else {
+                return Err(AssembleError::UndefinedSymbol {
+                    symbol: label,
+                    span: operand.span,
+                });
+            };
+            let Some(target) = context.symbols.pcrel_hi.get(&auipc) else {
+                return Err(invalid_operand(
+                    operand,
+                    "Label does not refer to an auipc with %pcrel_hi",
+                ));
+            };
+            let OperandKind::Expression(expr) = &target.kind else {
+                return Err(invalid_operand(target, "Expected an address"));
+            };
+            if let Some((name, _)) = symbol_offset(expr, target, context.symbols) {
+                if is_relocated(&name, RelocationKind::PcrelHi20, context) {
+                    if let Some(relocations) = context.relocations.as_mut() {
+                        relocations.push(Relocation {
+                            offset: context.pc as u64,
+                            kind,
+                            symbol: label,
+                            addend: 0,
+                        });
+                    }
+                    return Ok(0);
+                }
+            }
+            evaluate_expr(expr, target, context.symbols)? - auipc as i64
+        }
+        _ => {
+            if relocate(argument, operand, kind, context) {
+                return Ok(0);
+            }
+            let value = evaluate_expr(argument, operand, context.symbols)?;
+            if kind == RelocationKind::PcrelHi20 {
+                value - context.pc as i64
+            } else {
+                value
+            }
+        }
+    };
+    let lo = ((value as i32) << 20) >> 20;
+    match kind {
+        RelocationKind::Hi20 | RelocationKind::PcrelHi20 => {
+            Ok(upper_immediate((value as i32).wrapping_sub(lo) as i64))
+        }
+        _ => Ok(lo as i64),
+    }
+}
+
+// Leave a reference to `expr` to the linker when it is a symbol whose address is not known yet,
+// plus or minus a constant
+fn relocate(expr: &Expr, operand: &Operand, kind: RelocationKind, context: &mut Context) -> bool {
+    let Some((name, addend)) = symbol_offset(expr, operand, context.symbols) else {
+        return false;
+    };
+    if !is_relocated(&name, kind, context) {
+        return false;
+    }
+    let Some(relocations) = context.relocations.as_mut() else {
+        return false;
+    };
+    relocations.push(Relocation {
+        offset: context.pc as u64,
+        kind,
+        symbol: name,
+        addend,
+    });
+    true
+}
+
+// Whether a `kind` reference to `name` is left to the linker when producing an object. Besides
+// undefined symbols, that is any label whose address depends on where its section is placed.
+fn is_relocated(name: &str, kind: RelocationKind, context: &Context) -> bool {
+    if context.relocations.is_none() {
+        return false;
+    }
+    if !context.symbols.is_defined(name) {
+        return true;
+    }
+    match context.symbols.sections.get(name) {
+        Some(&section) => !kind.is_pc_relative() || section != context.section,
+        None => false,
+    }
+}
+
+// 20-bit field of lui and auipc that loads bits 31:12 of `value`
+fn upper_immediate(value: i64) -> i64 {
+    (value >> 12) & 0xFFFFF
+}
+
+fn check_imm(
+    imm: i64,
+    operand: &Operand,
+    inst_type: &InstructionType,
+) -> Result<(), AssembleError> {
+    let (min, max) = inst_type.imm_range();
+    if imm < min || imm > max {
+        return Err(AssembleError::ImmediateOutOfRange {
+            lexeme: operand.lexeme.clone(),
+            value: imm,
+            min,
+            max,
+            span: operand.span,
+        });
+    }
+    let alignment = inst_type.imm_alignment();
+    if imm % alignment != 0 {
+        return Err(AssembleError::MisalignedImmediate {
+            lexeme: operand.lexeme.clone(),
+            value: imm,
+            alignment,
+            span: operand.span,
+        });
+    }
+    Ok(())
+}
+
+fn set_imm(inst_bits: &mut u32, imm: i64, inst_type: &InstructionType) {
     let imm = imm as u32;
     match inst_type {
         InstructionType::I => *inst_bits |= (imm & 0xFFF) << 20,
@@ -97,8 +597,7 @@ fn set_imm(
             *inst_bits |= (imm_12 << 31) + (imm_10_5 << 25) + (imm_4_1 << 8) + (imm_11 << 7);
         }
         InstructionType::U => {
-            let imm_31_12 = (imm >> 12) & 0xFFFFF;
-            *inst_bits |= imm_31_12 << 12;
+            *inst_bits |= (imm & 0xFFFFF) << 12;
         }
         InstructionType::J => {
             let imm_20 = (imm >> 20) & 0x1;
@@ -107,24 +606,32 @@ fn set_imm(
             let imm_19_12 = (imm >> 12) & 0xFF;
             *inst_bits |= (imm_20 << 31) + (imm_10_1 << 21) + (imm_11 << 20) + (imm_19_12 << 12);
         }
-        InstructionType::R => panic!("R-type instruction should've not entered here"),
+        InstructionType::R | InstructionType::R4 => {
+            panic!("R-type instruction should've not entered here")
+        }
     }
-    return Ok(());
 }
 
 fn set_reg(
     inst_bits: &mut u32,
     reg_string: &str,
+    span: Span,
     reg_function: RegFunc,
-) -> Result<(), &'static str> {
-    // Clone and sort REG_FILE by name
-    let mut reg_file = REG_FILE;
+    reg_file: &[Register],
+) -> Result<(), AssembleError> {
+    // Sort the register file by name
+    let mut reg_file: Vec<_> = reg_file.iter().collect();
     reg_file.sort_by_key(|r| r.name);
 
     // Find register
     let reg_idx = match reg_file.binary_search_by_key(&reg_string, |r| r.name) {
         Ok(i) => i,
-        Err(_) => return Err("Invalid register"),
+        Err(_) => {
+            return Err(AssembleError::InvalidRegister {
+                lexeme: reg_string.to_string(),
+                span,
+            })
+        }
     };
     let reg = &reg_file[reg_idx];
 
@@ -132,12 +639,13 @@ fn set_reg(
     match reg_function {
         RegFunc::Src1 => *inst_bits |= reg.number << 15,
         RegFunc::Src2 => *inst_bits |= reg.number << 20,
+        RegFunc::Src3 => *inst_bits |= reg.number << 27,
         RegFunc::Dest => *inst_bits |= reg.number << 7,
     }
     Ok(())
 }
 
-fn imm_string_to_i32(imm_string: &str) -> Result<i32, &'static str> {
+fn imm_string_to_i64(imm_string: &str) -> Result<i64, &'static str> {
     let mut imm_chars = imm_string.chars().peekable();
     let mut sign = 1;
     // Skip + or - sign
@@ -191,34 +699,37 @@ fn imm_string_to_i32(imm_string: &str) -> Result<i32, &'static str> {
     }
     // Decode string
     let imm_string: String = imm_chars.collect();
-    match i32::from_str_radix(&imm_string, radix) {
-        Ok(x) => Ok(sign * x),
+    // Literals above i64::MAX are taken as their two's complement bit pattern
+    let number = i64::from_str_radix(&imm_string, radix)
+        .or_else(|_| u64::from_str_radix(&imm_string, radix).map(|x| x as i64));
+    match number {
+        Ok(x) => Ok(x.wrapping_mul(sign)),
         Err(_) => Err("String decode failed"),
     }
 }
 
 #[test]
-fn test_imm_string_to_i32() {
+fn test_imm_string_to_i64() {
     let imm_string = "200";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 200);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 200);
     let imm_string = "0200";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 200);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 200);
     let imm_string = "0d200";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 200);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 200);
     let imm_string = "$0c8";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0xc8);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0xc8);
     let imm_string = "0xc8";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0xc8);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0xc8);
     let imm_string = "0hc8";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0xc8);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0xc8);
     let imm_string = "0o310";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0o310);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0o310);
     let imm_string = "0q310";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0o310);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0o310);
     let imm_string = "0b11001000";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0b11001000);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0b11001000);
     let imm_string = "0y11001000";
-    assert_eq!(imm_string_to_i32(imm_string).unwrap(), 0b11001000);
+    assert_eq!(imm_string_to_i64(imm_string).unwrap(), 0b11001000);
 }
 #[test]
 fn test_op_imm() {
@@ -228,7 +739,7 @@ fn test_op_imm() {
 }
 #[test]
 fn test_u_inst() {
-    let asm_line = "lui t2 -3";
+    let asm_line = "lui t2 0xfffff";
     let expected_result: u32 = 0b11111111111111111111_00111_0110111;
     assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
 }
@@ -237,10 +748,14 @@ fn test_op() {
     let asm_line = "add t2 t1 t0";
     let expected_result: u32 = 0b0000000_00101_00110_000_00111_0110011;
     assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
+    // Numeric names as used by the ISA manual
+    assert_eq!(decode_asm_line("add x7, x6, x5").unwrap(), expected_result);
+    assert_eq!(decode_asm_line("sw x7, -3(x6)").unwrap(), 0xfe732ea3);
+    assert!(decode_asm_line("add x7, x6, x32").is_err());
 }
 #[test]
 fn test_jal() {
-    let asm_line = "jal t2 -3";
+    let asm_line = "jal t2 -4";
     let expected_result: u32 = 0b1_1111111110_1_11111111_00111_1101111;
     assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
 }
@@ -252,7 +767,7 @@ fn test_jalr() {
 }
 #[test]
 fn test_b_inst() {
-    let asm_line = "beq t2 t1 -3";
+    let asm_line = "beq t2 t1 -4";
     let expected_result: u32 = 0b1_111111_00110_00111_000_1110_1_1100011;
     assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
 }
@@ -263,6 +778,216 @@ fn test_load() {
     assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
 }
 #[test]
+fn test_gnu_syntax() {
+    let expected_result: u32 = 0b000000000001_00101_000_00101_0010011;
+    assert_eq!(decode_asm_line("addi t0, t0, 1").unwrap(), expected_result);
+    assert_eq!(
+        decode_asm_line("\taddi\tt0,t0,  1").unwrap(),
+        expected_result
+    );
+    assert_eq!(
+        decode_asm_line("addi  t0 t0 1 # increment").unwrap(),
+        expected_result
+    );
+    let expected_result: u32 = 0b1111111_00111_00110_010_11101_0100011;
+    assert_eq!(decode_asm_line("sw t2, -3(t1)").unwrap(), expected_result);
+    assert!(decode_asm_line("addi t0, t0").is_err());
+}
+#[test]
+fn test_system_and_fence() {
+    assert_eq!(decode_asm_line("ecall").unwrap(), 0x00000073);
+    assert_eq!(decode_asm_line("ebreak").unwrap(), 0x00100073);
+    assert_eq!(decode_asm_line("fence rw, w").unwrap(), 0x0310000f);
+    assert_eq!(decode_asm_line("fence iorw, iorw").unwrap(), 0x0ff0000f);
+    assert_eq!(decode_asm_line("fence wr, 0").unwrap(), 0x0300000f);
+    assert_eq!(decode_asm_line("fence").unwrap(), 0x0ff0000f);
+    assert_eq!(decode_asm_line("fence.i").unwrap(), 0x0000100f);
+    assert_eq!(decode_asm_line("fence.tso").unwrap(), 0x8330000f);
+    assert_eq!(decode_asm_line("mret").unwrap(), 0x30200073);
+    assert_eq!(decode_asm_line("sret").unwrap(), 0x10200073);
+    assert_eq!(decode_asm_line("wfi").unwrap(), 0x10500073);
+    assert!(decode_asm_line("fence rx, w").is_err());
+    assert!(decode_asm_line("fence rr, w").is_err());
+}
+#[test]
+fn test_csr() {
+    assert_eq!(
+        decode_asm_line("csrrw zero, mtvec, t0").unwrap(),
+        0x30529073
+    );
+    assert_eq!(
+        decode_asm_line("csrrs zero, mstatus, t0").unwrap(),
+        0x3002a073
+    );
+    assert_eq!(
+        decode_asm_line("csrrc t0, mcause, zero").unwrap(),
+        0x342032f3
+    );
+    assert_eq!(decode_asm_line("csrrwi a0, 0x7c0, 31").unwrap(), 0x7c0fd573);
+    assert_eq!(decode_asm_line("csrrsi zero, mie, 8").unwrap(), 0x30446073);
+    assert_eq!(decode_asm_line("csrrci zero, mie, 8").unwrap(), 0x30447073);
+    assert_eq!(decode_asm_line("csrr a0, cycle").unwrap(), 0xc0002573);
+    assert_eq!(decode_asm_line("csrw mscratch, a0").unwrap(), 0x34051073);
+    assert_eq!(decode_asm_line("csrs mie, a0").unwrap(), 0x30452073);
+    assert_eq!(decode_asm_line("csrc mie, a0").unwrap(), 0x30453073);
+    assert_eq!(decode_asm_line("csrwi mie, 1").unwrap(), 0x3040d073);
+    assert!(matches!(
+        decode_asm_line("csrrwi a0, mie, 32").unwrap_err(),
+        AssembleError::ImmediateOutOfRange { max: 31, .. }
+    ));
+    assert!(matches!(
+        decode_asm_line("csrrw a0, 0x1000, a0").unwrap_err(),
+        AssembleError::ImmediateOutOfRange { max: 0xFFF, .. }
+    ));
+    assert!(matches!(
+        decode_asm_line("csrr a0, mfoo").unwrap_err(),
+        AssembleError::UndefinedSymbol { .. }
+    ));
+}
+#[test]
+fn test_m_extension() {
+    // Encodings from the RISC-V unprivileged specification
+    let cases = [
+        ("mul a0, a1, a2", 0x02c58533),
+        ("mulh a0, a1, a2", 0x02c59533),
+        ("mulhsu a0, a1, a2", 0x02c5a533),
+        ("mulhu a0, a1, a2", 0x02c5b533),
+        ("div t0, t1, t2", 0x027342b3),
+        ("divu t0, t1, t2", 0x027352b3),
+        ("rem t0, t1, t2", 0x027362b3),
+        ("remu t0, t1, t2", 0x027372b3),
+    ];
+    for (asm_line, expected_result) in cases {
+        assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
+    }
+}
+#[test]
+fn test_a_extension() {
+    let cases = [
+        ("lr.w t0, (a0)", 0x100522af),
+        ("lr.w.aq t0, (a0)", 0x140522af),
+        ("sc.w t1, t2, (a0)", 0x1875232f),
+        ("sc.w.rl t1, t2, 0(a0)", 0x1a75232f),
+        ("amoswap.w.aqrl a0, a1, (a2)", 0x0eb6252f),
+        ("amoadd.w a0, a1, (a2)", 0x00b6252f),
+        ("amoxor.w a0, a1, (a2)", 0x20b6252f),
+        ("amoand.w a0, a1, (a2)", 0x60b6252f),
+        ("amoor.w a0, a1, (a2)", 0x40b6252f),
+        ("amomin.w a0, a1, (a2)", 0x80b6252f),
+        ("amomax.w a0, a1, (a2)", 0xa0b6252f),
+        ("amominu.w a0, a1, (a2)", 0xc0b6252f),
+        ("amomaxu.w.aq a0, a1, (a2)", 0xe4b6252f),
+    ];
+    for (asm_line, expected_result) in cases {
+        assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
+    }
+    assert!(decode_asm_line("amoadd.w a0, a1, 4(a2)").is_err());
+    assert!(matches!(
+        decode_asm_line("add.aq a0, a1, a2").unwrap_err(),
+        AssembleError::UnknownMnemonic { .. }
+    ));
+}
+#[test]
+fn test_f_d_extensions() {
+    // Encodings checked against llvm-mc
+    let cases = [
+        ("flw fa0, 8(a1)", 0x0085a507),
+        ("fsd fs1, -4(sp)", 0xfe913e27),
+        ("fmadd.s fa0, fa1, fa2, fa3", 0x68c5f543),
+        ("fnmadd.d f1, f2, f3, f4, rtz", 0x223110cf),
+        ("fadd.s fa0, fa1, fa2", 0x00c5f553),
+        ("fadd.s fa0, fa1, fa2, rne", 0x00c58553),
+        ("fsqrt.d ft0, ft1, rmm", 0x5a00c053),
+        ("fcvt.w.s a0, fa0, rtz", 0xc0051553),
+        ("fcvt.d.w fa0, a0", 0xd2050553),
+        ("fmv.x.w a0, ft0", 0xe0000553),
+        ("fclass.d a0, fa1", 0xe2059553),
+        ("fneg.d fa0, fa1", 0x22b59553),
+    ];
+    for (asm_line, expected_result) in cases {
+        assert_eq!(decode_asm_line(asm_line).unwrap(), expected_result);
+    }
+    // Integer and floating-point registers are not interchangeable
+    assert!(decode_asm_line("fadd.s a0, fa1, fa2").is_err());
+    assert!(decode_asm_line("fmv.x.w fa0, fa0").is_err());
+    // Only computations take a rounding mode
+    assert!(decode_asm_line("fadd.s fa0, fa1, fa2, nearest").is_err());
+    assert!(decode_asm_line("fsgnj.s fa0, fa1, fa2, rne").is_err());
+}
+
+#[test]
+fn test_errors() {
+    let error = decode_asm_line("addi t0, t9, 1").unwrap_err();
+    assert_eq!(
+        error,
+        AssembleError::InvalidRegister {
+            lexeme: "t9".to_string(),
+            span: Span {
+                line: 1,
+                column: 10,
+                length: 2
+            }
+        }
+    );
+    assert_eq!(error.to_string(), "[Line 1:10] Invalid register 't9'");
+    assert!(matches!(
+        decode_asm_line("adi t0, t0, 1").unwrap_err(),
+        AssembleError::UnknownMnemonic { .. }
+    ));
+    assert!(matches!(
+        decode_asm_line("addi t0, t0").unwrap_err(),
+        AssembleError::WrongOperandCount {
+            expected: 3,
+            found: 2,
+            ..
+        }
+    ));
+}
+#[test]
+fn test_imm_range() {
+    assert!(decode_asm_line("addi t0, t0, 2047").is_ok());
+    assert!(decode_asm_line("addi t0, t0, -2048").is_ok());
+    let error = decode_asm_line("addi t0, t0, 5000").unwrap_err();
+    assert!(matches!(
+        error,
+        AssembleError::ImmediateOutOfRange {
+            value: 5000,
+            min: -2048,
+            max: 2047,
+            ..
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "[Line 1:14] Immediate 5000 is out of range, expected a value in [-2048, 2047]"
+    );
+    assert!(decode_asm_line("sw t0, 2048(t1)").is_err());
+    assert!(decode_asm_line("beq t0, t1, 4094").is_ok());
+    assert!(decode_asm_line("beq t0, t1, 4096").is_err());
+    assert!(matches!(
+        decode_asm_line("beq t0, t1, 3").unwrap_err(),
+        AssembleError::MisalignedImmediate { alignment: 2, .. }
+    ));
+    assert!(decode_asm_line("jal ra, -1048576").is_ok());
+    assert!(decode_asm_line("jal ra, 1048576").is_err());
+}
+#[test]
+fn test_shamt() {
+    let expected_result: u32 = 0b0100000_11111_00101_101_00101_0010011;
+    assert_eq!(decode_asm_line("srai t0, t0, 31").unwrap(), expected_result);
+    let expected_result: u32 = 0b0000000_00001_00101_101_00101_0010011;
+    assert_eq!(decode_asm_line("srli t0, t0, 1").unwrap(), expected_result);
+    assert!(matches!(
+        decode_asm_line("srai t0, t0, -1").unwrap_err(),
+        AssembleError::ImmediateOutOfRange {
+            min: 0,
+            max: 31,
+            ..
+        }
+    ));
+    assert!(decode_asm_line("slli t0, t0, 32").is_err());
+}
+#[test]
 fn test_store() {
     let asm_line = "sw t2 -3(t1)";
     let expected_result: u32 = 0b1111111_00111_00110_010_11101_0100011;
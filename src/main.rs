@@ -1,6 +1,262 @@
-use rubbler;
+use rubbler::object::Object;
+use rubbler::AssembleError;
+use rubbler::Isa;
+use rubbler::Options;
+use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let asm_line = "add ra sp gp";
-    println!("{asm_line} => {:#034b}", rubbler::decode_asm_line(asm_line).unwrap());
+const USAGE: &str = "Usage: rubbler [options] <input.s>
+
+Options:
+  -o <file>                 Write output to <file> (default: a.out)
+  --format <bin|hex|elf>    Output format, inferred from the output file extension otherwise
+  -march=<isa>              Target ISA string, C enables compression (default: rv32g)
+  -a, --listing <file>      Write a listing of addresses and encodings to <file>
+  -Werror, --fatal-warnings Treat warnings as errors
+  -h, --help                Print this message";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Bin,
+    Hex,
+    Elf,
+}
+
+struct Args {
+    input: String,
+    output: String,
+    format: Format,
+    isa: Isa,
+    listing: Option<String>,
+    fatal_warnings: bool,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("rubbler: error: {message}\nTry 'rubbler --help' for more information");
+            return ExitCode::from(2);
+        }
+    };
+    let source = match fs::read_to_string(&args.input) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("rubbler: error: cannot read '{}': {error}", args.input);
+            return ExitCode::from(2);
+        }
+    };
+
+    // Undefined symbols can only be resolved by a linker when emitting ELF
+    let options = Options {
+        isa: args.isa.clone(),
+        relocatable: args.format == Format::Elf,
+    };
+    let object = match rubbler::assemble_with_options(&source, &options) {
+        Ok(object) => object,
+        Err(error) => {
+            report(&args.input, &source, &error, "error");
+            return ExitCode::FAILURE;
+        }
+    };
+    let severity = if args.fatal_warnings {
+        "error"
+    } else {
+        "warning"
+    };
+    for warning in &object.warnings {
+        report(&args.input, &source, warning, severity);
+    }
+    if args.fatal_warnings && !object.warnings.is_empty() {
+        return ExitCode::FAILURE;
+    }
+
+    let output = match args.format {
+        Format::Bin => object.to_binary(),
+        Format::Hex => to_hex(&object.to_binary()).into_bytes(),
+        Format::Elf => object.to_elf(&args.isa),
+    };
+    if let Err(error) = fs::write(&args.output, output) {
+        eprintln!("rubbler: error: cannot write '{}': {error}", args.output);
+        return ExitCode::from(2);
+    }
+    if let Some(listing) = &args.listing {
+        if let Err(error) = fs::write(listing, to_listing(&object, &source)) {
+            eprintln!("rubbler: error: cannot write '{listing}': {error}");
+            return ExitCode::from(2);
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// Returns `None` when help is requested
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut input = None;
+    let mut output = None;
+    let mut format = None;
+    let mut isa = Isa::default();
+    let mut listing = None;
+    let mut fatal_warnings = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| format!("Missing value for '{name}'"))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-o" => output = Some(value("-o")?),
+            "--format" => format = Some(parse_format(&value("--format")?)?),
+            "-march" => isa = Isa::parse(&value("-march")?)?,
+            "-a" | "--listing" => listing = Some(value(&arg)?),
+            "-Werror" | "--fatal-warnings" => fatal_warnings = true,
+            _ => {
+                if let Some(format_name) = arg.strip_prefix("--format=") {
+                    format = Some(parse_format(format_name)?);
+                } else if let Some(isa_string) = arg.strip_prefix("-march=") {
+                    isa = Isa::parse(isa_string)?;
+                } else if let Some(file) = arg.strip_prefix("--listing=") {
+                    listing = Some(file.to_string());
+                } else if arg.starts_with('-') && arg != "-" {
+                    return Err(format!("Unknown option '{arg}'"));
+                } else if input.replace(arg).is_some() {
+                    return Err("Only one input file may be given".to_string());
+                }
+            }
+        }
+    }
+
+    let input = input.ok_or("No input file")?;
+    let output = output.unwrap_or_else(|| "a.out".to_string());
+    let format = format.unwrap_or(match output.rsplit_once('.') {
+        Some((_, "bin")) => Format::Bin,
+        Some((_, "hex")) => Format::Hex,
+        _ => Format::Elf,
+    });
+    Ok(Some(Args {
+        input,
+        output,
+        format,
+        isa,
+        listing,
+        fatal_warnings,
+    }))
+}
+
+fn parse_format(name: &str) -> Result<Format, String> {
+    match name {
+        "bin" => Ok(Format::Bin),
+        "hex" => Ok(Format::Hex),
+        "elf" => Ok(Format::Elf),
+        _ => Err(format!("Unknown output format '{name}'")),
+    }
+}
+
+// Print `file:line:column: severity: message` followed by the offending source line
+fn report(file: &str, source: &str, error: &AssembleError, severity: &str) {
+    let span = error.span();
+    eprintln!(
+        "{file}:{}:{}: {severity}: {}",
+        span.line,
+        span.column,
+        error.message()
+    );
+    if let Some(line) = source.lines().nth(span.line.saturating_sub(1)) {
+        let indent: String = line
+            .chars()
+            .take(span.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        eprintln!("{line}\n{indent}{}", "^".repeat(span.length.max(1)));
+    }
+}
+
+// One little-endian 32-bit word per line, as read by `$readmemh`
+fn to_hex(binary: &[u8]) -> String {
+    let mut hex = String::new();
+    for chunk in binary.chunks(4) {
+        let mut word = [0; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        hex += &format!("{:08x}\n", u32::from_le_bytes(word));
+    }
+    hex
+}
+
+// Every source line preceded by its line number, offset and encoded bytes, followed by the symbols
+fn to_listing(object: &Object, source: &str) -> String {
+    let mut listing = String::new();
+    for (index, line) in source.lines().enumerate() {
+        let entries: Vec<_> = object
+            .listing
+            .iter()
+            .filter(|entry| entry.line == index + 1)
+            .collect();
+        let Some(first) = entries.first() else {
+            listing += &format!("{:>4} {:8} {:16} {line}\n", index + 1, "", "");
+            continue;
+        };
+        let bytes: String = entries
+            .iter()
+            .flat_map(|entry| {
+                let data = &object.sections[entry.section].data;
+                let start = entry.offset as usize;
+                data.get(start..start + entry.size as usize)
+                    .unwrap_or_default()
+                    .iter()
+            })
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        listing += &format!("{:>4} {:08x} {bytes:16} {line}\n", index + 1, first.offset);
+    }
+
+    // Every symbol with the section it is defined in and its offset there
+    listing += "\nSymbols:\n";
+    for symbol in &object.symbols {
+        let section = symbol
+            .section
+            .map_or("*UND*", |index| object.sections[index].name.as_str());
+        listing += &format!("{section:>10} {:08x} {}\n", symbol.value, symbol.name);
+    }
+    listing
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(line: &str) -> Result<Option<Args>, String> {
+        parse_args(line.split_whitespace().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn arguments() {
+        let parsed = args("prog.s -o prog.hex -march=rv32i -Werror -a prog.lst")
+            .unwrap()
+            .unwrap();
+        assert_eq!(parsed.input, "prog.s");
+        assert_eq!(parsed.format, Format::Hex);
+        assert_eq!(parsed.listing.as_deref(), Some("prog.lst"));
+        assert!(parsed.fatal_warnings);
+        let parsed = args("prog.s --format bin").unwrap().unwrap();
+        assert_eq!(
+            (parsed.output.as_str(), parsed.format),
+            ("a.out", Format::Bin)
+        );
+        assert!(args("--help").unwrap().is_none());
+        assert!(args("-o out.elf").is_err());
+        assert!(args("prog.s -march=rv32q").is_err());
+        assert!(args("prog.s --frobnicate").is_err());
+    }
+
+    #[test]
+    fn hex_output() {
+        assert_eq!(
+            to_hex(&[0xb7, 0x22, 0x01, 0x00, 0x13]),
+            "000122b7\n00000013\n"
+        );
+    }
 }
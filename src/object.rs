@@ -0,0 +1,154 @@
+use crate::error::AssembleError;
+
+/// Assembled program before it is laid out into an output file
+#[derive(Debug, Default)]
+pub struct Object {
+    pub sections: Vec<Section>,
+    pub symbols: Vec<Symbol>,
+    // Where the bytes of each source line ended up, in source order
+    pub listing: Vec<ListingLine>,
+    // Diagnostics that did not stop assembly
+    pub warnings: Vec<AssembleError>,
+}
+
+#[derive(Debug)]
+pub struct Section {
+    pub name: String,
+    pub kind: SectionKind,
+    // Zero-filled for `.bss`, which takes no space in the output file
+    pub data: Vec<u8>,
+    pub alignment: u64,
+    pub relocations: Vec<Relocation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SectionKind {
+    Text,
+    Data,
+    ReadOnlyData,
+    Bss,
+}
+
+#[derive(Debug)]
+pub struct Symbol {
+    pub name: String,
+    // Index into `Object::sections`, `None` for undefined symbols
+    pub section: Option<usize>,
+    pub value: u64,
+    pub global: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ListingLine {
+    pub line: usize,
+    pub section: usize,
+    pub offset: u64,
+    pub size: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Relocation {
+    pub offset: u64,
+    pub kind: RelocationKind,
+    pub symbol: String,
+    pub addend: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelocationKind {
+    Branch,
+    Jal,
+    // auipc and jalr pair of `call` and `tail`
+    Call,
+    // `%pcrel_hi` of auipc, and the `%pcrel_lo` referring back to it through the auipc's label
+    PcrelHi20,
+    PcrelLo12I,
+    PcrelLo12S,
+    // `%hi` of lui and `%lo` of loads, stores and immediate arithmetic
+    Hi20,
+    Lo12I,
+    Lo12S,
+    // Address held by `.word` and `.dword`
+    Data32,
+    Data64,
+}
+
+impl RelocationKind {
+    // Relocation type as defined in the RISC-V ELF psABI
+    pub fn elf_type(&self) -> u32 {
+        match self {
+            RelocationKind::Branch => 16,
+            RelocationKind::Jal => 17,
+            RelocationKind::Call => 19,
+            RelocationKind::PcrelHi20 => 23,
+            RelocationKind::PcrelLo12I => 24,
+            RelocationKind::PcrelLo12S => 25,
+            RelocationKind::Hi20 => 26,
+            RelocationKind::Lo12I => 27,
+            RelocationKind::Lo12S => 28,
+            RelocationKind::Data32 => 1,
+            RelocationKind::Data64 => 2,
+        }
+    }
+
+    // Whether the value is relative to the address the relocation is applied at
+    pub fn is_pc_relative(&self) -> bool {
+        !matches!(
+            self,
+            RelocationKind::Hi20
+                | RelocationKind::Lo12I
+                | RelocationKind::Lo12S
+                | RelocationKind::Data32
+                | RelocationKind::Data64
+        )
+    }
+}
+
+impl Object {
+    /// Concatenate the contents of every section holding file data, padding each one out to its
+    /// address
+    pub fn to_binary(&self) -> Vec<u8> {
+        let layout: Vec<_> = self
+            .sections
+            .iter()
+            .map(|s| (s.kind, s.data.len() as u64, s.alignment))
+            .collect();
+        let mut binary = vec![];
+        for (section, address) in self.sections.iter().zip(section_addresses(&layout)) {
+            if section.kind != SectionKind::Bss {
+                binary.resize(address as usize, 0);
+                binary.extend_from_slice(&section.data);
+            }
+        }
+        binary
+    }
+}
+
+/// Addresses of sections given by kind, size and alignment when laid out in a flat binary: one
+/// after another in order, followed by the `.bss` sections which take no space in the file
+pub fn section_addresses(sections: &[(SectionKind, u64, u64)]) -> Vec<u64> {
+    let mut addresses = vec![0; sections.len()];
+    let mut address: u64 = 0;
+    for bss in [false, true] {
+        for (index, &(kind, size, alignment)) in sections.iter().enumerate() {
+            if (kind == SectionKind::Bss) == bss {
+                address = address.next_multiple_of(alignment.max(1));
+                addresses[index] = address;
+                address += size;
+            }
+        }
+    }
+    addresses
+}
+
+impl Section {
+    pub fn new(name: &str, kind: SectionKind) -> Section {
+        Section {
+            name: name.to_string(),
+            kind,
+            data: vec![],
+            alignment: if kind == SectionKind::Text { 4 } else { 1 },
+            relocations: vec![],
+        }
+    }
+}
@@ -0,0 +1,528 @@
+pub mod ast;
+
+use crate::error::AssembleError;
+use crate::error::Span;
+use crate::reg::is_register;
+use crate::scanner::Token;
+use crate::scanner::TokenType;
+use ast::*;
+
+// Binary operators from the loosest binding to the tightest, as in C
+const PRECEDENCE: &[&[(TokenType, BinaryOperator)]] = &[
+    &[(TokenType::Pipe, BinaryOperator::Or)],
+    &[(TokenType::Caret, BinaryOperator::Xor)],
+    &[(TokenType::Ampersand, BinaryOperator::And)],
+    &[
+        (TokenType::LessLess, BinaryOperator::ShiftLeft),
+        (TokenType::GreaterGreater, BinaryOperator::ShiftRight),
+    ],
+    &[
+        (TokenType::Plus, BinaryOperator::Add),
+        (TokenType::Minus, BinaryOperator::Subtract),
+    ],
+    &[
+        (TokenType::Star, BinaryOperator::Multiply),
+        (TokenType::Slash, BinaryOperator::Divide),
+        (TokenType::Percent, BinaryOperator::Remainder),
+    ],
+];
+
+pub struct Parser {
+    tokens: Vec<Token>,
+    current: usize,
+    // Operands on the current line are separated by whitespace rather than commas
+    whitespace_separated: bool,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<Token>) -> Parser {
+        Parser {
+            tokens,
+            current: 0,
+            whitespace_separated: false,
+        }
+    }
+
+    pub fn parse(&mut self) -> Result<Program, AssembleError> {
+        let mut program = Program::default();
+        while self.peek(0).is_some() {
+            self.parse_line(&mut program.statements)?;
+        }
+        Ok(program)
+    }
+
+    fn parse_line(&mut self, statements: &mut Vec<Statement>) -> Result<(), AssembleError> {
+        // Labels
+        loop {
+            if self.check(0, TokenType::Identifier) && self.check(1, TokenType::Colon)
+                || self.check(0, TokenType::Number) && self.check(1, TokenType::Colon)
+            {
+                let token = self.advance().unwrap();
+                self.advance();
+                statements.push(Statement {
+                    kind: StatementKind::Label(token.lexeme.clone()),
+                    span: token.span(),
+                });
+            } else if self.check(0, TokenType::Dot)
+                && self.check(1, TokenType::Identifier)
+                && self.check(2, TokenType::Colon)
+            {
+                let dot = self.advance().unwrap();
+                let token = self.advance().unwrap();
+                self.advance();
+                statements.push(Statement {
+                    kind: StatementKind::Label(".".to_string() + &token.lexeme),
+                    span: dot.span().to(token.span()),
+                });
+            } else {
+                break;
+            }
+        }
+        // Directive, assignment or instruction
+        if self.check(0, TokenType::Identifier) && self.check(1, TokenType::Equals) {
+            // `NAME = value` is shorthand for `.set NAME, value`
+            let token = self.advance().unwrap();
+            self.advance();
+            self.whitespace_separated = false;
+            let name = Operand {
+                kind: OperandKind::Expression(Expr::Symbol(token.lexeme.clone())),
+                lexeme: token.lexeme.clone(),
+                span: token.span(),
+            };
+            let value = self.parse_operand()?;
+            statements.push(Statement {
+                kind: StatementKind::Directive {
+                    name: ".set".to_string(),
+                    arguments: vec![name, value],
+                },
+                span: token.span(),
+            });
+        } else if self.check(0, TokenType::Dot) && self.check(1, TokenType::Identifier) {
+            let dot = self.advance().unwrap();
+            let token = self.advance().unwrap();
+            let arguments = self.parse_operands()?;
+            statements.push(Statement {
+                kind: StatementKind::Directive {
+                    name: ".".to_string() + &token.lexeme,
+                    arguments,
+                },
+                span: dot.span().to(token.span()),
+            });
+        } else if self.check(0, TokenType::Identifier) {
+            let token = self.advance().unwrap();
+            let operands = self.parse_operands()?;
+            statements.push(Statement {
+                kind: StatementKind::Instruction {
+                    mnemonic: token.lexeme.clone(),
+                    operands,
+                },
+                span: token.span(),
+            });
+        }
+        // End of line
+        match self.advance() {
+            None => Ok(()),
+            Some(token) if token.token_type == TokenType::LineBreak => Ok(()),
+            Some(token) => Err(Self::error(token.span(), "Unexpected token", &token.lexeme)),
+        }
+    }
+
+    fn parse_operands(&mut self) -> Result<Vec<Operand>, AssembleError> {
+        let mut operands = vec![];
+        self.whitespace_separated = !(self.current..self.tokens.len())
+            .map(|i| self.tokens[i].token_type)
+            .take_while(|&t| t != TokenType::LineBreak)
+            .any(|t| t == TokenType::Comma);
+        while !self.at_end_of_line() {
+            operands.push(self.parse_operand()?);
+            if self.check(0, TokenType::Comma) {
+                self.advance();
+                if self.at_end_of_line() {
+                    return Err(Self::error(
+                        self.current_span(),
+                        "Expected operand after ','",
+                        "",
+                    ));
+                }
+            }
+        }
+        Ok(operands)
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, AssembleError> {
+        let start = self.current;
+        let kind = self.parse_operand_kind()?;
+        let tokens = &self.tokens[start..self.current];
+        let span = tokens[0].span().to(tokens[tokens.len() - 1].span());
+        let lexeme = tokens.iter().map(|t| t.lexeme.as_str()).collect();
+        Ok(Operand { kind, lexeme, span })
+    }
+
+    fn parse_operand_kind(&mut self) -> Result<OperandKind, AssembleError> {
+        let token = self.peek(0).unwrap();
+        match token.token_type {
+            TokenType::String => {
+                let bytes = self.advance().unwrap().bytes.unwrap();
+                Ok(OperandKind::String(bytes))
+            }
+            TokenType::Identifier
+                if is_register(&token.lexeme) && !self.check(1, TokenType::LeftParantheses) =>
+            {
+                let name = self.advance().unwrap().lexeme;
+                Ok(OperandKind::Register(name))
+            }
+            TokenType::LeftParantheses
+                if matches!(self.peek(1), Some(t) if is_register(&t.lexeme))
+                    && self.check(2, TokenType::RightParantheses) =>
+            {
+                let base = self.parse_base()?;
+                Ok(OperandKind::Memory { offset: None, base })
+            }
+            _ => {
+                let expr = self.parse_expr()?;
+                if self.check(0, TokenType::LeftParantheses) {
+                    let base = self.parse_base()?;
+                    Ok(OperandKind::Memory {
+                        offset: Some(expr),
+                        base,
+                    })
+                } else {
+                    Ok(OperandKind::Expression(expr))
+                }
+            }
+        }
+    }
+
+    fn parse_base(&mut self) -> Result<String, AssembleError> {
+        self.consume(TokenType::LeftParantheses, "Expected '('")?;
+        let base = self.consume(TokenType::Identifier, "Expected base register")?;
+        if !is_register(&base.lexeme) {
+            return Err(AssembleError::InvalidRegister {
+                lexeme: base.lexeme.clone(),
+                span: base.span(),
+            });
+        }
+        self.consume(TokenType::RightParantheses, "Expected ')'")?;
+        Ok(base.lexeme)
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, AssembleError> {
+        self.parse_binary(0)
+    }
+
+    fn parse_binary(&mut self, level: usize) -> Result<Expr, AssembleError> {
+        if level == PRECEDENCE.len() {
+            return self.parse_unary();
+        }
+        let mut left = self.parse_binary(level + 1)?;
+        while let Some(&(_, operator)) = PRECEDENCE[level]
+            .iter()
+            .find(|&&(token_type, _)| self.check(0, token_type))
+        {
+            if self.starts_operand() {
+                break;
+            }
+            self.advance();
+            let right = self.parse_binary(level + 1)?;
+            left = Expr::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+        Ok(left)
+    }
+
+    // Without commas, `addi t0 t0 -1` ends the operand before a sign that is separated from the
+    // previous token but attached to the next one
+    fn starts_operand(&self) -> bool {
+        let (Some(previous), Some(operator), Some(next)) = (
+            self.tokens.get(self.current.wrapping_sub(1)),
+            self.peek(0),
+            self.peek(1),
+        ) else {
+            return false;
+        };
+        let previous_end = previous.span().column + previous.span().length;
+        self.whitespace_separated
+            && operator.column > previous_end
+            && next.column == operator.column + 1
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, AssembleError> {
+        let span = self.current_span();
+        let Some(token) = self.advance() else {
+            return Err(Self::error(span, "Expected expression", ""));
+        };
+        match token.token_type {
+            TokenType::Minus => Ok(Expr::Negate(Box::new(self.parse_unary()?))),
+            TokenType::Plus => self.parse_unary(),
+            TokenType::Tilde => Ok(Expr::Not(Box::new(self.parse_unary()?))),
+            TokenType::Number => Ok(Expr::Number(token.literal.unwrap())),
+            TokenType::Identifier | TokenType::LocalLabel => Ok(Expr::Symbol(token.lexeme)),
+            TokenType::Dot => {
+                let name = self.consume(TokenType::Identifier, "Expected symbol after '.'")?;
+                Ok(Expr::Symbol(".".to_string() + &name.lexeme))
+            }
+            TokenType::LeftParantheses => {
+                // Operands cannot be separated inside parentheses
+                let whitespace_separated = std::mem::take(&mut self.whitespace_separated);
+                let expr = self.parse_expr()?;
+                self.whitespace_separated = whitespace_separated;
+                self.consume(TokenType::RightParantheses, "Expected ')'")?;
+                Ok(expr)
+            }
+            TokenType::Percent => {
+                let function =
+                    self.consume(TokenType::Identifier, "Expected function after '%'")?;
+                self.consume(TokenType::LeftParantheses, "Expected '('")?;
+                let argument = self.parse_expr()?;
+                self.consume(TokenType::RightParantheses, "Expected ')'")?;
+                Ok(Expr::Relocation {
+                    function: function.lexeme,
+                    argument: Box::new(argument),
+                })
+            }
+            _ => Err(Self::error(token.span(), "Unexpected token", &token.lexeme)),
+        }
+    }
+
+    fn consume(
+        &mut self,
+        token_type: TokenType,
+        description: &str,
+    ) -> Result<Token, AssembleError> {
+        if self.check(0, token_type) {
+            Ok(self.advance().unwrap())
+        } else {
+            let lexeme = match self.peek(0) {
+                Some(token) if token.token_type != TokenType::LineBreak => token.lexeme.clone(),
+                _ => "".to_string(),
+            };
+            Err(Self::error(self.current_span(), description, &lexeme))
+        }
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.current).cloned();
+        if token.is_some() {
+            self.current += 1;
+        }
+        token
+    }
+
+    fn peek(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.current + offset)
+    }
+
+    fn check(&self, offset: usize, token_type: TokenType) -> bool {
+        matches!(self.peek(offset), Some(token) if token.token_type == token_type)
+    }
+
+    fn at_end_of_line(&self) -> bool {
+        self.peek(0).is_none() || self.check(0, TokenType::LineBreak)
+    }
+
+    // Span of the current token, or the position right after the last token
+    fn current_span(&self) -> Span {
+        match (self.peek(0), self.tokens.last()) {
+            (Some(token), _) => token.span(),
+            (None, Some(token)) => {
+                let span = token.span();
+                Span {
+                    column: span.column + span.length,
+                    length: 1,
+                    ..span
+                }
+            }
+            (None, None) => Span {
+                line: 1,
+                column: 1,
+                length: 1,
+            },
+        }
+    }
+
+    fn error(span: Span, description: &str, lexeme: &str) -> AssembleError {
+        AssembleError::Syntax {
+            description: description.to_string(),
+            lexeme: lexeme.to_string(),
+            span,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ast::*;
+    use super::Parser;
+    use crate::error::AssembleError;
+    use crate::error::Span;
+    use crate::scanner::Scanner;
+
+    fn parse(source: &str) -> Result<Program, AssembleError> {
+        let tokens = Scanner::new(source.to_string()).scan_tokens()?;
+        Parser::new(tokens).parse()
+    }
+
+    #[test]
+    fn labels_and_instructions() {
+        let program = parse("loop: addi t0, t0, -1\n bne t0, zero, loop\n").unwrap();
+        let statements = &program.statements;
+        assert_eq!(statements.len(), 3);
+        assert!(matches!(&statements[0].kind, StatementKind::Label(name) if name == "loop"));
+        let StatementKind::Instruction { mnemonic, operands } = &statements[1].kind else {
+            panic!("Expected instruction");
+        };
+        assert_eq!(mnemonic, "addi");
+        assert!(matches!(&operands[0].kind, OperandKind::Register(r) if r == "t0"));
+        assert!(matches!(&operands[1].kind, OperandKind::Register(r) if r == "t0"));
+        assert!(matches!(
+            &operands[2].kind,
+            OperandKind::Expression(Expr::Negate(_))
+        ));
+        assert_eq!(operands[2].lexeme, "-1");
+        assert_eq!(
+            operands[2].span,
+            Span {
+                line: 1,
+                column: 20,
+                length: 2
+            }
+        );
+        let StatementKind::Instruction { operands, .. } = &statements[2].kind else {
+            panic!("Expected instruction");
+        };
+        assert!(
+            matches!(&operands[2].kind, OperandKind::Expression(Expr::Symbol(s)) if s == "loop")
+        );
+        assert_eq!(statements[2].span.line, 2);
+    }
+
+    #[test]
+    fn memory_operands() {
+        let program = parse("lw t2 -3(t1)\namoadd.w a0, a1, (a2)\n").unwrap();
+        let StatementKind::Instruction { operands, .. } = &program.statements[0].kind else {
+            panic!("Expected instruction");
+        };
+        assert!(
+            matches!(&operands[1].kind, OperandKind::Memory { offset: Some(_), base } if base == "t1")
+        );
+        let StatementKind::Instruction { operands, .. } = &program.statements[1].kind else {
+            panic!("Expected instruction");
+        };
+        assert!(
+            matches!(&operands[2].kind, OperandKind::Memory { offset: None, base } if base == "a2")
+        );
+    }
+
+    #[test]
+    fn directives_and_relocations() {
+        let source = "
+.equ RTC_BASE, 0x40000000
+1:      auipc   t0, %pcrel_hi(mtvec)        # load mtvec(hi)
+.section .rodata
+        .string \"PASS\\n\"
+";
+        let program = parse(source).unwrap();
+        let statements = &program.statements;
+        assert_eq!(statements.len(), 5);
+        let StatementKind::Directive { name, arguments } = &statements[0].kind else {
+            panic!("Expected directive");
+        };
+        assert_eq!(name, ".equ");
+        assert!(matches!(
+            &arguments[1].kind,
+            OperandKind::Expression(Expr::Number(0x40000000))
+        ));
+        assert!(matches!(&statements[1].kind, StatementKind::Label(name) if name == "1"));
+        let StatementKind::Instruction { operands, .. } = &statements[2].kind else {
+            panic!("Expected instruction");
+        };
+        assert!(matches!(
+            &operands[1].kind,
+            OperandKind::Expression(Expr::Relocation { function, .. }) if function == "pcrel_hi"
+        ));
+        let StatementKind::Directive { arguments, .. } = &statements[3].kind else {
+            panic!("Expected directive");
+        };
+        assert!(
+            matches!(&arguments[0].kind, OperandKind::Expression(Expr::Symbol(s)) if s == ".rodata")
+        );
+        let StatementKind::Directive { arguments, .. } = &statements[4].kind else {
+            panic!("Expected directive");
+        };
+        assert!(matches!(&arguments[0].kind, OperandKind::String(s) if s == b"PASS\n"));
+    }
+
+    #[test]
+    fn expressions() {
+        let source = "
+addi t0, t0, 1 + 2 * 3 | ~4
+addi t0 t0 -1
+.word end - start
+SIZE = (end - start) / 4
+lw a0, (4 + 4)(sp)
+";
+        let statements = parse(source).unwrap().statements;
+        let StatementKind::Instruction { operands, .. } = &statements[0].kind else {
+            panic!("Expected instruction");
+        };
+        let OperandKind::Expression(Expr::Binary {
+            operator: BinaryOperator::Or,
+            left,
+            right,
+        }) = &operands[2].kind
+        else {
+            panic!("Expected '|' to bind loosest");
+        };
+        assert!(matches!(
+            **left,
+            Expr::Binary {
+                operator: BinaryOperator::Add,
+                ..
+            }
+        ));
+        assert!(matches!(**right, Expr::Not(_)));
+        // Without commas a sign attached to the next token starts another operand
+        let StatementKind::Instruction { operands, .. } = &statements[1].kind else {
+            panic!("Expected instruction");
+        };
+        assert_eq!(operands.len(), 3);
+        let StatementKind::Directive { arguments, .. } = &statements[2].kind else {
+            panic!("Expected directive");
+        };
+        assert_eq!(arguments.len(), 1);
+        let StatementKind::Directive { name, arguments } = &statements[3].kind else {
+            panic!("Expected directive");
+        };
+        assert_eq!(name, ".set");
+        assert!(
+            matches!(&arguments[0].kind, OperandKind::Expression(Expr::Symbol(s)) if s == "SIZE")
+        );
+        let StatementKind::Instruction { operands, .. } = &statements[4].kind else {
+            panic!("Expected instruction");
+        };
+        assert!(
+            matches!(&operands[1].kind, OperandKind::Memory { offset: Some(_), base } if base == "sp")
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert!(parse("addi t0, t0,\n").is_err());
+        assert_eq!(
+            parse("lw t0, 4(foo)\n").unwrap_err(),
+            AssembleError::InvalidRegister {
+                lexeme: "foo".to_string(),
+                span: Span {
+                    line: 1,
+                    column: 10,
+                    length: 3
+                }
+            }
+        );
+        assert!(parse("%hi\n").is_err());
+        assert!(parse("addi t0, t0, (1 + 2\n").is_err());
+    }
+}
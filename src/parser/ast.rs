@@ -0,0 +1,82 @@
+use crate::error::Span;
+
+#[derive(Debug, Default)]
+pub struct Program {
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub kind: StatementKind,
+    // Span of the label, mnemonic or directive name
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum StatementKind {
+    // `loop:` or `1:`
+    Label(String),
+    // `addi t0, t0, 1`
+    Instruction {
+        mnemonic: String,
+        operands: Vec<Operand>,
+    },
+    // `.equ RTC_BASE, 0x40000000`
+    Directive {
+        name: String,
+        arguments: Vec<Operand>,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct Operand {
+    pub kind: OperandKind,
+    pub lexeme: String,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum OperandKind {
+    // `t0`
+    Register(String),
+    // `-3`, `loop`, `%pcrel_hi(mtvec)`
+    Expression(Expr),
+    // `-3(t1)` or `(a2)`
+    Memory { offset: Option<Expr>, base: String },
+    // `"PASS\n"`, with its escape sequences decoded
+    String(Vec<u8>),
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(i64),
+    Symbol(String),
+    Negate(Box<Expr>),
+    // `~mask`
+    Not(Box<Expr>),
+    // `end - start`
+    Binary {
+        operator: BinaryOperator,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+    // `%function(argument)`
+    Relocation {
+        function: String,
+        argument: Box<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    ShiftLeft,
+    ShiftRight,
+    And,
+    Or,
+    Xor,
+}
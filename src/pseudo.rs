@@ -0,0 +1,837 @@
+use crate::error::AssembleError;
+use crate::error::Span;
+use crate::expr::evaluate_expr;
+use crate::expr::refers_to_label;
+use crate::expr::Symbols;
+use crate::inst::find_instruction;
+use crate::inst::instruction_size;
+use crate::isa::Xlen;
+use crate::object::Relocation;
+use crate::object::RelocationKind;
+use crate::parser::ast::*;
+use crate::Context;
+
+// Operand of the base instruction an alias stands for
+enum AliasArg {
+    // Operand of the pseudo-instruction at this position
+    Operand(usize),
+    Reg(&'static str),
+    Imm(i64),
+    Symbol(&'static str),
+}
+
+// Pseudo-instruction standing for a single base instruction
+struct Alias<'a> {
+    asm_string: &'a str,
+    num_of_arguments: usize,
+    base: &'a str,
+    arguments: &'a [AliasArg],
+}
+
+const ALIASES: [Alias; 37] = [
+    Alias {
+        asm_string: "nop",
+        num_of_arguments: 0,
+        base: "addi",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Reg("zero"),
+            AliasArg::Imm(0),
+        ],
+    },
+    Alias {
+        asm_string: "mv",
+        num_of_arguments: 2,
+        base: "addi",
+        arguments: &[AliasArg::Operand(0), AliasArg::Operand(1), AliasArg::Imm(0)],
+    },
+    Alias {
+        asm_string: "not",
+        num_of_arguments: 2,
+        base: "xori",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Imm(-1),
+        ],
+    },
+    Alias {
+        asm_string: "neg",
+        num_of_arguments: 2,
+        base: "sub",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "seqz",
+        num_of_arguments: 2,
+        base: "sltiu",
+        arguments: &[AliasArg::Operand(0), AliasArg::Operand(1), AliasArg::Imm(1)],
+    },
+    Alias {
+        asm_string: "snez",
+        num_of_arguments: 2,
+        base: "sltu",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "sltz",
+        num_of_arguments: 2,
+        base: "slt",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Reg("zero"),
+        ],
+    },
+    Alias {
+        asm_string: "sgtz",
+        num_of_arguments: 2,
+        base: "slt",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "beqz",
+        num_of_arguments: 2,
+        base: "beq",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "bnez",
+        num_of_arguments: 2,
+        base: "bne",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "blez",
+        num_of_arguments: 2,
+        base: "bge",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "bgez",
+        num_of_arguments: 2,
+        base: "bge",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "bltz",
+        num_of_arguments: 2,
+        base: "blt",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "bgtz",
+        num_of_arguments: 2,
+        base: "blt",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "bgt",
+        num_of_arguments: 3,
+        base: "blt",
+        arguments: &[
+            AliasArg::Operand(1),
+            AliasArg::Operand(0),
+            AliasArg::Operand(2),
+        ],
+    },
+    Alias {
+        asm_string: "ble",
+        num_of_arguments: 3,
+        base: "bge",
+        arguments: &[
+            AliasArg::Operand(1),
+            AliasArg::Operand(0),
+            AliasArg::Operand(2),
+        ],
+    },
+    Alias {
+        asm_string: "bgtu",
+        num_of_arguments: 3,
+        base: "bltu",
+        arguments: &[
+            AliasArg::Operand(1),
+            AliasArg::Operand(0),
+            AliasArg::Operand(2),
+        ],
+    },
+    Alias {
+        asm_string: "bleu",
+        num_of_arguments: 3,
+        base: "bgeu",
+        arguments: &[
+            AliasArg::Operand(1),
+            AliasArg::Operand(0),
+            AliasArg::Operand(2),
+        ],
+    },
+    Alias {
+        asm_string: "j",
+        num_of_arguments: 1,
+        base: "jal",
+        arguments: &[AliasArg::Reg("zero"), AliasArg::Operand(0)],
+    },
+    Alias {
+        asm_string: "jal",
+        num_of_arguments: 1,
+        base: "jal",
+        arguments: &[AliasArg::Reg("ra"), AliasArg::Operand(0)],
+    },
+    Alias {
+        asm_string: "jr",
+        num_of_arguments: 1,
+        base: "jalr",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Imm(0),
+        ],
+    },
+    Alias {
+        asm_string: "jalr",
+        num_of_arguments: 1,
+        base: "jalr",
+        arguments: &[AliasArg::Reg("ra"), AliasArg::Operand(0), AliasArg::Imm(0)],
+    },
+    Alias {
+        asm_string: "fence",
+        num_of_arguments: 0,
+        base: "fence",
+        arguments: &[AliasArg::Symbol("iorw"), AliasArg::Symbol("iorw")],
+    },
+    Alias {
+        asm_string: "ret",
+        num_of_arguments: 0,
+        base: "jalr",
+        arguments: &[AliasArg::Reg("zero"), AliasArg::Reg("ra"), AliasArg::Imm(0)],
+    },
+    Alias {
+        asm_string: "csrr",
+        num_of_arguments: 2,
+        base: "csrrs",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Reg("zero"),
+        ],
+    },
+    Alias {
+        asm_string: "csrw",
+        num_of_arguments: 2,
+        base: "csrrw",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "csrs",
+        num_of_arguments: 2,
+        base: "csrrs",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "csrc",
+        num_of_arguments: 2,
+        base: "csrrc",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "csrwi",
+        num_of_arguments: 2,
+        base: "csrrwi",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "csrsi",
+        num_of_arguments: 2,
+        base: "csrrsi",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "csrci",
+        num_of_arguments: 2,
+        base: "csrrci",
+        arguments: &[
+            AliasArg::Reg("zero"),
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fmv.s",
+        num_of_arguments: 2,
+        base: "fsgnj.s",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fabs.s",
+        num_of_arguments: 2,
+        base: "fsgnjx.s",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fneg.s",
+        num_of_arguments: 2,
+        base: "fsgnjn.s",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fmv.d",
+        num_of_arguments: 2,
+        base: "fsgnj.d",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fabs.d",
+        num_of_arguments: 2,
+        base: "fsgnjx.d",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+    Alias {
+        asm_string: "fneg.d",
+        num_of_arguments: 2,
+        base: "fsgnjn.d",
+        arguments: &[
+            AliasArg::Operand(0),
+            AliasArg::Operand(1),
+            AliasArg::Operand(1),
+        ],
+    },
+];
+
+// Pseudo-instructions expanding to several base instructions
+const SEQUENCES: [(&str, usize); 4] = [("li", 2), ("la", 2), ("call", 1), ("tail", 1)];
+
+/// Encode `mnemonic`, expanding pseudo-instructions into the base instructions they stand for
+pub fn encode_statement(
+    mnemonic: &str,
+    mnemonic_span: Span,
+    operands: &[Operand],
+    context: &mut Context,
+) -> Result<Vec<u32>, AssembleError> {
+    if let Some(operands) = jalr_address(mnemonic, mnemonic_span, operands) {
+        return encode_statement(mnemonic, mnemonic_span, &operands, context);
+    }
+    if let Some(alias) = find_alias(mnemonic, operands.len()) {
+        check_operand_count(mnemonic, mnemonic_span, alias.num_of_arguments, operands)?;
+        let base_operands: Vec<Operand> = alias
+            .arguments
+            .iter()
+            .map(|arg| match arg {
+                AliasArg::Operand(index) => operands[*index].clone(),
+                AliasArg::Reg(reg) => register(reg, mnemonic_span),
+                AliasArg::Imm(imm) => immediate(*imm, mnemonic_span),
+                AliasArg::Symbol(name) => Operand {
+                    kind: OperandKind::Expression(Expr::Symbol(name.to_string())),
+                    lexeme: name.to_string(),
+                    span: mnemonic_span,
+                },
+            })
+            .collect();
+        let inst_bits =
+            crate::encode_instruction(alias.base, mnemonic_span, &base_operands, context)?;
+        return Ok(vec![inst_bits]);
+    }
+
+    let Some(&(_, num_of_arguments)) = SEQUENCES.iter().find(|(s, _)| *s == mnemonic) else {
+        let inst_bits = crate::encode_instruction(mnemonic, mnemonic_span, operands, context)?;
+        return Ok(vec![inst_bits]);
+    };
+    check_operand_count(mnemonic, mnemonic_span, num_of_arguments, operands)?;
+    let sequence = match mnemonic {
+        "li" => {
+            let xlen = context.isa.xlen;
+            let rd = operands[0].clone();
+            match &operands[1].kind {
+                OperandKind::Expression(expr) if refers_to_label(expr, context.symbols) => {
+                    // The address may be anywhere, so both halves are always loaded
+                    vec![
+                        (
+                            "lui",
+                            vec![rd.clone(), relocation("hi", expr, &operands[1])],
+                        ),
+                        (
+                            "addi",
+                            vec![rd.clone(), rd, relocation("lo", expr, &operands[1])],
+                        ),
+                    ]
+                }
+                _ => {
+                    let imm = load_immediate(&operands[1], context.symbols, xlen)?;
+                    load_sequence(imm, xlen)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, (base, imm))| {
+                            let rd = rd.clone();
+                            match (base, index) {
+                                ("lui", _) => (base, vec![rd, upper(imm, mnemonic_span)]),
+                                (_, 0) => {
+                                    let imm = immediate(imm, mnemonic_span);
+                                    (base, vec![rd, register("zero", mnemonic_span), imm])
+                                }
+                                _ => (base, vec![rd.clone(), rd, immediate(imm, mnemonic_span)]),
+                            }
+                        })
+                        .collect()
+                }
+            }
+        }
+        "la" => {
+            let offset =
+                pc_relative_offset(&operands[1], context, Some(RelocationKind::PcrelHi20))?;
+            // An external target is left to the linker, the addi refers back to the auipc through
+            // a label made up for it
+            let pc = context.pc as u64;
+            if let Some(relocations) = context.relocations.as_mut() {
+                if relocations.last().is_some_and(|r| r.offset == pc) {
+                    relocations.push(Relocation {
+                        offset: pc + 4,
+                        kind: RelocationKind::PcrelLo12I,
+                        symbol: pcrel_hi_label(pc),
+                        addend: 0,
+                    });
+                }
+            }
+            let (hi, lo) = split_imm(offset);
+            vec![
+                ("auipc", vec![operands[0].clone(), upper(hi, mnemonic_span)]),
+                (
+                    "addi",
+                    vec![
+                        operands[0].clone(),
+                        operands[0].clone(),
+                        immediate(lo, mnemonic_span),
+                    ],
+                ),
+            ]
+        }
+        _ => {
+            // `call` links through ra while `tail` clobbers t1 and discards the return address
+            let (scratch, link) = if mnemonic == "call" {
+                ("ra", "ra")
+            } else {
+                ("t1", "zero")
+            };
+            let offset = pc_relative_offset(&operands[0], context, Some(RelocationKind::Call))?;
+            let (hi, lo) = split_imm(offset);
+            vec![
+                (
+                    "auipc",
+                    vec![register(scratch, mnemonic_span), upper(hi, mnemonic_span)],
+                ),
+                (
+                    "jalr",
+                    vec![
+                        register(link, mnemonic_span),
+                        register(scratch, mnemonic_span),
+                        immediate(lo, mnemonic_span),
+                    ],
+                ),
+            ]
+        }
+    };
+
+    // Each base instruction is encoded at its own address. The auipc and jalr pair of `call` and
+    // `tail` stays whole so that the linker can relocate it.
+    let (pc, compress) = (context.pc, context.compress);
+    context.compress &= !matches!(mnemonic, "call" | "tail");
+    let mut words = vec![];
+    for (base, base_operands) in sequence {
+        let word = crate::encode_instruction(base, mnemonic_span, &base_operands, context)?;
+        words.push(word);
+        context.pc += instruction_size(word);
+    }
+    (context.pc, context.compress) = (pc, compress);
+    Ok(words)
+}
+
+/// Number of base instructions `mnemonic` is encoded as, known before any label is resolved
+pub fn instruction_count(
+    mnemonic: &str,
+    operands: &[Operand],
+    symbols: &Symbols,
+    xlen: Xlen,
+) -> Result<usize, AssembleError> {
+    match mnemonic {
+        "la" | "call" | "tail" => Ok(2),
+        "li" if operands.len() == 2 => {
+            // Symbols are always loaded in two instructions so that their value may be unknown yet
+            let OperandKind::Expression(expr) = &operands[1].kind else {
+                return Ok(1);
+            };
+            if refers_to_label(expr, symbols) {
+                return Ok(2);
+            }
+            let imm = load_immediate(&operands[1], symbols, xlen)?;
+            Ok(load_sequence(imm, xlen).len())
+        }
+        _ => Ok(1),
+    }
+}
+
+fn find_alias(mnemonic: &str, num_of_operands: usize) -> Option<&'static Alias<'static>> {
+    // Base instructions sharing the mnemonic only take over with their own operand count
+    let is_base = find_instruction(mnemonic).is_some();
+    ALIASES
+        .iter()
+        .filter(|a| a.asm_string == mnemonic)
+        .find(|a| a.num_of_arguments == num_of_operands || !is_base)
+}
+
+// Operands of the base jalr for `jalr rd, offset(rs1)` and `jalr offset(rs1)`
+fn jalr_address(mnemonic: &str, mnemonic_span: Span, operands: &[Operand]) -> Option<Vec<Operand>> {
+    let (address, rd) = operands.split_last()?;
+    let OperandKind::Memory { offset, base } = &address.kind else {
+        return None;
+    };
+    if mnemonic != "jalr" || rd.len() > 1 {
+        return None;
+    }
+    let rd = rd
+        .first()
+        .cloned()
+        .unwrap_or_else(|| register("ra", mnemonic_span));
+    let offset = match offset {
+        Some(expr) => Operand {
+            kind: OperandKind::Expression(expr.clone()),
+            lexeme: address.lexeme.clone(),
+            span: address.span,
+        },
+        None => immediate(0, address.span),
+    };
+    Some(vec![rd, register(base, address.span), offset])
+}
+
+fn check_operand_count(
+    mnemonic: &str,
+    mnemonic_span: Span,
+    expected: usize,
+    operands: &[Operand],
+) -> Result<(), AssembleError> {
+    if expected != operands.len() {
+        return Err(AssembleError::WrongOperandCount {
+            mnemonic: mnemonic.to_string(),
+            expected,
+            found: operands.len(),
+            span: mnemonic_span,
+        });
+    }
+    Ok(())
+}
+
+// Constant loaded by `li`
+fn load_immediate(operand: &Operand, symbols: &Symbols, xlen: Xlen) -> Result<i64, AssembleError> {
+    let OperandKind::Expression(expr) = &operand.kind else {
+        return Err(crate::invalid_operand(operand, "Expected an immediate"));
+    };
+    let imm = evaluate_expr(expr, operand, symbols)?;
+    let (min, max) = (i32::MIN as i64, u32::MAX as i64);
+    if xlen == Xlen::Rv32 && !(min..=max).contains(&imm) {
+        return Err(AssembleError::ImmediateOutOfRange {
+            lexeme: operand.lexeme.clone(),
+            value: imm,
+            min,
+            max,
+            span: operand.span,
+        });
+    }
+    Ok(imm)
+}
+
+// Base instructions loading a constant, each one after the first operating on the result of
+// the previous one
+fn load_sequence(imm: i64, xlen: Xlen) -> Vec<(&'static str, i64)> {
+    if xlen == Xlen::Rv32 || imm == imm as i32 as i64 {
+        // addiw wraps around on RV64 when rounding up the upper part overflows into bit 31
+        let add = if xlen == Xlen::Rv64 { "addiw" } else { "addi" };
+        return match split_imm(imm) {
+            (0, lo) => vec![("addi", lo)],
+            (hi, 0) => vec![("lui", hi)],
+            (hi, lo) => vec![("lui", hi), (add, lo)],
+        };
+    }
+
+    // Load the upper bits on their own, then shift them into place and add the lower 12 bits
+    let lo = (imm << 52) >> 52;
+    let upper = (imm as u64).wrapping_add(0x800) >> 12;
+    let shift = 12 + upper.trailing_zeros();
+    let hi = (((upper >> (shift - 12)) << shift) as i64) >> shift;
+    let mut sequence = load_sequence(hi, xlen);
+    sequence.push(("slli", shift as i64));
+    if lo != 0 {
+        sequence.push(("addi", lo));
+    }
+    sequence
+}
+
+// Distance from the current instruction to the target of `la`, `call` or `tail`
+fn pc_relative_offset(
+    operand: &Operand,
+    context: &mut Context,
+    relocation_kind: Option<RelocationKind>,
+) -> Result<i64, AssembleError> {
+    let OperandKind::Expression(expr) = &operand.kind else {
+        return Err(crate::invalid_operand(operand, "Expected a symbol"));
+    };
+    if relocation_kind.is_some_and(|kind| crate::relocate(expr, operand, kind, context)) {
+        return Ok(0);
+    }
+    let target = evaluate_expr(expr, operand, context.symbols)?;
+    Ok(((target - context.pc as i64) as i32) as i64)
+}
+
+// Split a 32-bit value into the upper part set by lui/auipc and the sign-extended lower 12 bits,
+// which is added to it afterwards
+fn split_imm(imm: i64) -> (i64, i64) {
+    let imm = imm as i32;
+    let lo = (imm << 20) >> 20;
+    let hi = imm.wrapping_sub(lo);
+    (hi as i64, lo as i64)
+}
+
+/// Label `la` makes up for its auipc at `offset`, which the relocation of its addi refers to
+pub fn pcrel_hi_label(offset: u64) -> String {
+    format!(".Lpcrel_hi{offset}")
+}
+
+// `%function(expr)` in place of `operand`
+fn relocation(function: &str, expr: &Expr, operand: &Operand) -> Operand {
+    Operand {
+        kind: OperandKind::Expression(Expr::Relocation {
+            function: function.to_string(),
+            argument: Box::new(expr.clone()),
+        }),
+        lexeme: operand.lexeme.clone(),
+        span: operand.span,
+    }
+}
+
+fn register(name: &str, span: Span) -> Operand {
+    Operand {
+        kind: OperandKind::Register(name.to_string()),
+        lexeme: name.to_string(),
+        span,
+    }
+}
+
+// Immediate of lui or auipc loading the upper part `imm` of a value
+fn upper(imm: i64, span: Span) -> Operand {
+    immediate(crate::upper_immediate(imm), span)
+}
+
+fn immediate(imm: i64, span: Span) -> Operand {
+    Operand {
+        kind: OperandKind::Expression(Expr::Number(imm)),
+        lexeme: imm.to_string(),
+        span,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::load_sequence;
+    use super::split_imm;
+    use crate::assemble;
+    use crate::decode_asm_line;
+    use crate::isa::Xlen;
+
+    fn words(source: &str) -> Vec<u32> {
+        assemble(source)
+            .unwrap()
+            .chunks(4)
+            .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn split() {
+        assert_eq!(split_imm(0x12345678), (0x12345000, 0x678));
+        assert_eq!(split_imm(0x12345800), (0x12346000, -0x800));
+        assert_eq!(split_imm(0xFFFFFFFF), (0, -1));
+        assert_eq!(split_imm(0x7FFFF800), (i32::MIN as i64, -0x800));
+    }
+
+    #[test]
+    fn aliases() {
+        assert_eq!(decode_asm_line("nop").unwrap(), 0x00000013);
+        assert_eq!(decode_asm_line("mv a0, a1").unwrap(), 0x00058513);
+        assert_eq!(decode_asm_line("not a0, a1").unwrap(), 0xfff5c513);
+        assert_eq!(decode_asm_line("neg a0, a1").unwrap(), 0x40b00533);
+        assert_eq!(decode_asm_line("seqz a0, a1").unwrap(), 0x0015b513);
+        assert_eq!(decode_asm_line("snez a0, a1").unwrap(), 0x00b03533);
+        assert_eq!(decode_asm_line("ret").unwrap(), 0x00008067);
+        assert_eq!(decode_asm_line("jr a0").unwrap(), 0x00050067);
+        assert_eq!(decode_asm_line("jalr a0").unwrap(), 0x000500e7);
+        assert_eq!(decode_asm_line("jalr t0, 4(t1)").unwrap(), 0x004302e7);
+        assert_eq!(decode_asm_line("jalr 0(t0)").unwrap(), 0x000280e7);
+        assert_eq!(decode_asm_line("jalr zero, (ra)").unwrap(), 0x00008067);
+        assert_eq!(decode_asm_line("jalr a0, -8(a1)").unwrap(), 0xff858567);
+        assert_eq!(decode_asm_line("j 8").unwrap(), 0x0080006f);
+        assert_eq!(decode_asm_line("jal 8").unwrap(), 0x008000ef);
+        assert_eq!(decode_asm_line("bgez t0, 8").unwrap(), 0x0002d463);
+        assert_eq!(decode_asm_line("bgt a0, a1, 8").unwrap(), 0x00a5c463);
+        // The base instruction still takes over with its own operand count
+        assert_eq!(decode_asm_line("jal t2 -4").unwrap(), 0xffdff3ef);
+    }
+
+    #[test]
+    fn load_immediate() {
+        assert_eq!(words("li t0, 8"), vec![0x00800293]);
+        assert_eq!(words("li t0, -2048"), vec![0x80000293]);
+        assert_eq!(words("li t0, 0x12345000"), vec![0x123452b7]);
+        assert_eq!(words("li a0, 0x12345678"), vec![0x12345537, 0x67850513]);
+        // The lower 12 bits are negative, so the upper part is rounded up
+        assert_eq!(words("li t0, 1000000000"), vec![0x3b9ad2b7, 0xa0028293]);
+        assert_eq!(words("li t0, 0xFFFFFFFF"), vec![0xfff00293]);
+        assert!(assemble("li t0, 0x100000000").is_err());
+    }
+
+    // Value left in the register by a sequence from `load_sequence` on RV64
+    fn run_rv64(sequence: &[(&str, i64)]) -> i64 {
+        let mut value: i64 = 0;
+        for &(base, imm) in sequence {
+            value = match base {
+                "lui" => imm as i32 as i64,
+                "addi" => value.wrapping_add(imm),
+                "addiw" => value.wrapping_add(imm) as i32 as i64,
+                "slli" => value << imm,
+                _ => panic!("Unexpected {base}"),
+            };
+        }
+        value
+    }
+
+    #[test]
+    fn load_immediate_rv64() {
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+        let mut values = vec![i64::MIN, i64::MAX, 0x7FFFF800, 0x80000000, 0xFFFFFFFF, -1];
+        for _ in 0..1000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            // Mix in values with long runs of zeros in their upper bits
+            values.push((state as i64) >> (state % 64));
+        }
+        for value in values {
+            let sequence = load_sequence(value, Xlen::Rv64);
+            assert_eq!(run_rv64(&sequence), value, "{sequence:?}");
+            assert!(sequence.len() <= 8);
+        }
+        // Same sequence as LLVM emits
+        assert_eq!(
+            load_sequence(0x123456789abcdef0, Xlen::Rv64),
+            vec![
+                ("lui", 0x247000),
+                ("addiw", -0x753),
+                ("slli", 14),
+                ("addi", -0x3b3),
+                ("slli", 12),
+                ("addi", 0x5e7),
+                ("slli", 13),
+                ("addi", -0x110),
+            ]
+        );
+        // Constants that fit in 32 bits are sign-extended on RV64
+        assert_eq!(
+            load_sequence(0x7FFFF800, Xlen::Rv64),
+            vec![("lui", i32::MIN as i64), ("addiw", -0x800)]
+        );
+    }
+
+    #[test]
+    fn pc_relative() {
+        let source = "
+        la a0, msg
+        call puts
+        tail puts
+msg:    nop
+puts:   ret
+";
+        assert_eq!(
+            words(source),
+            vec![
+                0x00000517, 0x01850513, // la a0, msg (+24)
+                0x00000097, 0x014080e7, // call puts (+20)
+                0x00000317, 0x00c30067, // tail puts (+12)
+                0x00000013, 0x00008067,
+            ]
+        );
+        let object = crate::assemble_object("call printf\n").unwrap();
+        assert_eq!(object.sections[0].relocations[0].kind.elf_type(), 19);
+        assert!(assemble("la a0, nowhere\n").is_err());
+    }
+}
@@ -1,6 +1,7 @@
 pub enum RegFunc {
     Src1,
     Src2,
+    Src3,
     Dest,
 }
 
@@ -9,7 +10,8 @@ pub struct Register<'a> {
     pub number: u32,
 }
 
-pub const REG_FILE: [Register; 33] = [
+// The canonical ABI name of each register comes first
+pub const REG_FILE: [Register; 65] = [
     Register {
         name: "zero",
         number: 0,
@@ -43,11 +45,11 @@ pub const REG_FILE: [Register; 33] = [
         number: 7,
     },
     Register {
-        name: "fp",
+        name: "s0",
         number: 8,
     },
     Register {
-        name: "s0",
+        name: "fp",
         number: 8,
     },
     Register {
@@ -142,4 +144,435 @@ pub const REG_FILE: [Register; 33] = [
         name: "t6",
         number: 31,
     },
+    Register {
+        name: "x0",
+        number: 0,
+    },
+    Register {
+        name: "x1",
+        number: 1,
+    },
+    Register {
+        name: "x2",
+        number: 2,
+    },
+    Register {
+        name: "x3",
+        number: 3,
+    },
+    Register {
+        name: "x4",
+        number: 4,
+    },
+    Register {
+        name: "x5",
+        number: 5,
+    },
+    Register {
+        name: "x6",
+        number: 6,
+    },
+    Register {
+        name: "x7",
+        number: 7,
+    },
+    Register {
+        name: "x8",
+        number: 8,
+    },
+    Register {
+        name: "x9",
+        number: 9,
+    },
+    Register {
+        name: "x10",
+        number: 10,
+    },
+    Register {
+        name: "x11",
+        number: 11,
+    },
+    Register {
+        name: "x12",
+        number: 12,
+    },
+    Register {
+        name: "x13",
+        number: 13,
+    },
+    Register {
+        name: "x14",
+        number: 14,
+    },
+    Register {
+        name: "x15",
+        number: 15,
+    },
+    Register {
+        name: "x16",
+        number: 16,
+    },
+    Register {
+        name: "x17",
+        number: 17,
+    },
+    Register {
+        name: "x18",
+        number: 18,
+    },
+    Register {
+        name: "x19",
+        number: 19,
+    },
+    Register {
+        name: "x20",
+        number: 20,
+    },
+    Register {
+        name: "x21",
+        number: 21,
+    },
+    Register {
+        name: "x22",
+        number: 22,
+    },
+    Register {
+        name: "x23",
+        number: 23,
+    },
+    Register {
+        name: "x24",
+        number: 24,
+    },
+    Register {
+        name: "x25",
+        number: 25,
+    },
+    Register {
+        name: "x26",
+        number: 26,
+    },
+    Register {
+        name: "x27",
+        number: 27,
+    },
+    Register {
+        name: "x28",
+        number: 28,
+    },
+    Register {
+        name: "x29",
+        number: 29,
+    },
+    Register {
+        name: "x30",
+        number: 30,
+    },
+    Register {
+        name: "x31",
+        number: 31,
+    },
+];
+
+// Floating-point registers of the F and D extensions, ABI names first
+pub const F_REG_FILE: [Register; 64] = [
+    Register {
+        name: "ft0",
+        number: 0,
+    },
+    Register {
+        name: "ft1",
+        number: 1,
+    },
+    Register {
+        name: "ft2",
+        number: 2,
+    },
+    Register {
+        name: "ft3",
+        number: 3,
+    },
+    Register {
+        name: "ft4",
+        number: 4,
+    },
+    Register {
+        name: "ft5",
+        number: 5,
+    },
+    Register {
+        name: "ft6",
+        number: 6,
+    },
+    Register {
+        name: "ft7",
+        number: 7,
+    },
+    Register {
+        name: "fs0",
+        number: 8,
+    },
+    Register {
+        name: "fs1",
+        number: 9,
+    },
+    Register {
+        name: "fa0",
+        number: 10,
+    },
+    Register {
+        name: "fa1",
+        number: 11,
+    },
+    Register {
+        name: "fa2",
+        number: 12,
+    },
+    Register {
+        name: "fa3",
+        number: 13,
+    },
+    Register {
+        name: "fa4",
+        number: 14,
+    },
+    Register {
+        name: "fa5",
+        number: 15,
+    },
+    Register {
+        name: "fa6",
+        number: 16,
+    },
+    Register {
+        name: "fa7",
+        number: 17,
+    },
+    Register {
+        name: "fs2",
+        number: 18,
+    },
+    Register {
+        name: "fs3",
+        number: 19,
+    },
+    Register {
+        name: "fs4",
+        number: 20,
+    },
+    Register {
+        name: "fs5",
+        number: 21,
+    },
+    Register {
+        name: "fs6",
+        number: 22,
+    },
+    Register {
+        name: "fs7",
+        number: 23,
+    },
+    Register {
+        name: "fs8",
+        number: 24,
+    },
+    Register {
+        name: "fs9",
+        number: 25,
+    },
+    Register {
+        name: "fs10",
+        number: 26,
+    },
+    Register {
+        name: "fs11",
+        number: 27,
+    },
+    Register {
+        name: "ft8",
+        number: 28,
+    },
+    Register {
+        name: "ft9",
+        number: 29,
+    },
+    Register {
+        name: "ft10",
+        number: 30,
+    },
+    Register {
+        name: "ft11",
+        number: 31,
+    },
+    Register {
+        name: "f0",
+        number: 0,
+    },
+    Register {
+        name: "f1",
+        number: 1,
+    },
+    Register {
+        name: "f2",
+        number: 2,
+    },
+    Register {
+        name: "f3",
+        number: 3,
+    },
+    Register {
+        name: "f4",
+        number: 4,
+    },
+    Register {
+        name: "f5",
+        number: 5,
+    },
+    Register {
+        name: "f6",
+        number: 6,
+    },
+    Register {
+        name: "f7",
+        number: 7,
+    },
+    Register {
+        name: "f8",
+        number: 8,
+    },
+    Register {
+        name: "f9",
+        number: 9,
+    },
+    Register {
+        name: "f10",
+        number: 10,
+    },
+    Register {
+        name: "f11",
+        number: 11,
+    },
+    Register {
+        name: "f12",
+        number: 12,
+    },
+    Register {
+        name: "f13",
+        number: 13,
+    },
+    Register {
+        name: "f14",
+        number: 14,
+    },
+    Register {
+        name: "f15",
+        number: 15,
+    },
+    Register {
+        name: "f16",
+        number: 16,
+    },
+    Register {
+        name: "f17",
+        number: 17,
+    },
+    Register {
+        name: "f18",
+        number: 18,
+    },
+    Register {
+        name: "f19",
+        number: 19,
+    },
+    Register {
+        name: "f20",
+        number: 20,
+    },
+    Register {
+        name: "f21",
+        number: 21,
+    },
+    Register {
+        name: "f22",
+        number: 22,
+    },
+    Register {
+        name: "f23",
+        number: 23,
+    },
+    Register {
+        name: "f24",
+        number: 24,
+    },
+    Register {
+        name: "f25",
+        number: 25,
+    },
+    Register {
+        name: "f26",
+        number: 26,
+    },
+    Register {
+        name: "f27",
+        number: 27,
+    },
+    Register {
+        name: "f28",
+        number: 28,
+    },
+    Register {
+        name: "f29",
+        number: 29,
+    },
+    Register {
+        name: "f30",
+        number: 30,
+    },
+    Register {
+        name: "f31",
+        number: 31,
+    },
 ];
+
+pub fn is_register(name: &str) -> bool {
+    REG_FILE
+        .iter()
+        .chain(F_REG_FILE.iter())
+        .any(|r| r.name == name)
+}
+
+/// Naming style of registers printed by the disassembler
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RegisterNames {
+    // `zero`, `ra`, `fa0`...
+    #[default]
+    Abi,
+    // `x0`, `x1`, `f10`...
+    Numeric,
+}
+
+pub fn register_name(number: u32, names: RegisterNames) -> &'static str {
+    name_in(&REG_FILE, number, names)
+}
+
+pub fn float_register_name(number: u32, names: RegisterNames) -> &'static str {
+    name_in(&F_REG_FILE, number, names)
+}
+
+// ABI names come first in the register files and numeric names last
+fn name_in(
+    reg_file: &'static [Register<'static>],
+    number: u32,
+    names: RegisterNames,
+) -> &'static str {
+    let mut registers = reg_file.iter().filter(|r| r.number == number);
+    let register = match names {
+        RegisterNames::Abi => registers.next(),
+        RegisterNames::Numeric => registers.next_back(),
+    };
+    register.unwrap().name
+}
+
+pub fn register_number(name: &str, reg_file: &[Register]) -> Option<u32> {
+    reg_file.iter().find(|r| r.name == name).map(|r| r.number)
+}
@@ -0,0 +1,231 @@
+// Assemble, disassemble and re-assemble random operands for every instruction
+use crate::inst::*;
+use crate::isa::Xlen;
+use crate::reg::{Register, F_REG_FILE, REG_FILE};
+use crate::RegisterNames;
+use crate::{
+    assemble_with_options, decode_asm_line, disassemble_word_with, AssembleError, Isa, Options,
+};
+
+const ITERATIONS: usize = 200;
+
+// xorshift64, deterministic so that failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn range(&mut self, min: i64, max: i64) -> i64 {
+        min + (self.next() % ((max - min) as u64 + 1)) as i64
+    }
+
+    fn register(&mut self, reg_file: &'static [Register<'static>]) -> &'static str {
+        reg_file[self.next() as usize % reg_file.len()].name
+    }
+
+    // One of the eight registers reachable from the 3-bit fields of compressed instructions
+    fn compressed_register(&mut self, reg_file: &'static [Register<'static>]) -> &'static str {
+        let number = self.range(8, 15) as u32;
+        reg_file.iter().find(|r| r.number == number).unwrap().name
+    }
+
+    fn register_names(&mut self) -> RegisterNames {
+        if self.range(0, 1) == 0 {
+            RegisterNames::Abi
+        } else {
+            RegisterNames::Numeric
+        }
+    }
+
+    fn imm(&mut self, inst_type: &InstructionType) -> i64 {
+        let (min, max) = inst_type.imm_range();
+        let alignment = inst_type.imm_alignment();
+        self.range(min / alignment, max / alignment) * alignment
+    }
+}
+
+fn random_asm_line(inst: &Instruction, xlen: Xlen, rng: &mut Rng) -> String {
+    let mut operands = vec![];
+    for arg in inst.arguments.iter().take(inst.num_of_arguments) {
+        let operand = match arg {
+            AsmArgs::RegDest | AsmArgs::RegSrc1 | AsmArgs::RegSrc2 => {
+                rng.register(&REG_FILE).to_string()
+            }
+            AsmArgs::Imm => rng.imm(&inst.inst_type).to_string(),
+            AsmArgs::Shamt if xlen == Xlen::Rv64 => rng.range(0, SHAMT_MAX_RV64).to_string(),
+            AsmArgs::Shamt | AsmArgs::ShamtW => rng.range(0, SHAMT_MAX).to_string(),
+            AsmArgs::Mem => format!("{}({})", rng.imm(&inst.inst_type), rng.register(&REG_FILE)),
+            AsmArgs::Pred | AsmArgs::Succ => {
+                let bits = rng.range(0, 15) as usize;
+                let set: String = FENCE_ACCESSES
+                    .iter()
+                    .enumerate()
+                    .filter(|(position, _)| bits & (1 << (3 - position)) != 0)
+                    .map(|(_, access)| access)
+                    .collect();
+                if set.is_empty() {
+                    "0".to_string()
+                } else {
+                    set
+                }
+            }
+            AsmArgs::Csr => rng.range(0, 0xFFF).to_string(),
+            AsmArgs::Uimm => rng.range(0, UIMM_MAX).to_string(),
+            AsmArgs::Addr => format!("({})", rng.register(&REG_FILE)),
+            AsmArgs::FRegDest | AsmArgs::FRegSrc1 | AsmArgs::FRegSrc2 | AsmArgs::FRegSrc3 => {
+                rng.register(&F_REG_FILE).to_string()
+            }
+            AsmArgs::NoArg => break,
+        };
+        operands.push(operand);
+    }
+    if takes_rounding_mode(inst) && rng.range(0, 1) == 1 {
+        let (mode, _) = ROUNDING_MODES[rng.next() as usize % ROUNDING_MODES.len()];
+        operands.push(mode.to_string());
+    }
+    inst.asm_string.to_string() + " " + &operands.join(", ")
+}
+
+fn random_compressed_line(inst: &CompressedInstruction, rng: &mut Rng, xlen: Xlen) -> String {
+    let mut operands = vec![];
+    let imm = |rng: &mut Rng| {
+        let layout = inst.imm_layout.unwrap();
+        let (min, max) = layout.imm_range();
+        let alignment = layout.imm_alignment();
+        let imm = rng.range(min / alignment, max / alignment) * alignment;
+        // Upper immediates are written as the 20-bit field of lui
+        if alignment >= 1 << 12 {
+            (imm >> 12) & 0xFFFFF
+        } else {
+            imm
+        }
+    };
+    for arg in inst.arguments.iter().take(inst.num_of_arguments) {
+        let (_, prime) = inst.format.register_field(arg);
+        let operand = match arg {
+            CompressedArgs::Rd | CompressedArgs::Rs1 | CompressedArgs::Rs2 if prime => {
+                rng.compressed_register(&REG_FILE).to_string()
+            }
+            CompressedArgs::Rd | CompressedArgs::Rs1 | CompressedArgs::Rs2 => {
+                rng.register(&REG_FILE).to_string()
+            }
+            CompressedArgs::FRd | CompressedArgs::FRs2 if prime => {
+                rng.compressed_register(&F_REG_FILE).to_string()
+            }
+            CompressedArgs::FRd | CompressedArgs::FRs2 => rng.register(&F_REG_FILE).to_string(),
+            CompressedArgs::Sp => "sp".to_string(),
+            CompressedArgs::Imm => imm(rng).to_string(),
+            CompressedArgs::Shamt if xlen == Xlen::Rv64 => rng.range(1, SHAMT_MAX_RV64).to_string(),
+            CompressedArgs::Shamt => rng.range(1, SHAMT_MAX).to_string(),
+            CompressedArgs::Mem => {
+                format!("{}({})", imm(rng), rng.compressed_register(&REG_FILE))
+            }
+            CompressedArgs::SpMem => format!("{}(sp)", imm(rng)),
+            CompressedArgs::NoArg => break,
+        };
+        operands.push(operand);
+    }
+    inst.asm_string.to_string() + " " + &operands.join(", ")
+}
+
+// Encode a single instruction for the given XLEN. RV32 lines go through the public single-line
+// entry point, which cannot select RV64, so RV64 lines are assembled as a program instead.
+fn assemble_line(asm_line: &str, xlen: Xlen) -> Result<u32, AssembleError> {
+    if xlen == Xlen::Rv32 {
+        return decode_asm_line(asm_line);
+    }
+    let mut options = Options::default();
+    options.isa.xlen = xlen;
+    let binary = assemble_with_options(asm_line, &options)?.to_binary();
+    Ok(u32::from_le_bytes(binary[..4].try_into().unwrap()))
+}
+
+// `decode_asm_line` never compresses, so compressed lines are assembled as a program with C
+fn assemble_compressed_line(asm_line: &str, xlen: Xlen) -> Result<u32, AssembleError> {
+    let isa = match xlen {
+        Xlen::Rv32 => "rv32gc",
+        Xlen::Rv64 => "rv64gc",
+    };
+    let options = Options {
+        isa: Isa::parse(isa).unwrap(),
+        ..Default::default()
+    };
+    let binary = assemble_with_options(asm_line, &options)?.to_binary();
+    Ok(u16::from_le_bytes(binary[..2].try_into().unwrap()) as u32)
+}
+
+#[test]
+fn round_trip() {
+    let mut rng = Rng(0x9E3779B97F4A7C15);
+    for xlen in [Xlen::Rv32, Xlen::Rv64] {
+        let instructions = INSTRUCTION_SETS
+            .iter()
+            .filter(|set| set.xlen.is_none_or(|set_xlen| set_xlen == xlen))
+            .flat_map(|set| set.instructions.iter());
+        for inst in instructions {
+            for _ in 0..ITERATIONS {
+                let asm_line = random_asm_line(inst, xlen, &mut rng);
+                let word = assemble_line(&asm_line, xlen)
+                    .unwrap_or_else(|e| panic!("Failed to assemble '{asm_line}': {e}"));
+                let disassembled = disassemble_word_with(word, xlen, rng.register_names())
+                    .unwrap_or_else(|e| panic!("Failed to disassemble '{asm_line}': {e}"));
+                assert_eq!(
+                    disassembled.split(' ').next(),
+                    Some(inst.asm_string),
+                    "'{asm_line}' disassembled to '{disassembled}'"
+                );
+                let reassembled = assemble_line(&disassembled, xlen)
+                    .unwrap_or_else(|e| panic!("Failed to re-assemble '{disassembled}': {e}"));
+                assert_eq!(
+                    word, reassembled,
+                    "'{asm_line}' -> '{disassembled}' changed the encoding"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn compressed_round_trip() {
+    let mut rng = Rng(0x2545F4914F6CDD1D);
+    for xlen in [Xlen::Rv32, Xlen::Rv64] {
+        let instructions = COMPRESSED_SETS
+            .iter()
+            .filter(|set| set.xlen.is_none_or(|set_xlen| set_xlen == xlen))
+            .flat_map(|set| set.instructions.iter());
+        for inst in instructions {
+            let mut encoded = 0;
+            for _ in 0..ITERATIONS {
+                // Some operands are reserved, such as a zero immediate for c.addi
+                let asm_line = random_compressed_line(inst, &mut rng, xlen);
+                let Ok(word) = assemble_compressed_line(&asm_line, xlen) else {
+                    continue;
+                };
+                encoded += 1;
+                let disassembled = disassemble_word_with(word, xlen, rng.register_names())
+                    .unwrap_or_else(|e| panic!("Failed to disassemble '{asm_line}': {e}"));
+                assert_eq!(
+                    disassembled.split(' ').next(),
+                    Some(inst.asm_string),
+                    "'{asm_line}' disassembled to '{disassembled}'"
+                );
+                let reassembled = assemble_compressed_line(&disassembled, xlen)
+                    .unwrap_or_else(|e| panic!("Failed to re-assemble '{disassembled}': {e}"));
+                assert_eq!(
+                    word, reassembled,
+                    "'{asm_line}' -> '{disassembled}' changed the encoding"
+                );
+            }
+            assert!(
+                encoded > ITERATIONS / 2,
+                "Few valid operands for {}",
+                inst.asm_string
+            );
+        }
+    }
+}
@@ -1,7 +1,9 @@
 mod token;
-use token::Token;
-use token::TokenType;
+pub use token::Token;
+pub use token::TokenType;
 
+use crate::error::AssembleError;
+use crate::error::Span;
 use std::{iter::Peekable, str::Chars};
 
 pub struct Scanner {
@@ -10,12 +12,13 @@ pub struct Scanner {
 
 impl Scanner {
     pub fn new(source: String) -> Scanner {
-        Scanner { source: source }
+        Scanner { source }
     }
 
-    pub fn scan_tokens(&mut self) -> Result<Vec<Token>, String> {
+    pub fn scan_tokens(&mut self) -> Result<Vec<Token>, AssembleError> {
         let mut tokens = vec![];
-        let mut line_number = 0;
+        let mut line_number = 1;
+        let mut next_column = 1;
         let mut find_end_of_line = false;
         let mut chars = self.source.chars().peekable();
         loop {
@@ -24,6 +27,8 @@ impl Scanner {
                 Some(c) => c,
                 None => break,
             };
+            let column = next_column;
+            next_column += 1;
             // Ignore any token after comment token
             if find_end_of_line && c == '\n' {
                 find_end_of_line = false;
@@ -38,26 +43,133 @@ impl Scanner {
                     c.to_string(),
                     TokenType::LeftParantheses,
                     line_number,
+                    column,
                 ));
             } else if c == ')' {
                 tokens.push(Token::new(
                     c.to_string(),
                     TokenType::RightParantheses,
                     line_number,
+                    column,
                 ));
             } else if c == ':' {
-                tokens.push(Token::new(c.to_string(), TokenType::Colon, line_number));
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Colon,
+                    line_number,
+                    column,
+                ));
             } else if c == '.' {
-                tokens.push(Token::new(c.to_string(), TokenType::Dot, line_number));
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Dot,
+                    line_number,
+                    column,
+                ));
             } else if c == ',' {
-                tokens.push(Token::new(c.to_string(), TokenType::Comma, line_number));
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Comma,
+                    line_number,
+                    column,
+                ));
             } else if c == '%' {
-                tokens.push(Token::new(c.to_string(), TokenType::Percent, line_number));
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Percent,
+                    line_number,
+                    column,
+                ));
+            } else if c == '+' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Plus,
+                    line_number,
+                    column,
+                ));
+            } else if c == '-' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Minus,
+                    line_number,
+                    column,
+                ));
+            } else if c == '*' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Star,
+                    line_number,
+                    column,
+                ));
+            } else if c == '/' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Slash,
+                    line_number,
+                    column,
+                ));
+            } else if c == '&' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Ampersand,
+                    line_number,
+                    column,
+                ));
+            } else if c == '|' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Pipe,
+                    line_number,
+                    column,
+                ));
+            } else if c == '^' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Caret,
+                    line_number,
+                    column,
+                ));
+            } else if c == '~' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Tilde,
+                    line_number,
+                    column,
+                ));
+            } else if c == '=' {
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::Equals,
+                    line_number,
+                    column,
+                ));
+            }
+            // Shifts
+            else if (c == '<' || c == '>') && chars.peek() == Some(&c) {
+                chars.next();
+                next_column += 1;
+                let token_type = if c == '<' {
+                    TokenType::LessLess
+                } else {
+                    TokenType::GreaterGreater
+                };
+                tokens.push(Token::new(
+                    format!("{c}{c}"),
+                    token_type,
+                    line_number,
+                    column,
+                ));
             }
             // Line break
             else if c == '\n' {
-                tokens.push(Token::new(c.to_string(), TokenType::LineBreak, line_number));
+                tokens.push(Token::new(
+                    c.to_string(),
+                    TokenType::LineBreak,
+                    line_number,
+                    column,
+                ));
                 line_number += 1;
+                next_column = 1;
             }
             // Ignore whitespace
             else if c.is_whitespace() {
@@ -66,37 +178,50 @@ impl Scanner {
             // Identify multi-character tokens
             // String
             else if c == '"' {
-                if let Ok(string) = Self::extract_string(c, &mut chars) {
-                    tokens.push(Token::new(string, TokenType::String, line_number));
+                if let Ok((string, bytes)) = Self::extract_string(&mut chars) {
+                    let token = Token::new_string(string, bytes, line_number, column);
+                    next_column = column + token.span().length;
+                    tokens.push(token);
                 } else {
                     return Err(Self::error(
                         line_number,
-                        "Syntax error",
+                        column,
                         "Error in parsing string",
+                        "",
                     ));
                 }
             }
             // Number
             else if c.is_ascii_digit() {
-                if let Ok((string, number)) = Self::extract_number(c, &mut chars) {
-                    tokens.push(Token::new_number(string, number, line_number));
+                if let Some(string) = Self::extract_local_label(c, &mut chars) {
+                    let token = Token::new(string, TokenType::LocalLabel, line_number, column);
+                    next_column = column + token.span().length;
+                    tokens.push(token);
+                } else if let Ok((string, number)) = Self::extract_number(c, &mut chars) {
+                    let token = Token::new_number(string, number, line_number, column);
+                    next_column = column + token.span().length;
+                    tokens.push(token);
                 } else {
                     return Err(Self::error(
                         line_number,
-                        "Syntax error",
+                        column,
                         "Error in parsing number",
+                        "",
                     ));
                 }
             }
             // Identifier
             else if c.is_ascii_alphabetic() || c == '_' || c == '.' || c == '$' || c == '@' {
                 if let Ok(string) = Self::extract_identifier(c, &mut chars) {
-                    tokens.push(Token::new(string, TokenType::Identifier, line_number));
+                    let token = Token::new(string, TokenType::Identifier, line_number, column);
+                    next_column = column + token.span().length;
+                    tokens.push(token);
                 } else {
                     return Err(Self::error(
                         line_number,
-                        "Syntax error",
+                        column,
                         "Error in parsing identifier",
+                        "",
                     ));
                 }
             }
@@ -104,70 +229,117 @@ impl Scanner {
             else {
                 return Err(Self::error(
                     line_number,
-                    "Syntax error",
+                    column,
                     "Unexpected character",
+                    &c.to_string(),
                 ));
             }
         }
         Ok(tokens)
     }
 
-    fn extract_string(c: char, chars: &mut Peekable<Chars>) -> Result<String, ()> {
+    // Returns the string as written along with its decoded bytes
+    fn extract_string(chars: &mut Peekable<Chars>) -> Result<(String, Vec<u8>), ()> {
         let mut string = "".to_string();
-        while let Some(&c) = chars.peek() {
+        let mut bytes = vec![];
+        while let Some(c) = chars.next() {
             if c == '\n' {
                 return Err(());
             } else if c == '"' {
-                chars.next();
-                return Ok(string);
-            } else {
-                string += &c.to_string();
-                chars.next();
+                return Ok((string, bytes));
+            }
+            string.push(c);
+            if c != '\\' {
+                let mut buffer = [0; 4];
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+                continue;
             }
+            let c = chars.next().ok_or(())?;
+            string.push(c);
+            let byte = match c {
+                'n' => b'\n',
+                't' => b'\t',
+                'r' => b'\r',
+                'a' => 0x07,
+                'b' => 0x08,
+                'f' => 0x0C,
+                'v' => 0x0B,
+                '\\' | '"' | '\'' => c as u8,
+                // `\x41`
+                'x' => {
+                    let mut value = 0u32;
+                    let mut digits = 0;
+                    while let Some(digit) = chars.peek().and_then(|c| c.to_digit(16)) {
+                        string.push(chars.next().unwrap());
+                        value = (value << 4 | digit) & 0xFF;
+                        digits += 1;
+                    }
+                    if digits == 0 {
+                        return Err(());
+                    }
+                    value as u8
+                }
+                // `\0` or `\101`, at most three octal digits
+                '0'..='7' => {
+                    let mut value = c.to_digit(8).unwrap();
+                    for _ in 0..2 {
+                        match chars.peek().and_then(|c| c.to_digit(8)) {
+                            Some(digit) => {
+                                string.push(chars.next().unwrap());
+                                value = value << 3 | digit;
+                            }
+                            None => break,
+                        }
+                    }
+                    value as u8
+                }
+                _ => return Err(()),
+            };
+            bytes.push(byte);
         }
         Err(())
     }
 
-    fn extract_number(c: char, chars: &mut Peekable<Chars>) -> Result<(String, i32), ()> {
+    // Digits directly followed by `f` or `b`, which would otherwise be taken for a hexadecimal or
+    // binary number. Nothing is consumed unless one is found.
+    fn extract_local_label(c: char, chars: &mut Peekable<Chars>) -> Option<String> {
+        let mut lookahead = chars.clone();
+        let mut string = c.to_string();
+        while let Some(c) = lookahead.next_if(|c| c.is_ascii_digit()) {
+            string.push(c);
+        }
+        let direction = lookahead.next_if(|&c| c == 'f' || c == 'b')?;
+        string.push(direction);
+        if lookahead
+            .peek()
+            .is_some_and(|&c| c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$')
+        {
+            return None;
+        }
+        *chars = lookahead;
+        Some(string)
+    }
+
+    fn extract_number(c: char, chars: &mut Peekable<Chars>) -> Result<(String, i64), ()> {
         let mut string = c.to_string();
         while let Some(&c) = chars.peek() {
-            if c.is_whitespace()
-                || c == '#'
-                || c == ';'
-                || c == '('
-                || c == ')'
-                || c == ','
-                || c == ':'
-            {
-                break;
-            } else if c.is_ascii_hexdigit() || c == 'x' || c == 'h' || c == 'q' || c == 'y' {
+            if c.is_ascii_hexdigit() || c == 'x' || c == 'h' || c == 'q' || c == 'y' {
                 string += &c.to_string();
                 chars.next();
             } else {
-                return Err(());
+                break;
             }
         }
-        if let Ok(number) = super::imm_string_to_i32(&string) {
-            return Ok((string, number));
-        } else {
-            println!("Number string: {}", string);
-            return Err(());
+        match super::imm_string_to_i64(&string) {
+            Ok(number) => Ok((string, number)),
+            Err(_) => Err(()),
         }
     }
 
     fn extract_identifier(c: char, chars: &mut Peekable<Chars>) -> Result<String, ()> {
         let mut string = c.to_string();
         while let Some(&c) = chars.peek() {
-            if c.is_whitespace()
-                || c == '#'
-                || c == ';'
-                || c == '('
-                || c == ')'
-                || c == ','
-                || c == ':'
-            {
-                break;
-            } else if c.is_ascii_alphabetic()
+            if c.is_ascii_alphabetic()
                 || c.is_ascii_digit()
                 || c == '_'
                 || c == '.'
@@ -177,14 +349,22 @@ impl Scanner {
                 string += &c.to_string();
                 chars.next();
             } else {
-                return Err(());
+                break;
             }
         }
         Ok(string)
     }
 
-    fn error(line_number: i32, what: &str, description: &str) -> String {
-        "[Line ".to_string() + &line_number.to_string() + "] " + what + ": " + description
+    fn error(line_number: usize, column: usize, description: &str, lexeme: &str) -> AssembleError {
+        AssembleError::Syntax {
+            description: description.to_string(),
+            lexeme: lexeme.to_string(),
+            span: Span {
+                line: line_number,
+                column,
+                length: lexeme.chars().count().max(1),
+            },
+        }
     }
 }
 
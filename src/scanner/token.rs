@@ -1,31 +1,62 @@
-#[derive(Debug)]
+use crate::error::Span;
+
+#[derive(Debug, Clone)]
 pub struct Token {
-    lexeme: String,
-    token_type: TokenType,
-    literal: Option<i32>,
-    line_number: i32,
+    pub lexeme: String,
+    pub token_type: TokenType,
+    pub literal: Option<i64>,
+    // Contents of a string literal with its escape sequences decoded
+    pub bytes: Option<Vec<u8>>,
+    pub line_number: usize,
+    pub column: usize,
 }
 
 impl Token {
-    pub fn new(lexeme: String, token_type: TokenType, line_number: i32) -> Token {
+    pub fn new(lexeme: String, token_type: TokenType, line_number: usize, column: usize) -> Token {
         Token {
             lexeme,
             token_type,
             line_number,
+            column,
             literal: None,
+            bytes: None,
         }
     }
-    pub fn new_number(lexeme: String, literal: i32, line_number: i32) -> Token {
+    pub fn new_string(lexeme: String, bytes: Vec<u8>, line_number: usize, column: usize) -> Token {
+        Token {
+            lexeme,
+            token_type: TokenType::String,
+            line_number,
+            column,
+            literal: None,
+            bytes: Some(bytes),
+        }
+    }
+    pub fn new_number(lexeme: String, literal: i64, line_number: usize, column: usize) -> Token {
         Token {
             lexeme,
             token_type: TokenType::Number,
             line_number,
+            column,
             literal: Some(literal),
+            bytes: None,
+        }
+    }
+    pub fn span(&self) -> Span {
+        let mut length = self.lexeme.chars().count();
+        // Account for the quotes around string literals
+        if self.token_type == TokenType::String {
+            length += 2;
+        }
+        Span {
+            line: self.line_number,
+            column: self.column,
+            length,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TokenType {
     // Single character tokens
     LeftParantheses,
@@ -34,10 +65,25 @@ pub enum TokenType {
     Dot,
     Comma,
     Percent,
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Ampersand,
+    Pipe,
+    Caret,
+    Tilde,
+    Equals,
     LineBreak,
 
+    // Two character tokens
+    LessLess,
+    GreaterGreater,
+
     // Multi character token
     Identifier,
     Number,
     String,
+    // `1f` or `1b`, referring to the next or previous numeric label `1:`
+    LocalLabel,
 }